@@ -7,10 +7,10 @@ use oxc::{
   span::{GetSpan, Span},
 };
 use rolldown_common::ImportKind;
-use rolldown_ecmascript::ToSourceString;
+use rolldown_ecmascript::{ExpressionExt, ToSourceString};
 use rolldown_error::BuildDiagnostic;
 
-use crate::utils::call_expression_ext::CallExpressionExt;
+use crate::utils::{call_expression_ext::CallExpressionExt, new_expression_ext::NewExpressionExt};
 
 use super::{side_effect_detector::SideEffectDetector, AstScanner};
 
@@ -40,6 +40,18 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
 
   fn visit_member_expression(&mut self, expr: &MemberExpression<'ast>) {
     match expr {
+      MemberExpression::StaticMemberExpression(member_expr)
+        if member_expr.object.is_import_meta() =>
+      {
+        if !member_expr.span.is_unspanned() {
+          self
+            .result
+            .import_meta_references
+            .push((member_expr.span, Some(member_expr.property.name.as_str().into())));
+        }
+        // Don't walk again, otherwise `import.meta` will be recorded again in `visit_meta_property`.
+        return;
+      }
       MemberExpression::StaticMemberExpression(member_expr) => {
         // For member expression like `a.b.c.d`, we will first enter the (object: `a.b.c`, property: `d`) expression.
         // So we add these properties with order `d`, `c`, `b`.
@@ -86,6 +98,12 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
     }
   }
 
+  fn visit_meta_property(&mut self, meta: &ast::MetaProperty<'ast>) {
+    if meta.meta.name == "import" && meta.property.name == "meta" && !meta.span.is_unspanned() {
+      self.result.import_meta_references.push((meta.span, None));
+    }
+  }
+
   fn visit_statement(&mut self, stmt: &ast::Statement<'ast>) {
     if let Some(decl) = stmt.as_module_declaration() {
       self.scan_module_decl(decl);
@@ -105,6 +123,28 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
     walk::walk_import_expression(self, expr);
   }
 
+  fn visit_new_expression(&mut self, expr: &ast::NewExpression<'ast>) {
+    if let Some((url, worker_type)) = expr.new_worker_with_url(self.scopes) {
+      if let Some(ast::Argument::StringLiteral(specifier)) = url.arguments.first() {
+        if is_file_reference(&specifier.value) {
+          self.result.worker_references.push((
+            url.span,
+            specifier.value.as_str().into(),
+            worker_type,
+          ));
+        }
+      }
+    } else if let Some(specifier) = expr.new_url_with_import_meta_url(self.scopes) {
+      // Urls of workers are recorded when visiting the `new Worker(...)` expressions.
+      let is_worker_url =
+        self.result.worker_references.last().map_or(false, |(span, ..)| *span == expr.span);
+      if !is_worker_url && is_file_reference(&specifier.value) {
+        self.result.new_url_references.push((expr.span, specifier.value.as_str().into()));
+      }
+    }
+    walk::walk_new_expression(self, expr);
+  }
+
   fn visit_assignment_expression(&mut self, node: &ast::AssignmentExpression<'ast>) {
     match &node.left {
       ast::AssignmentTarget::AssignmentTargetIdentifier(id_ref) => {
@@ -167,3 +207,12 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
     walk::walk_call_expression(self, expr);
   }
 }
+
+/// Absolute urls, urls with a scheme and fragment-only urls don't refer to files in the module graph.
+fn is_file_reference(specifier: &str) -> bool {
+  !specifier.is_empty()
+    && !specifier.starts_with(['/', '#'])
+    && specifier
+      .find(':')
+      .map_or(true, |colon| specifier.find('/').map_or(false, |slash| slash < colon))
+}
@@ -39,6 +39,13 @@ pub struct ScanResult {
   pub star_exports: Vec<ImportRecordIdx>,
   pub default_export_ref: SymbolRef,
   pub imports: FxHashMap<Span, ImportRecordIdx>,
+  /// Spans of `new URL('./foo.png', import.meta.url)` and the files they refer to, in source order.
+  pub new_url_references: Vec<(Span, Rstr)>,
+  /// Spans of `new URL(...)` in `new Worker(new URL('./worker.js', import.meta.url))`, the files they refer to and the
+  /// types of the workers, in source order.
+  pub worker_references: Vec<(Span, Rstr, WorkerType)>,
+  /// Spans of `import.meta` and `import.meta.<property>` and the accessed properties, in source order.
+  pub import_meta_references: Vec<(Span, Option<Rstr>)>,
   pub exports_kind: ExportsKind,
   pub warnings: Vec<BuildDiagnostic>,
   pub errors: Vec<BuildDiagnostic>,
@@ -46,6 +53,13 @@ pub struct ScanResult {
   pub ast_usage: EcmaModuleAstUsage,
 }
 
+/// Module workers are bundled as ES modules, and classic workers are bundled as IIFE scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorkerType {
+  Classic,
+  Module,
+}
+
 pub struct AstScanner<'me> {
   idx: ModuleIdx,
   source: &'me ArcStr,
@@ -105,6 +119,9 @@ impl<'me> AstScanner<'me> {
       star_exports: Vec::new(),
       default_export_ref: (idx, symbol_id_for_default_export_ref).into(),
       imports: FxHashMap::default(),
+      new_url_references: Vec::new(),
+      worker_references: Vec::new(),
+      import_meta_references: Vec::new(),
       exports_kind: ExportsKind::None,
       warnings: Vec::new(),
       has_eval: false,
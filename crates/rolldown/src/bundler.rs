@@ -1,4 +1,8 @@
-use std::sync::Arc;
+use std::{
+  collections::BTreeSet,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use super::stages::{
   link_stage::{LinkStage, LinkStageOutput},
@@ -7,13 +11,15 @@ use super::stages::{
 use crate::{
   bundler_builder::BundlerBuilder,
   stages::{generate_stage::GenerateStage, scan_stage::ScanStage},
-  types::bundle_output::BundleOutput,
+  types::{bundle_output::BundleOutput, scan_stage_cache::ScanStageCache},
+  utils::depfile::{depfile_path, render_depfile},
+  watcher::Watcher,
   BundlerOptions, SharedOptions, SharedResolver,
 };
 use anyhow::Result;
-use rolldown_common::{NormalizedBundlerOptions, SharedFileEmitter};
-use rolldown_error::{BuildDiagnostic, DiagnosableResult};
-use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_common::{Log, LogLevel, NormalizedBundlerOptions, SharedFileEmitter};
+use rolldown_error::{BuildDiagnostic, DiagnosableResult, DiagnosticOptions, Severity};
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use rolldown_plugin::{
   HookBuildEndArgs, HookRenderErrorArgs, SharedPluginDriver, __inner::SharedPluginable,
 };
@@ -23,9 +29,11 @@ pub struct Bundler {
   pub(crate) closed: bool,
   pub(crate) options: SharedOptions,
   pub(crate) plugin_driver: SharedPluginDriver,
-  pub(crate) fs: OsFileSystem,
+  pub(crate) fs: SharedFileSystem,
   pub(crate) resolver: SharedResolver,
   pub(crate) file_emitter: SharedFileEmitter,
+  /// Only exists in watch mode, to reuse unchanged modules while rebuilding.
+  pub(crate) cache: Option<ScanStageCache>,
   pub(crate) _log_guard: Option<FlushGuard>,
 }
 
@@ -54,7 +62,9 @@ impl Bundler {
       let dest = dir.join(chunk.filename());
       if let Some(p) = dest.parent() {
         if !self.fs.exists(p) {
-          self.fs.create_dir_all(p).unwrap();
+          self.fs.create_dir_all(p).map_err(|err| {
+            anyhow::anyhow!("Could not create directory for output chunks: {:?}", p).context(err)
+          })?;
         }
       };
       self
@@ -63,11 +73,53 @@ impl Bundler {
         .map_err(|err| anyhow::anyhow!("Failed to write file in {:?}", dest).context(err))?;
     }
 
+    if let Some(depfile_filename) = &self.options.depfile_filename {
+      self.write_depfile(&dir, depfile_filename, &output)?;
+    }
+
     self.plugin_driver.write_bundle(&mut output.assets).await?;
 
     Ok(output)
   }
 
+  /// Writes the depfile listing the files of modules, the files added by plugins, and the `package.json` and tsconfig
+  /// files read by the resolver. Files that don't exist are skipped, e.g. modules with queries in their ids.
+  fn write_depfile(&self, dir: &Path, depfile_filename: &str, output: &BundleOutput) -> Result<()> {
+    let cwd = &self.options.cwd;
+    let outputs = output
+      .assets
+      .iter()
+      .map(|asset| depfile_path(&dir.join(asset.filename()), cwd))
+      .collect::<Vec<_>>();
+    let inputs = output
+      .watch_files
+      .iter()
+      .map(|file| PathBuf::from(file.as_str()))
+      .chain(self.resolver.package_json_files())
+      .chain(self.resolver.tsconfig_files())
+      .filter(|path| self.fs.exists(path))
+      .map(|path| depfile_path(&path, cwd))
+      .collect::<BTreeSet<_>>()
+      .into_iter()
+      .collect::<Vec<_>>();
+
+    let dest = dir.join(depfile_filename);
+    self
+      .fs
+      .write(&dest, render_depfile(&outputs, &inputs).as_bytes())
+      .map_err(|err| anyhow::anyhow!("Failed to write depfile {:?}", dest).context(err))?;
+    if self.options.depfile_json {
+      let mut dest = dest.into_os_string();
+      dest.push(".json");
+      let dest = PathBuf::from(dest);
+      self
+        .fs
+        .write(&dest, serde_json::to_string_pretty(&inputs)?.as_bytes())
+        .map_err(|err| anyhow::anyhow!("Failed to write depfile {:?}", dest).context(err))?;
+    }
+    Ok(())
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate(&mut self) -> Result<BundleOutput> {
     self.bundle_up(/* is_write */ false).await
@@ -85,29 +137,40 @@ impl Bundler {
     Ok(())
   }
 
+  /// Build the bundle, and rebuild it when the files of the module graph are changed.
+  ///
+  /// The bundle is written to the disk on every build unless `watch.skipWrite` is `true`. Results of builds
+  /// are reported through [Watcher::recv].
+  pub fn watch(self) -> Watcher {
+    Watcher::new(self)
+  }
+
   pub async fn scan(&mut self) -> Result<DiagnosableResult<ScanStageOutput>> {
-    self.plugin_driver.build_start().await?;
+    // The module table of the previous build is no longer valid.
+    self.plugin_driver.clear_module_table();
+    self.file_emitter.reset_chunks();
+    self.file_emitter.reset_files();
+    if self.calls_build_hooks() {
+      self.plugin_driver.build_start().await?;
+    }
 
     let mut error_for_build_end_hook = None;
 
     let scan_stage_output = match ScanStage::new(
       Arc::clone(&self.options),
       Arc::clone(&self.plugin_driver),
-      self.fs,
+      Arc::clone(&self.file_emitter),
+      self.fs.clone(),
       Arc::clone(&self.resolver),
     )
-    .scan()
+    .scan(self.cache.as_mut())
     .await
     {
       Ok(v) => v,
       Err(err) => {
         // TODO: So far we even call build end hooks on unhandleable errors . But should we call build end hook even for unhandleable errors?
         error_for_build_end_hook = Some(err.to_string());
-        self
-          .plugin_driver
-          .build_end(error_for_build_end_hook.map(|error| HookBuildEndArgs { error }).as_ref())
-          .await?;
-        self.plugin_driver.close_bundle().await?;
+        self.call_build_end_hooks(error_for_build_end_hook, /* close_bundle */ true).await?;
         return Err(err);
       }
     };
@@ -118,23 +181,37 @@ impl Bundler {
         if let Some(err_msg) = errs.first().map(ToString::to_string) {
           error_for_build_end_hook = Some(err_msg.clone());
         }
-        self
-          .plugin_driver
-          .build_end(error_for_build_end_hook.map(|error| HookBuildEndArgs { error }).as_ref())
-          .await?;
-        self.plugin_driver.close_bundle().await?;
+        self.call_build_end_hooks(error_for_build_end_hook, /* close_bundle */ true).await?;
         return Ok(Err(errs));
       }
     };
 
-    self
-      .plugin_driver
-      .build_end(error_for_build_end_hook.map(|error| HookBuildEndArgs { error }).as_ref())
-      .await?;
+    self.call_build_end_hooks(error_for_build_end_hook, /* close_bundle */ false).await?;
 
     Ok(Ok(scan_stage_output))
   }
 
+  /// Builds of workers share the plugins of the build referencing them, so the hooks bracketing a build are only
+  /// called for the outermost build. See [crate::utils::bundle_worker::bundle_worker].
+  fn calls_build_hooks(&self) -> bool {
+    self.options.enclosing_workers.is_empty()
+  }
+
+  async fn call_build_end_hooks(
+    &mut self,
+    error: Option<String>,
+    close_bundle: bool,
+  ) -> Result<()> {
+    if !self.calls_build_hooks() {
+      return Ok(());
+    }
+    self.plugin_driver.build_end(error.map(|error| HookBuildEndArgs { error }).as_ref()).await?;
+    if close_bundle {
+      self.plugin_driver.close_bundle().await?;
+    }
+    Ok(())
+  }
+
   async fn try_build(&mut self) -> Result<DiagnosableResult<LinkStageOutput>> {
     let build_info = match self.scan().await? {
       Ok(scan_stage_output) => scan_stage_output,
@@ -143,8 +220,29 @@ impl Bundler {
     Ok(Ok(LinkStage::new(build_info, &self.options).link()))
   }
 
-  #[allow(clippy::missing_transmute_annotations)]
   async fn bundle_up(&mut self, is_write: bool) -> Result<BundleOutput> {
+    let mut output = match self.try_bundle_up(is_write).await {
+      Ok(output) => output,
+      // Errors created by `PluginContext::error` are reported as diagnostics.
+      Err(err) => match err.downcast::<BuildDiagnostic>() {
+        Ok(diagnostic) => BundleOutput { errors: vec![diagnostic], ..Default::default() },
+        Err(err) => return Err(err),
+      },
+    };
+    output.warnings.extend(self.plugin_driver.take_logs());
+    let plugin_watch_files = self.plugin_driver.take_watch_files();
+    output.watch_files.extend(plugin_watch_files.keys().cloned());
+    let missing_files = self.resolver.take_missing_files();
+    if let Some(cache) = &mut self.cache {
+      cache.add_plugin_watch_files(plugin_watch_files);
+      cache.add_missing_files(missing_files);
+    }
+    self.filter_logs(&mut output.warnings);
+    Ok(output)
+  }
+
+  #[allow(clippy::missing_transmute_annotations)]
+  async fn try_bundle_up(&mut self, is_write: bool) -> Result<BundleOutput> {
     if self.closed {
       return Err(anyhow::anyhow!(
         "Bundle is already closed, no more calls to 'generate' or 'write' are allowed."
@@ -153,7 +251,7 @@ impl Bundler {
 
     let mut link_stage_output = match self.try_build().await? {
       Ok(v) => v,
-      Err(errors) => return Ok(BundleOutput { assets: vec![], warnings: vec![], errors }),
+      Err(errors) => return Ok(BundleOutput { errors, ..Default::default() }),
     };
 
     self.plugin_driver.set_module_table(unsafe {
@@ -164,26 +262,53 @@ impl Bundler {
       std::mem::transmute(&link_stage_output.module_table)
     });
 
-    self.plugin_driver.render_start().await?;
+    let output = self.generate_linked(&mut link_stage_output, is_write).await;
+    // The `module_table` is dropped with `link_stage_output` once returned, hooks called later, e.g. `watchChange`
+    // and `closeWatcher`, must not access it.
+    self.plugin_driver.clear_module_table();
+    output
+  }
+
+  async fn generate_linked(
+    &mut self,
+    link_stage_output: &mut LinkStageOutput,
+    is_write: bool,
+  ) -> Result<BundleOutput> {
+    let watch_files = link_stage_output
+      .module_table
+      .modules
+      .iter()
+      .filter_map(|module| module.as_normal())
+      .filter(|module| Path::new(module.id.as_str()).is_absolute())
+      .map(|module| module.id.as_str().into())
+      .collect();
+
+    if self.calls_build_hooks() {
+      self.plugin_driver.render_start().await?;
+    }
 
     let mut output = {
       let bundle_output =
-        GenerateStage::new(&mut link_stage_output, &self.options, &self.plugin_driver)
-          .generate()
-          .await;
+        GenerateStage::new(link_stage_output, &self.options, &self.plugin_driver).generate().await;
 
       if let Some(error) = Self::normalize_error(&bundle_output, |ret| &ret.errors) {
-        self.plugin_driver.render_error(&HookRenderErrorArgs { error }).await?;
+        if self.calls_build_hooks() {
+          self.plugin_driver.render_error(&HookRenderErrorArgs { error }).await?;
+        }
       }
 
       bundle_output?
     };
 
+    self.file_emitter.set_chunk_file_names(&output.assets);
     // Add additional files from build plugins.
     self.file_emitter.add_additional_files(&mut output.assets);
 
-    self.plugin_driver.generate_bundle(&mut output.assets, is_write).await?;
+    if self.calls_build_hooks() {
+      self.plugin_driver.generate_bundle(&mut output.assets, is_write).await?;
+    }
 
+    output.watch_files = watch_files;
     Ok(output)
   }
 
@@ -197,6 +322,26 @@ impl Bundler {
     )
   }
 
+  /// Drops the logs filtered out by `logLevel` and `onLog`.
+  fn filter_logs(&self, logs: &mut Vec<BuildDiagnostic>) {
+    let opts = DiagnosticOptions { cwd: self.options.cwd.clone() };
+    logs.retain(|log| {
+      let level = match log.severity() {
+        Severity::Error | Severity::Warning => LogLevel::Warn,
+        Severity::Info => LogLevel::Info,
+        Severity::Debug => LogLevel::Debug,
+      };
+      level <= self.options.log_level
+        && self.options.on_log.as_ref().map_or(true, |on_log| {
+          on_log.call(Log {
+            level,
+            code: &log.kind().to_string(),
+            message: &log.to_message_with(&opts),
+          })
+        })
+    });
+  }
+
   pub fn options(&self) -> &NormalizedBundlerOptions {
     &self.options
   }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use rolldown_common::FileEmitter;
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use rolldown_plugin::{PluginDriver, __inner::SharedPluginable};
 use rolldown_resolver::Resolver;
 
@@ -17,6 +17,7 @@ use crate::{
 pub struct BundlerBuilder {
   options: BundlerOptions,
   plugins: Vec<SharedPluginable>,
+  fs: SharedFileSystem,
 }
 
 impl BundlerBuilder {
@@ -26,7 +27,7 @@ impl BundlerBuilder {
     let NormalizeOptionsReturn { options, resolve_options } = normalize_options(self.options);
 
     let resolver: SharedResolver =
-      Resolver::new(resolve_options, options.platform, options.cwd.clone(), OsFileSystem).into();
+      Resolver::new(resolve_options, options.platform, options.cwd.clone(), self.fs.clone()).into();
 
     let options = Arc::new(options);
 
@@ -40,7 +41,8 @@ impl BundlerBuilder {
       file_emitter,
       resolver,
       options,
-      fs: OsFileSystem,
+      fs: self.fs,
+      cache: None,
       _log_guard: maybe_guard,
     }
   }
@@ -56,4 +58,12 @@ impl BundlerBuilder {
     self.plugins = plugins;
     self
   }
+
+  /// Read modules from and write the output to the given file system instead of the disk, e.g. a
+  /// `MemoryFileSystem` for bundling virtual projects.
+  #[must_use]
+  pub fn with_file_system(mut self, fs: impl FileSystem + 'static) -> Self {
+    self.fs = SharedFileSystem::new(fs);
+    self
+  }
 }
@@ -2,7 +2,13 @@ use crate::types::generator::{GenerateContext, GenerateOutput, Generator};
 
 use anyhow::Result;
 use rolldown_common::{InstantiatedChunk, InstantiationKind};
+use rolldown_css::{CssCompiler, CssModulesConfig, CssRenderOptions};
 use rolldown_error::DiagnosableResult;
+use rolldown_sourcemap::{
+  collapse_sourcemaps, lines_count, ConcatSource, RawSource, SourceMapSource,
+};
+use rustc_hash::FxHashSet;
+use sugar_path::SugarPath;
 
 pub struct CssGenerator;
 
@@ -26,16 +32,10 @@ impl Generator for CssGenerator {
       }));
     }
 
-    let mut content = String::new();
-
-    for module in &ordered_css_modules {
-      let css_view = module.css_view.as_ref().unwrap();
-      content.push_str(&css_view.source);
-    }
-
     // Here file path is generated by chunk file name template, it maybe including path segments.
     // So here need to read it's parent directory as file_dir.
-    let file_path = ctx.options.cwd.as_path().join(&ctx.options.dir).join(
+    let out_dir = ctx.options.cwd.as_path().join(&ctx.options.dir);
+    let file_path = out_dir.join(
       ctx
         .chunk
         .css_preliminary_filename
@@ -45,12 +45,76 @@ impl Generator for CssGenerator {
     );
     let file_dir = file_path.parent().expect("chunk file name should have a parent");
 
+    let project_root = ctx.options.cwd.to_string_lossy();
+    let mut concat_source = ConcatSource::default();
+    let mut hoisted_imports = FxHashSet::default();
+
+    for module in &ordered_css_modules {
+      let css_view = module.css_view.as_ref().unwrap();
+      let enable_sourcemap = ctx.options.sourcemap.is_some() && !module.is_virtual();
+
+      // Emitted assets are placed relative to `dir`, while urls in the output are relative to the CSS file.
+      let rewrite_url = |specifier: &str| {
+        css_view
+          .url_assets
+          .get(specifier)
+          .map(|file_name| out_dir.join(file_name).relative(file_dir).to_slash_lossy().into_owned())
+      };
+      let ret = CssCompiler::render(
+        &css_view.source,
+        &module.id,
+        &CssRenderOptions {
+          sourcemap: enable_sourcemap,
+          css_modules: css_view.is_css_module.then(|| CssModulesConfig {
+            pattern: &ctx.options.css_modules_pattern,
+            project_root: &project_root,
+          }),
+          rewrite_url: &rewrite_url,
+        },
+      )?;
+
+      // `@import`s must precede all other rules, so the ones that are not bundled are moved to the top.
+      let hoisted = ret.hoisted_imports.trim_end();
+      if !hoisted.is_empty() && hoisted_imports.insert(hoisted.to_string()) {
+        concat_source.add_prepend_source(Box::new(RawSource::new(hoisted.to_string())));
+      }
+
+      let code = ret.code.trim_end().to_string();
+      if code.is_empty() {
+        continue;
+      }
+      let map = ret.map.map(|map| {
+        if module.sourcemap_chain.is_empty() {
+          map
+        } else {
+          let mut sourcemap_chain = module.sourcemap_chain.iter().collect::<Vec<_>>();
+          sourcemap_chain.push(&map);
+          collapse_sourcemaps(sourcemap_chain)
+        }
+      });
+      if let Some(map) = map {
+        let lines_count = lines_count(&code);
+        concat_source.add_source(Box::new(SourceMapSource::new(code, map, lines_count)));
+      } else {
+        concat_source.add_source(Box::new(RawSource::new(code)));
+      }
+    }
+
+    let (content, mut map) = concat_source.content_and_sourcemap();
+
+    if let Some(map) = map.as_mut() {
+      let paths =
+        map.get_sources().map(|source| source.as_path().relative(file_dir)).collect::<Vec<_>>();
+      let sources = paths.iter().map(|x| x.to_string_lossy()).collect::<Vec<_>>();
+      map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+    }
+
     Ok(Ok(GenerateOutput {
       chunks: vec![InstantiatedChunk {
         origin_chunk: ctx.chunk_idx,
         content,
-        map: None,
-        meta: InstantiationKind::None,
+        map,
+        meta: InstantiationKind::Css,
         augment_chunk_hash: None,
         file_dir: file_dir.to_path_buf(),
         preliminary_filename: ctx
@@ -1,9 +1,140 @@
 pub mod css_generator;
 
+use std::{borrow::Cow, path::Path};
+
 use arcstr::ArcStr;
-use oxc::index::IndexVec;
-use rolldown_common::CssView;
+use oxc::{diagnostics::LabeledSpan, index::IndexVec};
+use rolldown_common::{AssetSource, CssView, EmittedAsset, ImportKind, ResolvedId};
+use rolldown_css::{CssCompiler, CssModulesConfig};
+use rolldown_error::{BuildDiagnostic, DiagnosableResult};
+use rolldown_fs::FileSystem;
+use rolldown_utils::ecma_script::is_validate_assignee_identifier_name;
+use rustc_hash::FxHashMap;
+
+use crate::{module_loader::task_context::TaskContext, types::module_factory::CreateModuleContext};
+
+pub struct CreateCssViewReturn {
+  pub css_view: CssView,
+  /// Source of the ecma part of the CSS module. Bundled `@import`s are turned into side-effect imports, so the
+  /// module loader follows them and imported files are executed, and thus placed, before the importer. For CSS
+  /// Modules, it also exports the scoped names.
+  pub ecma_source: String,
+}
+
+pub async fn create_css_view(
+  ctx: &TaskContext,
+  resolved_id: &ResolvedId,
+  source: &ArcStr,
+  warnings: &mut Vec<BuildDiagnostic>,
+) -> anyhow::Result<DiagnosableResult<CreateCssViewReturn>> {
+  // Files ending with `.module.css` are compiled as CSS Modules.
+  let is_css_module = resolved_id.id.ends_with(".module.css");
+  let project_root = ctx.options.cwd.to_string_lossy();
+  let css_modules = is_css_module.then(|| CssModulesConfig {
+    pattern: &ctx.options.css_modules_pattern,
+    project_root: &project_root,
+  });
+  let deps = match CssCompiler::scan_dependencies(source, resolved_id.id.to_string(), css_modules) {
+    Ok(deps) => deps,
+    Err(err) => {
+      return Ok(Err(vec![BuildDiagnostic::oxc_parse_error(
+        source.clone(),
+        resolved_id.id.to_string(),
+        String::new(),
+        err.message,
+        err.offset.map(|offset| LabeledSpan::new(None, offset, 0)).into_iter().collect(),
+      )]));
+    }
+  };
+
+  let mut url_assets = FxHashMap::default();
+  for specifier in deps.urls {
+    if url_assets.contains_key(&specifier) {
+      continue;
+    }
+    let (path, suffix) = specifier.split_at(specifier.find(['?', '#']).unwrap_or(specifier.len()));
+    let resolved = CreateModuleContext::resolve_id(
+      &ctx.options,
+      &ctx.resolver,
+      &ctx.plugin_driver,
+      &resolved_id.id,
+      &to_css_specifier(path),
+      ImportKind::Import,
+    )
+    .await?;
+    match resolved {
+      Ok(resolved) if resolved.is_external || resolved.ignored => {}
+      Ok(resolved) => {
+        let content = ctx.fs.read(Path::new(resolved.id.as_str()))?;
+        let reference_id = ctx.file_emitter.emit_file_for_module(
+          EmittedAsset {
+            name: Path::new(resolved.id.as_str())
+              .file_name()
+              .map(|name| name.to_string_lossy().into_owned()),
+            original_file_name: Some(resolved.id.to_string()),
+            file_name: None,
+            source: AssetSource::Buffer(content),
+          },
+          Some(&resolved_id.id),
+        );
+        let file_name = ctx.file_emitter.get_file_name(&reference_id);
+        url_assets.insert(specifier.clone(), format!("{file_name}{suffix}"));
+      }
+      Err(_) => {
+        warnings.push(
+          BuildDiagnostic::unresolved_import(specifier, resolved_id.id.as_str())
+            .with_severity_warning(),
+        );
+      }
+    }
+  }
+
+  let mut ecma_source = String::new();
+  for specifier in &deps.imports {
+    let specifier = serde_json::to_string(&to_css_specifier(specifier))?;
+    ecma_source.push_str(&format!("import {specifier};\n"));
+  }
+  if is_css_module {
+    ecma_source.push_str(&render_css_module_exports(&deps.css_module_exports)?);
+  }
+
+  Ok(Ok(CreateCssViewReturn {
+    css_view: CssView {
+      source: source.clone(),
+      import_records: IndexVec::default(),
+      url_assets,
+      is_css_module,
+    },
+    ecma_source,
+  }))
+}
+
+/// Scoped names are exported as named exports if the original names are valid identifiers, and all of them are
+/// exported as the properties of the default export. Unused named exports can be tree-shaken as usual.
+fn render_css_module_exports(exports: &[(String, String)]) -> anyhow::Result<String> {
+  let mut code = String::new();
+  let mut default_export_props = vec![];
+  for (name, value) in exports {
+    let value = serde_json::to_string(value)?;
+    let key = serde_json::to_string(name)?;
+    if is_validate_assignee_identifier_name(name) {
+      code.push_str(&format!("export const {name} = {value};\n"));
+      default_export_props.push(format!("  {key}: {name},\n"));
+    } else {
+      default_export_props.push(format!("  {key}: {value},\n"));
+    }
+  }
+  code.push_str(&format!("export default {{\n{}}};\n", default_export_props.concat()));
+  Ok(code)
+}
 
-pub fn create_css_view(source: &ArcStr) -> CssView {
-  CssView { source: source.clone(), import_records: IndexVec::default() }
+/// Specifiers in CSS are relative to the importer even without `./`, while `~pkg` refers to a package.
+fn to_css_specifier(specifier: &str) -> Cow<'_, str> {
+  if let Some(package) = specifier.strip_prefix('~') {
+    Cow::Borrowed(package)
+  } else if specifier.starts_with("./") || specifier.starts_with("../") {
+    Cow::Borrowed(specifier)
+  } else {
+    Cow::Owned(format!("./{specifier}"))
+  }
 }
@@ -1,12 +1,13 @@
 use crate::{
+  stages::link_stage::LinkStageOutput,
   types::generator::{GenerateContext, GenerateOutput, Generator},
   utils::{chunk::generate_rendered_chunk, render_ecma_module::render_ecma_module},
 };
 
 use anyhow::Result;
 use rolldown_common::{
-  EcmaAssetMeta, InstantiatedChunk, InstantiationKind, ModuleId, ModuleIdx, OutputFormat,
-  RenderedModule,
+  EcmaAssetMeta, InstantiatedChunk, InstantiationKind, ModuleId, ModuleIdx, NormalModule,
+  NormalizedBundlerOptions, OutputFormat, RenderedModule,
 };
 use rolldown_error::DiagnosableResult;
 use rolldown_plugin::HookAddonArgs;
@@ -17,7 +18,10 @@ use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
-use super::format::{app::render_app, cjs::render_cjs, esm::render_esm, iife::render_iife};
+use super::format::{
+  amd::render_amd, app::render_app, cjs::render_cjs, esm::render_esm, iife::render_iife,
+  umd::render_umd,
+};
 
 pub type RenderedModuleSources = Vec<(ModuleIdx, ModuleId, Option<Vec<Box<dyn Source + Send>>>)>;
 
@@ -42,16 +46,22 @@ impl Generator for EcmaGenerator {
           .map(|m| (m, codegen_ret.expect("should have codegen_ret")))
       })
       .map(|(m, codegen_ret)| {
-        (m.idx, m.id.clone(), render_ecma_module(m, ctx.options, codegen_ret))
+        let rendered_module =
+          generate_rendered_module(m, ctx.options, ctx.link_output, codegen_ret.source_text.len());
+        (m.idx, m.id.clone(), render_ecma_module(m, ctx.options, codegen_ret), rendered_module)
       })
       .collect::<Vec<_>>();
 
-    rendered_module_sources.iter().for_each(|(_, module_id, _)| {
-      // FIXME: NAPI-RS used CStr under the hood, so it can't handle null byte in the string.
-      if !module_id.starts_with('\0') {
-        rendered_modules.insert(module_id.clone(), RenderedModule { code: None });
-      }
-    });
+    let rendered_module_sources = rendered_module_sources
+      .into_iter()
+      .map(|(module_idx, module_id, sources, rendered_module)| {
+        // FIXME: NAPI-RS used CStr under the hood, so it can't handle null byte in the string.
+        if !module_id.starts_with('\0') {
+          rendered_modules.insert(module_id.clone(), rendered_module);
+        }
+        (module_idx, module_id, sources)
+      })
+      .collect::<RenderedModuleSources>();
 
     let rendered_chunk = generate_rendered_chunk(
       ctx.chunk,
@@ -119,6 +129,18 @@ impl Generator for EcmaGenerator {
           Err(errors) => return Ok(Err(errors)),
         }
       }
+      OutputFormat::Umd => {
+        match render_umd(ctx, rendered_module_sources, banner, footer, intro, outro) {
+          Ok(concat_source) => concat_source,
+          Err(errors) => return Ok(Err(errors)),
+        }
+      }
+      OutputFormat::Amd => {
+        match render_amd(ctx, rendered_module_sources, banner, footer, intro, outro) {
+          Ok(concat_source) => concat_source,
+          Err(errors) => return Ok(Err(errors)),
+        }
+      }
     };
 
     let (content, mut map) = concat_source.content_and_sourcemap();
@@ -161,3 +183,31 @@ impl Generator for EcmaGenerator {
     }))
   }
 }
+
+#[allow(clippy::cast_possible_truncation)]
+fn generate_rendered_module(
+  module: &NormalModule,
+  options: &NormalizedBundlerOptions,
+  link_output: &LinkStageOutput,
+  rendered_length: usize,
+) -> RenderedModule {
+  let mut rendered_exports = vec![];
+  let mut removed_exports = vec![];
+  for (name, local_export) in &module.named_exports {
+    let canonical_ref = link_output.symbols.par_canonical_ref_for(local_export.referenced);
+    if !options.treeshake.enabled() || link_output.used_symbol_refs.contains(&canonical_ref) {
+      rendered_exports.push(name.to_string());
+    } else {
+      removed_exports.push(name.to_string());
+    }
+  }
+  rendered_exports.sort_unstable();
+  removed_exports.sort_unstable();
+  RenderedModule {
+    code: None,
+    original_length: module.source.len() as u32,
+    rendered_length: rendered_length as u32,
+    rendered_exports,
+    removed_exports,
+  }
+}
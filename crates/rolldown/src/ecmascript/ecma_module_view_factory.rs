@@ -2,19 +2,25 @@ use arcstr::ArcStr;
 use oxc::{
   index::IndexVec,
   semantic::{ScopeTree, SymbolTable},
+  span::Span,
 };
 use rolldown_common::{
   side_effects::{DeterminedSideEffects, HookSideEffects},
-  AstScopes, EcmaView, ModuleDefFormat, ModuleId, ModuleIdx, ModuleType, ModuleView, SymbolRef,
-  TreeshakeOptions,
+  AssetSource, AstScopes, EcmaView, EmittedAsset, ImportKind, ModuleDefFormat, ModuleId, ModuleIdx,
+  ModuleType, ModuleView, Output, ResolvedId, SymbolRef, TreeshakeOptions,
 };
 use rolldown_ecmascript::EcmaAst;
-use rolldown_error::{DiagnosableResult, UnhandleableResult};
+use rolldown_error::{BuildDiagnostic, DiagnosableResult, UnhandleableResult};
+use rolldown_fs::FileSystem;
+use rolldown_resolver::ResolveError;
+use rolldown_rstr::Rstr;
 use rolldown_utils::{ecma_script::legitimize_identifier_name, path_ext::PathExt};
+use rustc_hash::FxHashMap;
+use std::{borrow::Cow, path::Path, sync::Arc};
 use sugar_path::SugarPath;
 
 use crate::{
-  ast_scanner::{AstScanner, ScanResult},
+  ast_scanner::{AstScanner, ScanResult, WorkerType},
   types::{
     ast_symbols::AstSymbols,
     module_factory::{
@@ -22,6 +28,7 @@ use crate::{
     },
   },
   utils::{
+    bundle_worker::{bundle_worker, WorkerBundle},
     make_ast_symbol_and_scope::make_ast_scopes_and_symbols,
     parse_to_ecma_ast::{parse_to_ecma_ast, ParseToEcmaAstResult},
   },
@@ -58,6 +65,166 @@ impl EcmaModuleViewFactory {
 
     Ok((ast_scopes, scan_result, ast_symbols, namespace_object_ref))
   }
+
+  /// Resolves the file referenced by `new URL(...)`, which is relative to the importer even without `./`.
+  async fn resolve_url(
+    ctx: &CreateModuleContext<'_>,
+    path: &str,
+  ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
+    let request = if path.starts_with("./") || path.starts_with("../") {
+      Cow::Borrowed(path)
+    } else {
+      Cow::Owned(format!("./{path}"))
+    };
+    CreateModuleContext::resolve_id(
+      ctx.options,
+      ctx.resolver,
+      ctx.plugin_driver,
+      &ctx.resolved_id.id,
+      &request,
+      ImportKind::Import,
+    )
+    .await
+  }
+
+  /// Emits the files referenced by `new URL('./foo.png', import.meta.url)` as assets, so the urls could be rewritten
+  /// to the emitted files. Unresolvable references are left as they are.
+  async fn emit_new_url_assets(
+    ctx: &mut CreateModuleContext<'_>,
+    references: Vec<(Span, Rstr)>,
+  ) -> anyhow::Result<FxHashMap<Span, ArcStr>> {
+    let mut new_url_assets = FxHashMap::default();
+    for (span, specifier) in references {
+      let (path, suffix) =
+        specifier.split_at(specifier.find(['?', '#']).unwrap_or(specifier.len()));
+      match Self::resolve_url(ctx, path).await? {
+        Ok(resolved) if resolved.is_external || resolved.ignored => {}
+        Ok(resolved) => {
+          let content = ctx.fs.read(Path::new(resolved.id.as_str()))?;
+          let reference_id = ctx.file_emitter.emit_file_for_module(
+            EmittedAsset {
+              name: Path::new(resolved.id.as_str())
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+              original_file_name: Some(resolved.id.to_string()),
+              file_name: None,
+              source: AssetSource::Buffer(content),
+            },
+            Some(&ctx.resolved_id.id),
+          );
+          // The asset isn't a module, so changes to it are only noticed through the importer.
+          ctx.plugin_driver.add_watch_file(resolved.id.clone(), Some(&ctx.resolved_id.id));
+          let file_name = ctx.file_emitter.get_file_name(&reference_id);
+          new_url_assets.insert(span, format!("{file_name}{suffix}").into());
+        }
+        Err(_) => {
+          ctx.warnings.push(
+            BuildDiagnostic::unresolved_import(specifier.to_string(), ctx.resolved_id.id.as_str())
+              .with_severity_warning(),
+          );
+        }
+      }
+    }
+    Ok(new_url_assets)
+  }
+
+  /// Bundles the workers created by `new Worker(new URL('./worker.js', import.meta.url))` and emits their outputs as
+  /// assets, so the urls could be rewritten to the entry chunks of the workers. Each worker is only bundled once in a
+  /// build, see [crate::utils::bundle_worker::WorkerBundles].
+  async fn emit_worker_chunks(
+    ctx: &mut CreateModuleContext<'_>,
+    references: Vec<(Span, Rstr, WorkerType)>,
+  ) -> anyhow::Result<DiagnosableResult<FxHashMap<Span, ArcStr>>> {
+    let mut worker_chunks = FxHashMap::default();
+    for (span, specifier, worker_type) in references {
+      let resolved = match Self::resolve_url(ctx, &specifier).await? {
+        Ok(resolved) if resolved.is_external || resolved.ignored => continue,
+        // A worker creating itself is left as it is, otherwise it would be bundled endlessly.
+        Ok(resolved) if resolved.id == ctx.resolved_id.id => continue,
+        Ok(resolved) => resolved,
+        Err(_) => {
+          ctx.warnings.push(
+            BuildDiagnostic::unresolved_import(specifier.to_string(), ctx.resolved_id.id.as_str())
+              .with_severity_warning(),
+          );
+          continue;
+        }
+      };
+      // Workers creating each other can't be bundled, since the outputs of a worker contain the url of the other one.
+      let enclosing_workers = &ctx.options.enclosing_workers;
+      if let Some(start) = enclosing_workers.iter().position(|id| *id == resolved.id) {
+        let paths = enclosing_workers[start..]
+          .iter()
+          .chain([&resolved.id])
+          .map(ToString::to_string)
+          .collect::<Vec<_>>();
+        return Ok(Err(vec![BuildDiagnostic::circular_dependency(paths)]));
+      }
+      let mut errors = vec![];
+      let cell = ctx.worker_bundles.cell(&resolved.id, worker_type);
+      let bundle = cell
+        .get_or_try_init(|| {
+          Self::bundle_and_emit_worker(ctx, &resolved.id, worker_type, &mut errors)
+        })
+        .await?;
+      if !errors.is_empty() {
+        return Ok(Err(errors));
+      }
+      // The worker failed to bundle for another module, which reports the errors.
+      let Some(bundle) = bundle else { continue };
+      for reference_id in &bundle.reference_ids {
+        ctx.file_emitter.add_file_for_module(reference_id, &ctx.resolved_id.id);
+      }
+      for file in &bundle.watch_files {
+        ctx.plugin_driver.add_watch_file(file.clone(), Some(&ctx.resolved_id.id));
+      }
+      worker_chunks.insert(span, bundle.entry_file_name.clone());
+    }
+    Ok(Ok(worker_chunks))
+  }
+
+  /// Bundles the worker and emits its outputs. Returns `None` and pushes the errors to `errors` if it fails.
+  async fn bundle_and_emit_worker(
+    ctx: &mut CreateModuleContext<'_>,
+    worker_id: &ArcStr,
+    worker_type: WorkerType,
+    errors: &mut Vec<BuildDiagnostic>,
+  ) -> anyhow::Result<Option<WorkerBundle>> {
+    let output =
+      bundle_worker(ctx.options, ctx.plugin_driver, ctx.resolver, ctx.fs, worker_id, worker_type)
+        .await?;
+    if !output.errors.is_empty() {
+      errors.extend(output.errors);
+      return Ok(None);
+    }
+    ctx.warnings.extend(output.warnings);
+    let mut entry_file_name = None;
+    let mut reference_ids = vec![];
+    for output in output.assets {
+      let (file_name, source) = match output {
+        Output::Chunk(chunk) => {
+          let file_name: ArcStr = chunk.filename.as_str().into();
+          if chunk.is_entry {
+            entry_file_name = Some(file_name.clone());
+          }
+          (file_name, AssetSource::String(chunk.code))
+        }
+        Output::Asset(asset) => (asset.filename, asset.source),
+      };
+      // The files are added to the importers with the reference ids, including the module bundling the worker.
+      reference_ids.push(ctx.file_emitter.emit_file(EmittedAsset {
+        name: None,
+        original_file_name: None,
+        file_name: Some(file_name),
+        source,
+      }));
+    }
+    Ok(Some(WorkerBundle {
+      entry_file_name: entry_file_name.expect("the worker should have an entry chunk"),
+      reference_ids,
+      watch_files: output.watch_files.into_iter().collect(),
+    }))
+  }
 }
 
 impl ModuleViewFactory for EcmaModuleViewFactory {
@@ -110,6 +277,9 @@ impl ModuleViewFactory for EcmaModuleViewFactory {
       star_exports,
       default_export_ref,
       imports,
+      new_url_references,
+      worker_references,
+      import_meta_references,
       exports_kind,
       warnings: scan_warnings,
       has_eval,
@@ -120,6 +290,13 @@ impl ModuleViewFactory for EcmaModuleViewFactory {
       return Ok(Err(errors));
     }
     ctx.warnings.extend(scan_warnings);
+    let mut new_url_assets = Self::emit_new_url_assets(ctx, new_url_references).await?;
+    match Self::emit_worker_chunks(ctx, worker_references).await? {
+      Ok(worker_chunks) => new_url_assets.extend(worker_chunks),
+      Err(errs) => {
+        return Ok(Err(errs));
+      }
+    }
 
     let mut imported_ids = vec![];
     let mut dynamically_imported_ids = vec![];
@@ -181,9 +358,11 @@ impl ModuleViewFactory for EcmaModuleViewFactory {
       named_exports,
       stmt_infos,
       imports,
+      new_url_assets,
+      import_meta_references,
       star_exports,
       default_export_ref,
-      scope,
+      scope: Arc::new(scope),
       exports_kind,
       namespace_object_ref,
       def_format: ctx.resolved_id.module_def_format,
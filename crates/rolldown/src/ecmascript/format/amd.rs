@@ -0,0 +1,195 @@
+//! This is the render function for AMD format.
+//! It wraps the chunk content in a factory function passed to `define`.
+//!
+//! 1. Render the banner if it exists.
+//! 2. Determine the export mode (from auto or manual exports) for entry chunks. Common chunks always use named exports.
+//! 3. Collect the dependencies of the chunk, which are passed to the factory function as arguments:
+//!       - `require`, if the chunk has dynamic imports. The local `require` resolves ids relative to the chunk.
+//!       - `exports`, if the chunk has named exports.
+//!       - Other chunks, bound to the same names as `require` bindings in CJS format.
+//!       - External modules.
+//!
+//!    Dependencies that are only imported for side effects are placed at the end without a parameter.
+//! 4. Check if the chunk is suitable for strict mode, and add `"use strict";` if necessary.
+//! 5. Render the intro if it exists.
+//! 6. Render the chunk content.
+//! 7. Render the exports if it exists. If you are using named export, it will modify the `exports` object.
+//!    If you are using default export, it will return the default value.
+//! 8. Render the outro if it exists.
+//! 9. Render the footer if it exists.
+
+use crate::ecmascript::format::utils::wrap::{
+  is_side_effect_only_import, render_wrapper_chunk_imports,
+};
+use crate::utils::chunk::namespace_marker::render_namespace_markers;
+use crate::{
+  ecmascript::ecma_generator::RenderedModuleSources,
+  types::generator::GenerateContext,
+  utils::chunk::{
+    determine_export_mode::determine_export_mode,
+    determine_use_strict::determine_use_strict,
+    render_chunk_exports::{get_export_items, render_chunk_exports},
+    render_document_current_script::render_document_current_script,
+  },
+};
+use rolldown_common::{ChunkKind, ImportKind, OutputExports};
+use rolldown_error::DiagnosableResult;
+use rolldown_sourcemap::{ConcatSource, RawSource};
+
+/// The main function for rendering the AMD format chunks.
+pub fn render_amd(
+  ctx: &mut GenerateContext<'_>,
+  module_sources: RenderedModuleSources,
+  banner: Option<String>,
+  footer: Option<String>,
+  intro: Option<String>,
+  outro: Option<String>,
+) -> DiagnosableResult<ConcatSource> {
+  let mut concat_source = ConcatSource::default();
+
+  if let Some(banner) = banner {
+    concat_source.add_source(Box::new(RawSource::new(banner)));
+  }
+
+  // Analyze the export information of the chunk.
+  let export_items = get_export_items(ctx.chunk, ctx.link_output);
+  let has_exports = !export_items.is_empty();
+  let has_default_export = export_items.iter().any(|(name, _)| name.as_str() == "default");
+
+  let export_mode = match ctx.chunk.kind {
+    ChunkKind::EntryPoint { module, .. } => {
+      let entry_module =
+        &ctx.link_output.module_table.modules[module].as_normal().expect("should be normal module");
+      determine_export_mode(ctx, entry_module, &export_items)?
+    }
+    // Common chunks expose their exports to other chunks through the `exports` object.
+    ChunkKind::Common => OutputExports::Named,
+  };
+  let named_exports = matches!(&export_mode, OutputExports::Named);
+  let is_entry = matches!(ctx.chunk.kind, ChunkKind::EntryPoint { .. });
+
+  let mut deps = vec![];
+  let mut params = vec![];
+  let mut side_effect_deps = vec![];
+
+  // Dynamic imports are rewritten to `require([...])` calls, which must use the local `require`, since the global
+  // one resolves relative ids against `baseUrl` instead of the chunk.
+  if has_dynamic_imports(ctx) {
+    deps.push("'require'".to_string());
+    params.push("require".to_string());
+  }
+
+  if has_exports && named_exports {
+    deps.push("'exports'".to_string());
+    params.push("exports".to_string());
+  }
+
+  // imports from other chunks
+  ctx.chunk.imports_from_other_chunks.iter().for_each(|(exporter_id, items)| {
+    let importee_chunk = &ctx.chunk_graph.chunk_table[*exporter_id];
+    let import_path = render_amd_import_path(ctx.chunk.import_path_for(importee_chunk));
+    if items.is_empty() {
+      side_effect_deps.push(format!("'{import_path}'"));
+    } else {
+      deps.push(format!("'{import_path}'"));
+      params.push(ctx.chunk.require_binding_names_for_other_chunks[exporter_id].clone());
+    }
+  });
+
+  // imports from external modules
+  let (import_code, externals) = render_wrapper_chunk_imports(ctx)?;
+  externals.iter().for_each(|external| {
+    if is_side_effect_only_import(external) {
+      side_effect_deps.push(format!("'{}'", external.path));
+    } else {
+      deps.push(format!("'{}'", external.path));
+      params.push(
+        ctx
+          .link_output
+          .symbols
+          .canonical_name_for(external.symbol_ref, &ctx.chunk.canonical_names)
+          .to_string(),
+      );
+    }
+  });
+
+  deps.extend(side_effect_deps);
+
+  // amd wrapper start
+  if deps.is_empty() {
+    concat_source
+      .add_source(Box::new(RawSource::new(format!("define(function({}) {{\n", params.join(", ")))));
+  } else {
+    concat_source.add_source(Box::new(RawSource::new(format!(
+      "define([{}], function({}) {{\n",
+      deps.join(", "),
+      params.join(", ")
+    ))));
+  }
+
+  if determine_use_strict(ctx) {
+    concat_source.add_source(Box::new(RawSource::new("\"use strict\";".to_string())));
+  }
+
+  if let Some(current_script) = render_document_current_script(ctx) {
+    concat_source.add_source(Box::new(RawSource::new(current_script)));
+  }
+
+  if let Some(intro) = intro {
+    concat_source.add_source(Box::new(RawSource::new(intro)));
+  }
+
+  if is_entry && named_exports {
+    if let Some(marker) =
+      render_namespace_markers(&ctx.options.es_module, has_default_export, false)
+    {
+      concat_source.add_source(Box::new(RawSource::new(marker.into())));
+    }
+  }
+
+  concat_source.add_source(Box::new(RawSource::new(import_code)));
+
+  // chunk content
+  module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+    if let Some(emitted_sources) = module_render_output {
+      for source in emitted_sources {
+        concat_source.add_source(source);
+      }
+    }
+  });
+
+  // amd exports
+  if let Some(exports) = render_chunk_exports(ctx, Some(&export_mode)) {
+    concat_source.add_source(Box::new(RawSource::new(exports)));
+  }
+
+  if let Some(outro) = outro {
+    concat_source.add_source(Box::new(RawSource::new(outro)));
+  }
+
+  // amd wrapper end
+  concat_source.add_source(Box::new(RawSource::new("});".to_string())));
+
+  if let Some(footer) = footer {
+    concat_source.add_source(Box::new(RawSource::new(footer)));
+  }
+
+  Ok(concat_source)
+}
+
+/// AMD loaders resolve module ids by appending `.js` themselves, so it should be removed from the import path.
+fn has_dynamic_imports(ctx: &GenerateContext<'_>) -> bool {
+  !ctx.options.inline_dynamic_imports
+    && ctx.chunk.modules.iter().any(|module_idx| {
+      ctx.link_output.module_table.modules[*module_idx].as_normal().is_some_and(|module| {
+        module.import_records.iter().any(|rec| matches!(rec.kind, ImportKind::DynamicImport))
+      })
+    })
+}
+
+pub fn render_amd_import_path(import_path: String) -> String {
+  match import_path.strip_suffix(".js") {
+    Some(stripped) => stripped.to_string(),
+    None => import_path,
+  }
+}
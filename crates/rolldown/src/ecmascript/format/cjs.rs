@@ -1,3 +1,6 @@
+use crate::ecmascript::format::utils::external_import::{
+  render_external_interop, validate_external_named_imports,
+};
 use crate::utils::chunk::determine_export_mode::determine_export_mode;
 use crate::utils::chunk::namespace_marker::render_namespace_markers;
 use crate::utils::chunk::render_chunk_exports::get_export_items;
@@ -6,16 +9,18 @@ use crate::{
   types::generator::GenerateContext,
   utils::chunk::{
     collect_render_chunk_imports::{
-      collect_render_chunk_imports, RenderImportDeclarationSpecifier,
+      collect_render_chunk_imports, RenderImportDeclarationSpecifier, RenderImportStmt,
     },
     determine_use_strict::determine_use_strict,
     render_chunk_exports::render_chunk_exports,
+    render_document_current_script::render_document_current_script,
   },
 };
 use rolldown_common::{ChunkKind, ExportsKind, Module, OutputExports, WrapKind};
 use rolldown_error::DiagnosableResult;
 use rolldown_sourcemap::{ConcatSource, RawSource};
 
+#[allow(clippy::too_many_lines)]
 pub fn render_cjs(
   ctx: &mut GenerateContext<'_>,
   module_sources: RenderedModuleSources,
@@ -34,6 +39,10 @@ pub fn render_cjs(
     concat_source.add_source(Box::new(RawSource::new("\"use strict\";".to_string())));
   }
 
+  if let Some(current_script) = render_document_current_script(ctx) {
+    concat_source.add_source(Box::new(RawSource::new(current_script)));
+  }
+
   if let Some(intro) = intro {
     concat_source.add_source(Box::new(RawSource::new(intro)));
   }
@@ -100,7 +109,7 @@ pub fn render_cjs(
     _ => {}
   }
 
-  concat_source.add_source(Box::new(RawSource::new(render_cjs_chunk_imports(ctx))));
+  concat_source.add_source(Box::new(RawSource::new(render_cjs_chunk_imports(ctx)?)));
 
   // chunk content
   module_sources_peekable.for_each(|(_, _, module_render_output)| {
@@ -150,7 +159,7 @@ pub fn render_cjs(
   Ok(concat_source)
 }
 
-fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
+fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> DiagnosableResult<String> {
   let render_import_stmts =
     collect_render_chunk_imports(ctx.chunk, ctx.link_output, ctx.chunk_graph);
 
@@ -170,16 +179,18 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
     }
   });
 
+  let mut errors = vec![];
   render_import_stmts.iter().for_each(|stmt| {
-    if !stmt.is_external() {
+    let RenderImportStmt::ExternalRenderImportStmt(stmt) = stmt else {
       return;
-    }
-    let require_path_str = format!("require(\"{}\")", &stmt.path());
-    match &stmt.specifiers() {
+    };
+    let require_path_str = format!("require(\"{}\")", &stmt.path);
+    match &stmt.specifiers {
       RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
         if specifiers.is_empty() {
           s.push_str(&format!("{require_path_str};\n"));
         } else {
+          validate_external_named_imports(ctx, stmt, &mut errors);
           let specifiers = specifiers
             .iter()
             .map(|specifier| {
@@ -193,36 +204,22 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
           s.push_str(&format!(
             "const {{ {} }} = {};\n",
             specifiers.join(", "),
-            if stmt.is_external() {
-              let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
-                .link_output
-                .symbols
-                .par_canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
-
-              format!("{to_esm_fn_name}({require_path_str})")
-            } else {
-              require_path_str
-            }
+            render_external_interop(ctx, stmt, require_path_str)
           ));
         }
       }
       RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
         s.push_str(&format!(
           "const {alias} = {};\n",
-          if stmt.is_external() {
-            let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
-              .link_output
-              .symbols
-              .par_canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
-
-            format!("{to_esm_fn_name}({require_path_str})")
-          } else {
-            require_path_str
-          }
+          render_external_interop(ctx, stmt, require_path_str)
         ));
       }
     }
   });
 
-  s
+  if errors.is_empty() {
+    Ok(s)
+  } else {
+    Err(errors)
+  }
 }
@@ -23,10 +23,10 @@
 //! 10. Render the footer if it exists.
 
 use crate::ecmascript::format::utils::namespace::generate_identifier;
-use crate::utils::chunk::collect_render_chunk_imports::{
-  collect_render_chunk_imports, ExternalRenderImportStmt, RenderImportDeclarationSpecifier,
-  RenderImportStmt,
+use crate::ecmascript::format::utils::wrap::{
+  is_side_effect_only_import, render_wrapper_chunk_imports, resolve_external_global_name,
 };
+use crate::utils::chunk::collect_render_chunk_imports::ExternalRenderImportStmt;
 use crate::utils::chunk::namespace_marker::render_namespace_markers;
 use crate::{
   ecmascript::ecma_generator::RenderedModuleSources,
@@ -35,16 +35,14 @@ use crate::{
     determine_export_mode::determine_export_mode,
     determine_use_strict::determine_use_strict,
     render_chunk_exports::{get_export_items, render_chunk_exports},
+    render_document_current_script::render_document_current_script,
   },
 };
-use arcstr::ArcStr;
-use itertools::Itertools;
 use rolldown_common::{ChunkKind, OutputExports};
-use rolldown_error::{BuildDiagnostic, DiagnosableResult};
+use rolldown_error::DiagnosableResult;
 use rolldown_sourcemap::{ConcatSource, RawSource};
 use rolldown_utils::ecma_script::legitimize_identifier_name;
 
-// TODO refactor it to `wrap.rs` to reuse it for other formats (e.g. amd, umd).
 /// The main function for rendering the IIFE format chunks.
 pub fn render_iife(
   ctx: &mut GenerateContext<'_>,
@@ -72,7 +70,9 @@ pub fn render_iife(
     ChunkKind::EntryPoint { module, .. } => {
       &ctx.link_output.module_table.modules[module].as_normal().expect("should be normal module")
     }
-    ChunkKind::Common => unreachable!("iife should be entry point chunk"),
+    // Common chunks only exist if the bundle is split, which is already reported as `INVALID_OPTION` while
+    // generating chunks.
+    ChunkKind::Common => return Err(vec![]),
   };
 
   // We need to transform the `OutputExports::Auto` to suitable `OutputExports`.
@@ -81,7 +81,9 @@ pub fn render_iife(
   let named_exports = matches!(&export_mode, OutputExports::Named);
 
   // It is similar to CJS.
-  let (import_code, externals) = render_iife_chunk_imports(ctx);
+  // Empty specifiers can be ignored in IIFE.
+  let (import_code, mut externals) = render_wrapper_chunk_imports(ctx)?;
+  externals.retain(|external| !is_side_effect_only_import(external));
 
   // Generate the identifier for the IIFE wrapper function.
   // You can refer to the function for more details.
@@ -125,6 +127,10 @@ pub fn render_iife(
     concat_source.add_source(Box::new(RawSource::new("\"use strict\";".to_string())));
   }
 
+  if let Some(current_script) = render_document_current_script(ctx) {
+    concat_source.add_source(Box::new(RawSource::new(current_script)));
+  }
+
   if let Some(intro) = intro {
     concat_source.add_source(Box::new(RawSource::new(intro)));
   }
@@ -177,55 +183,6 @@ pub fn render_iife(
   Ok(concat_source)
 }
 
-/// Handling external imports needs to modify the arguments of the wrapper function.
-fn render_iife_chunk_imports(ctx: &GenerateContext<'_>) -> (String, Vec<ExternalRenderImportStmt>) {
-  let render_import_stmts =
-    collect_render_chunk_imports(ctx.chunk, ctx.link_output, ctx.chunk_graph);
-
-  let mut import_code = String::new();
-  let externals = render_import_stmts
-    .into_iter()
-    .filter_map(|stmt| {
-      if let RenderImportStmt::ExternalRenderImportStmt(external_stmt) = stmt {
-        let symbol_name = ctx
-          .link_output
-          .symbols
-          .canonical_name_for(external_stmt.symbol_ref, &ctx.chunk.canonical_names);
-        match &external_stmt.specifiers {
-          RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
-            // Empty specifiers can be ignored in IIFE.
-            if specifiers.is_empty() {
-              None
-            } else {
-              let specifiers = specifiers
-                .iter()
-                .map(|specifier| {
-                  if let Some(alias) = &specifier.alias {
-                    format!("{}: {alias}", specifier.imported)
-                  } else {
-                    specifier.imported.to_string()
-                  }
-                })
-                .collect::<Vec<_>>();
-              import_code
-                .push_str(&format!("const {{ {} }} = {symbol_name};\n", specifiers.join(", ")));
-              Some(external_stmt)
-            }
-          }
-          RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
-            import_code.push_str(&format!("const {alias} = {symbol_name};\n"));
-            Some(external_stmt)
-          }
-        }
-      } else {
-        None
-      }
-    })
-    .collect_vec();
-
-  (import_code, externals)
-}
-
 /// Rendering the arguments of the wrapper function, including the function arguments and calling arguments.
 /// - If `output.exports` is `named`, the first argument is `exports`.
 ///    - If you are using `extend: true`, the inputted argument will be extended;
@@ -248,22 +205,13 @@ fn render_iife_arguments(
   } else {
     vec![]
   };
-  let globals = &ctx.options.globals;
   externals.iter().for_each(|external| {
     let symbol_name =
       ctx.link_output.symbols.canonical_name_for(external.symbol_ref, &ctx.chunk.canonical_names);
     input_args.push(symbol_name.as_str());
 
-    if let Some(global) = globals.get(external.path.as_str()) {
-      output_args.push(legitimize_identifier_name(global).to_string());
-    } else {
-      let target = legitimize_identifier_name(external.path.as_str()).to_string();
-      ctx.warnings.push(
-        BuildDiagnostic::missing_global_name(external.path.clone(), ArcStr::from(&target))
-          .with_severity_warning(),
-      );
-      output_args.push(target);
-    }
+    let global = resolve_external_global_name(ctx, external);
+    output_args.push(legitimize_identifier_name(&global).to_string());
   });
   (input_args.join(", "), output_args.join(", "))
 }
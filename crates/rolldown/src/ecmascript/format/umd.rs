@@ -0,0 +1,258 @@
+//! This is the render function for UMD format.
+//! It wraps the chunk content in a factory function, and calls the factory with the dependencies
+//! provided by the detected module system.
+//!
+//! 1. Render the banner if it exists.
+//! 2. Determine the export mode (from auto or manual exports). UMD bundles that have exports must have
+//!    a `output.name`, otherwise the exports are not accessible without a module loader.
+//! 3. Render the UMD wrapper, which calls the factory in three different ways:
+//!       - CommonJS: the dependencies are `require`d, and the `exports` object is passed or `module.exports` is assigned.
+//!       - AMD: the dependencies are declared in `define`.
+//!       - Global: the dependencies are read from the global variables according to `output.globals`,
+//!         and the exports are assigned to the global variable named by `output.name` (or extend it if `output.extend` is true).
+//! 4. Check if the chunk is suitable for strict mode, and add `"use strict";` if necessary.
+//! 5. Render the intro if it exists.
+//! 6. Render the chunk content.
+//! 7. Render the exports if it exists. If you are using named export, it will modify the `exports` object.
+//!    If you are using default export, it will return the default value.
+//! 8. Render the outro if it exists.
+//! 9. Render the footer if it exists.
+
+use crate::ecmascript::format::utils::namespace::generate_caller;
+use crate::ecmascript::format::utils::wrap::{
+  is_side_effect_only_import, render_wrapper_chunk_imports, resolve_external_global_name,
+};
+use crate::utils::chunk::namespace_marker::render_namespace_markers;
+use crate::{
+  ecmascript::ecma_generator::RenderedModuleSources,
+  types::generator::GenerateContext,
+  utils::chunk::{
+    determine_export_mode::determine_export_mode,
+    determine_use_strict::determine_use_strict,
+    render_chunk_exports::{get_export_items, render_chunk_exports},
+    render_document_current_script::render_document_current_script,
+  },
+};
+use rolldown_common::{ChunkKind, OutputExports};
+use rolldown_error::{BuildDiagnostic, DiagnosableResult};
+use rolldown_sourcemap::{ConcatSource, RawSource};
+
+/// The main function for rendering the UMD format chunks.
+#[allow(clippy::too_many_lines)]
+pub fn render_umd(
+  ctx: &mut GenerateContext<'_>,
+  module_sources: RenderedModuleSources,
+  banner: Option<String>,
+  footer: Option<String>,
+  intro: Option<String>,
+  outro: Option<String>,
+) -> DiagnosableResult<ConcatSource> {
+  let mut concat_source = ConcatSource::default();
+
+  if let Some(banner) = banner {
+    concat_source.add_source(Box::new(RawSource::new(banner)));
+  }
+
+  // Analyze the export information of the chunk.
+  let export_items = get_export_items(ctx.chunk, ctx.link_output);
+  let has_exports = !export_items.is_empty();
+  let has_default_export = export_items.iter().any(|(name, _)| name.as_str() == "default");
+
+  let entry_module = match ctx.chunk.kind {
+    ChunkKind::EntryPoint { module, .. } => {
+      &ctx.link_output.module_table.modules[module].as_normal().expect("should be normal module")
+    }
+    // Common chunks only exist if the bundle is split, which is already reported as `INVALID_OPTION` while
+    // generating chunks.
+    ChunkKind::Common => return Err(vec![]),
+  };
+
+  // We need to transform the `OutputExports::Auto` to suitable `OutputExports`.
+  let export_mode = determine_export_mode(ctx, entry_module, &export_items)?;
+  let named_exports = matches!(&export_mode, OutputExports::Named);
+  let default_export = matches!(&export_mode, OutputExports::Default);
+
+  // Unlike IIFE, the exports of UMD bundles are not accessible at all in the global environment without a name.
+  let name = ctx.options.name.as_deref().filter(|name| !name.is_empty());
+  if has_exports && name.is_none() {
+    return Err(vec![BuildDiagnostic::missing_name_option_for_umd_export()]);
+  }
+
+  let (import_code, externals) = render_wrapper_chunk_imports(ctx)?;
+  // Externals imported only for side effects don't need a parameter in the factory function,
+  // so they are placed at the end of the dependencies.
+  let (externals, side_effect_externals): (Vec<_>, Vec<_>) =
+    externals.into_iter().partition(|external| !is_side_effect_only_import(external));
+
+  let needs_exports_arg = has_exports && named_exports;
+
+  let mut factory_params = vec![];
+  let mut cjs_args = vec![];
+  let mut amd_deps = vec![];
+  let mut global_args = vec![];
+
+  if needs_exports_arg {
+    factory_params.push("exports".to_string());
+    cjs_args.push("exports".to_string());
+    amd_deps.push("'exports'".to_string());
+    let (initializations, accessor) = generate_global_accessor(name.unwrap_or_default());
+    let exports_target = if ctx.options.extend {
+      format!("{accessor} = {accessor} || {{}}")
+    } else {
+      format!("{accessor} = {{}}")
+    };
+    if initializations.is_empty() {
+      global_args.push(exports_target);
+    } else {
+      global_args.push(format!("({}, {exports_target})", initializations.join(", ")));
+    }
+  }
+
+  for external in &externals {
+    let symbol_name =
+      ctx.link_output.symbols.canonical_name_for(external.symbol_ref, &ctx.chunk.canonical_names);
+    factory_params.push(symbol_name.to_string());
+    let global = resolve_external_global_name(ctx, external);
+    global_args.push(generate_global_accessor(&global).1);
+  }
+
+  for external in externals.iter().chain(side_effect_externals.iter()) {
+    cjs_args.push(format!("require('{}')", external.path));
+    amd_deps.push(format!("'{}'", external.path));
+  }
+
+  let cjs_factory_call = format!("factory({})", cjs_args.join(", "));
+  let cjs_branch =
+    if default_export { format!("module.exports = {cjs_factory_call}") } else { cjs_factory_call };
+
+  let amd_branch = if amd_deps.is_empty() {
+    "define(factory)".to_string()
+  } else {
+    format!("define([{}], factory)", amd_deps.join(", "))
+  };
+
+  let global_factory_call = format!("factory({})", global_args.join(", "));
+  let global_branch = match name {
+    Some(name) if default_export => {
+      let (mut initializations, accessor) = generate_global_accessor(name);
+      initializations.push(format!("{accessor} = {global_factory_call}"));
+      format!(
+        "(global = typeof globalThis !== 'undefined' ? globalThis : global || self, {})",
+        initializations.join(", ")
+      )
+    }
+    _ if !global_args.is_empty() => format!(
+      "(global = typeof globalThis !== 'undefined' ? globalThis : global || self, {global_factory_call})"
+    ),
+    _ => global_factory_call,
+  };
+
+  // umd wrapper start
+  concat_source.add_source(Box::new(RawSource::new(format!(
+    "(function(global, factory) {{
+  typeof exports === 'object' && typeof module !== 'undefined' ? {cjs_branch} :
+  typeof define === 'function' && define.amd ? {amd_branch} :
+  {global_branch};
+}})(this, function({}) {{\n",
+    factory_params.join(", ")
+  ))));
+
+  if determine_use_strict(ctx) {
+    concat_source.add_source(Box::new(RawSource::new("\"use strict\";".to_string())));
+  }
+
+  if let Some(current_script) = render_document_current_script(ctx) {
+    concat_source.add_source(Box::new(RawSource::new(current_script)));
+  }
+
+  if let Some(intro) = intro {
+    concat_source.add_source(Box::new(RawSource::new(intro)));
+  }
+
+  if named_exports {
+    if let Some(marker) =
+      render_namespace_markers(&ctx.options.es_module, has_default_export, false)
+    {
+      concat_source.add_source(Box::new(RawSource::new(marker.into())));
+    }
+  }
+
+  concat_source.add_source(Box::new(RawSource::new(import_code)));
+
+  // chunk content
+  module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+    if let Some(emitted_sources) = module_render_output {
+      for source in emitted_sources {
+        concat_source.add_source(source);
+      }
+    }
+  });
+
+  // umd exports
+  if let Some(exports) = render_chunk_exports(ctx, Some(&export_mode)) {
+    concat_source.add_source(Box::new(RawSource::new(exports)));
+  }
+
+  if let Some(outro) = outro {
+    concat_source.add_source(Box::new(RawSource::new(outro)));
+  }
+
+  // umd wrapper end
+  concat_source.add_source(Box::new(RawSource::new("});".to_string())));
+
+  if let Some(footer) = footer {
+    concat_source.add_source(Box::new(RawSource::new(footer)));
+  }
+
+  Ok(concat_source)
+}
+
+/// Generates the property access on `global` for the given (maybe namespaced) name.
+///
+/// # Example
+///
+/// for the name `namespace.module.hello`, it will generate:
+///
+/// - The initializations of the intermediate namespaces:
+///    ```js
+///    global.namespace = global.namespace || {}, global.namespace.module = global.namespace.module || {}
+///    ```
+/// - The final accessor:
+///    ```js
+///    global.namespace.module.hello
+///    ```
+fn generate_global_accessor(name: &str) -> (Vec<String>, String) {
+  let mut initializations = vec![];
+  let mut accessor = String::from("global");
+
+  let parts: Vec<&str> = name.split('.').collect();
+
+  for (i, part) in parts.iter().enumerate() {
+    accessor.push_str(&generate_caller(part));
+
+    if i < parts.len() - 1 {
+      initializations.push(format!("{accessor} = {accessor} || {{}}"));
+    }
+  }
+
+  (initializations, accessor)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_generate_global_accessor() {
+    let (initializations, accessor) = generate_global_accessor("a.b.c");
+    assert_eq!(initializations, vec!["global.a = global.a || {}", "global.a.b = global.a.b || {}"]);
+    assert_eq!(accessor, "global.a.b.c");
+  }
+
+  #[test]
+  fn test_generate_global_accessor_with_reserved_name() {
+    let (initializations, accessor) = generate_global_accessor("if");
+    assert!(initializations.is_empty());
+    assert_eq!(accessor, "global[\"if\"]");
+  }
+}
@@ -0,0 +1,56 @@
+//! Items shared by the formats that get external modules through `require()` or wrapper arguments
+//! (CJS, IIFE, UMD and AMD), where `output.interop` decides how the value is turned into a namespace.
+use rolldown_common::{Interop, InteropType};
+use rolldown_error::BuildDiagnostic;
+
+use crate::types::generator::GenerateContext;
+use crate::utils::chunk::collect_render_chunk_imports::{
+  ExternalRenderImportStmt, RenderImportDeclarationSpecifier,
+};
+
+/// Wraps the value of the external module with `__toESM` according to its `output.interop`.
+pub fn render_external_interop(
+  ctx: &GenerateContext<'_>,
+  stmt: &ExternalRenderImportStmt,
+  value: String,
+) -> String {
+  let importee = ctx.link_output.module_table.modules[stmt.importee]
+    .as_external()
+    .expect("Should be external module here");
+  let Some(interop) = importee.to_esm_interop() else {
+    return value;
+  };
+  let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
+    .link_output
+    .symbols
+    .par_canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
+  match interop {
+    Interop::Babel => format!("{to_esm_fn_name}({value})"),
+    Interop::Node => format!("{to_esm_fn_name}({value}, 1)"),
+  }
+}
+
+/// External modules with `defaultOnly` interop only have the default export, so importing names from them is an
+/// error.
+pub fn validate_external_named_imports(
+  ctx: &GenerateContext<'_>,
+  stmt: &ExternalRenderImportStmt,
+  errors: &mut Vec<BuildDiagnostic>,
+) {
+  let RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) = &stmt.specifiers else {
+    return;
+  };
+  let importee = ctx.link_output.module_table.modules[stmt.importee]
+    .as_external()
+    .expect("Should be external module here");
+  if !matches!(importee.interop, InteropType::DefaultOnly) {
+    return;
+  }
+  errors.extend(
+    specifiers.iter().filter(|specifier| specifier.imported.as_str() != "default").map(
+      |specifier| {
+        BuildDiagnostic::unexpected_named_import(stmt.path.clone(), specifier.imported.clone())
+      },
+    ),
+  );
+}
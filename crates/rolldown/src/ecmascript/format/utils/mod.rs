@@ -1 +1,3 @@
+pub mod external_import;
 pub mod namespace;
+pub mod wrap;
@@ -0,0 +1,103 @@
+//! Items shared by the formats that wrap the chunk content in a function (IIFE, UMD and AMD).
+//!
+//! In these formats, external modules are not imported by statements. Instead, they are passed
+//! to the wrapper function as arguments, and the named imports are destructured from them.
+use arcstr::ArcStr;
+use itertools::Itertools;
+use rolldown_error::{BuildDiagnostic, DiagnosableResult};
+use rolldown_utils::ecma_script::legitimize_identifier_name;
+
+use super::external_import::{render_external_interop, validate_external_named_imports};
+use crate::types::generator::GenerateContext;
+use crate::utils::chunk::collect_render_chunk_imports::{
+  collect_render_chunk_imports, ExternalRenderImportStmt, RenderImportDeclarationSpecifier,
+  RenderImportStmt,
+};
+
+/// Collects the external imports of the chunk and renders the code destructuring the named imports
+/// from the arguments of the wrapper function.
+///
+/// External modules that are only imported for side effects are returned as well, callers could decide
+/// whether they should be passed as dependencies or not. The arguments are wrapped with `__toESM` according to
+/// `output.interop`, like `require()` in CJS.
+pub fn render_wrapper_chunk_imports(
+  ctx: &GenerateContext<'_>,
+) -> DiagnosableResult<(String, Vec<ExternalRenderImportStmt>)> {
+  let render_import_stmts =
+    collect_render_chunk_imports(ctx.chunk, ctx.link_output, ctx.chunk_graph);
+
+  let mut import_code = String::new();
+  let mut errors = vec![];
+  let externals = render_import_stmts
+    .into_iter()
+    .filter_map(|stmt| {
+      let RenderImportStmt::ExternalRenderImportStmt(external_stmt) = stmt else {
+        return None;
+      };
+      let symbol_name = ctx
+        .link_output
+        .symbols
+        .canonical_name_for(external_stmt.symbol_ref, &ctx.chunk.canonical_names);
+      match &external_stmt.specifiers {
+        RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
+          if !specifiers.is_empty() {
+            validate_external_named_imports(ctx, &external_stmt, &mut errors);
+            let specifiers = specifiers
+              .iter()
+              .map(|specifier| {
+                if let Some(alias) = &specifier.alias {
+                  format!("{}: {alias}", specifier.imported)
+                } else {
+                  specifier.imported.to_string()
+                }
+              })
+              .collect::<Vec<_>>();
+            import_code.push_str(&format!(
+              "const {{ {} }} = {};\n",
+              specifiers.join(", "),
+              render_external_interop(ctx, &external_stmt, symbol_name.to_string())
+            ));
+          }
+        }
+        RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
+          import_code.push_str(&format!(
+            "const {alias} = {};\n",
+            render_external_interop(ctx, &external_stmt, symbol_name.to_string())
+          ));
+        }
+      }
+      Some(external_stmt)
+    })
+    .collect_vec();
+
+  if errors.is_empty() {
+    Ok((import_code, externals))
+  } else {
+    Err(errors)
+  }
+}
+
+/// Returns `true` if the external module is imported only for its side effects, e.g. `import 'foo'`.
+pub fn is_side_effect_only_import(external: &ExternalRenderImportStmt) -> bool {
+  matches!(&external.specifiers, RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) if specifiers.is_empty())
+}
+
+/// Get the global variable name of the external module.
+///
+/// - If the global variable is defined in `output.globals`, it will be returned directly.
+/// - If not, you will be warned and rolldown will guess the name by legitimizing the module id.
+pub fn resolve_external_global_name(
+  ctx: &mut GenerateContext<'_>,
+  external: &ExternalRenderImportStmt,
+) -> String {
+  if let Some(global) = ctx.options.globals.get(external.path.as_str()) {
+    global.clone()
+  } else {
+    let target = legitimize_identifier_name(external.path.as_str()).to_string();
+    ctx.warnings.push(
+      BuildDiagnostic::missing_global_name(external.path.clone(), ArcStr::from(&target))
+        .with_severity_warning(),
+    );
+    target
+  }
+}
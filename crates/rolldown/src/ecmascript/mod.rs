@@ -1,3 +1,3 @@
 pub mod ecma_generator;
 pub mod ecma_module_view_factory;
-mod format;
+pub(crate) mod format;
@@ -11,21 +11,31 @@ mod stages;
 mod type_alias;
 mod types;
 mod utils;
+mod watcher;
 
 use std::sync::Arc;
 
-use rolldown_fs::OsFileSystem;
 use rolldown_resolver::Resolver;
 
-pub(crate) type SharedResolver = Arc<Resolver<OsFileSystem>>;
+pub(crate) type SharedResolver = Arc<Resolver>;
 pub(crate) type SharedOptions = SharedNormalizedBundlerOptions;
 
 pub use crate::{
-  bundler::Bundler, bundler_builder::BundlerBuilder, types::bundle_output::BundleOutput,
+  bundler::Bundler,
+  bundler_builder::BundlerBuilder,
+  types::bundle_output::BundleOutput,
+  watcher::{Watcher, WatcherEvent},
 };
 
 pub use rolldown_common::bundler_options::*;
 
+#[cfg(feature = "deserialize_bundler_options")]
+pub use crate::utils::validate_options::{
+  check_bundler_options, deserialize_bundler_options, validate_bundler_options,
+};
+
 pub use rolldown_resolver::ResolveOptions;
 
+pub use rolldown_fs::FileSystem;
+
 pub use rolldown_plugin as plugin;
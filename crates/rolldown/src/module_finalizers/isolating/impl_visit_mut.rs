@@ -6,7 +6,10 @@ use rolldown_common::{Interop, Module};
 use rolldown_ecmascript::TakeIn;
 use rolldown_utils::ecma_script::legitimize_identifier_name;
 
-use crate::utils::call_expression_ext::CallExpressionExt;
+use crate::{
+  module_finalizers::{import_meta_url_expr, rewrite_new_url_expr},
+  utils::call_expression_ext::CallExpressionExt,
+};
 
 use super::IsolatingModuleFinalizer;
 
@@ -103,6 +106,20 @@ impl<'me, 'ast> VisitMut<'ast> for IsolatingModuleFinalizer<'me, 'ast> {
     walk_mut::walk_expression(self, expr);
   }
 
+  fn visit_new_expression(&mut self, expr: &mut ast::NewExpression<'ast>) {
+    rewrite_new_url_expr(expr, self.ctx.module, self.ctx.chunk, self.ctx.options, &self.snippet);
+    if self.ctx.module.new_url_assets.contains_key(&expr.span) {
+      if let (Some(base), Some(base_url)) = (
+        expr.arguments.get_mut(1),
+        import_meta_url_expr(self.ctx.options, self.ctx.chunk, &self.snippet),
+      ) {
+        *base = self.snippet.builder.argument_expression(base_url);
+      }
+    }
+
+    walk_mut::walk_new_expression(self, expr);
+  }
+
   fn visit_call_expression(&mut self, expr: &mut ast::CallExpression<'ast>) {
     if expr.is_global_require_call(self.scope) {
       if let Some(ast::Argument::StringLiteral(request)) = expr.arguments.first_mut() {
@@ -3,11 +3,11 @@ use oxc::{
   ast::ast::{ObjectPropertyKind, Statement},
   span::CompactStr,
 };
-use rolldown_common::{AstScopes, IndexModules, NormalModule};
+use rolldown_common::{AstScopes, Chunk, IndexModules, NormalModule};
 use rolldown_ecmascript::AstSnippet;
 use rustc_hash::FxHashSet;
 
-use crate::types::symbols::Symbols;
+use crate::{types::symbols::Symbols, SharedOptions};
 
 mod impl_visit_mut;
 
@@ -15,6 +15,8 @@ pub struct IsolatingModuleFinalizerContext<'me> {
   pub module: &'me NormalModule,
   pub modules: &'me IndexModules,
   pub symbols: &'me Symbols,
+  pub chunk: &'me Chunk,
+  pub options: &'me SharedOptions,
 }
 
 pub struct IsolatingModuleFinalizer<'me, 'ast> {
@@ -1,2 +1,78 @@
+use oxc::{ast::ast, span::GetSpan};
+use rolldown_common::{Chunk, NormalModule, NormalizedBundlerOptions, OutputFormat};
+use rolldown_ecmascript::AstSnippet;
+use sugar_path::SugarPath;
+
 pub mod isolating;
 pub mod scope_hoisting;
+
+/// Rewrites the specifier of `new URL('./foo.png', import.meta.url)` to refer to the emitted asset relative to the
+/// chunk. `import.meta.url` is lowered separately.
+pub fn rewrite_new_url_expr<'ast>(
+  expr: &mut ast::NewExpression<'ast>,
+  module: &NormalModule,
+  chunk: &Chunk,
+  options: &NormalizedBundlerOptions,
+  snippet: &AstSnippet<'ast>,
+) {
+  let Some(file_name) = module.new_url_assets.get(&expr.span) else {
+    return;
+  };
+  let Some(specifier) = expr.arguments.first_mut() else {
+    return;
+  };
+  let asset_path = options.cwd.join(&options.dir).join(file_name.as_str());
+  let relative_path = chunk.relative_path_for(&asset_path.to_slash_lossy());
+  *specifier =
+    ast::Argument::StringLiteral(snippet.alloc_string_literal(&relative_path, specifier.span()));
+}
+
+/// The binding of `document.currentScript`, which is captured at the top of the wrapper of chunks that lower
+/// `import.meta.url` to the url of their script.
+pub const DOCUMENT_CURRENT_SCRIPT: &str = "_documentCurrentScript";
+
+/// Returns the replacement of `import.meta.url` for formats without `import.meta`, which is the url of `chunk`.
+///
+/// Wrapped formats use the `src` of the script captured in `DOCUMENT_CURRENT_SCRIPT`, and fall back to resolving the
+/// chunk against `document.baseURI`. Formats that might run in Node.js check for `document` first. Classic workers are
+/// bundled as IIFE and have no `document`, but know their own url.
+pub fn import_meta_url_expr<'ast>(
+  options: &NormalizedBundlerOptions,
+  chunk: &Chunk,
+  snippet: &AstSnippet<'ast>,
+) -> Option<ast::Expression<'ast>> {
+  let file_name = chunk.preliminary_filename.as_deref().map_or("", |name| name.as_str());
+  match options.format {
+    OutputFormat::Esm => None,
+    OutputFormat::Iife if !options.enclosing_workers.is_empty() => {
+      Some(snippet.worker_location_url_expr())
+    }
+    OutputFormat::Cjs | OutputFormat::Umd => {
+      Some(snippet.cjs_file_url_expr(DOCUMENT_CURRENT_SCRIPT, file_name))
+    }
+    OutputFormat::Iife | OutputFormat::Amd => {
+      Some(snippet.document_script_url_expr(DOCUMENT_CURRENT_SCRIPT, file_name))
+    }
+    OutputFormat::App => Some(snippet.document_base_url_expr(file_name)),
+  }
+}
+
+/// Whether `import.meta.url` is lowered by `import_meta_url_expr` to an expression reading `DOCUMENT_CURRENT_SCRIPT`.
+pub fn lowers_import_meta_url_to_current_script(options: &NormalizedBundlerOptions) -> bool {
+  match options.format {
+    OutputFormat::Iife => options.enclosing_workers.is_empty(),
+    OutputFormat::Cjs | OutputFormat::Umd | OutputFormat::Amd => true,
+    OutputFormat::Esm | OutputFormat::App => false,
+  }
+}
+
+/// Whether `import.meta.<property>`, or `import.meta` itself if `property` is `None`, keeps its meaning in `format`.
+/// Unsupported properties are replaced with `undefined`.
+pub fn is_import_meta_property_supported(format: &OutputFormat, property: Option<&str>) -> bool {
+  match property {
+    _ if matches!(format, OutputFormat::Esm) => true,
+    None | Some("url" | "env") => true,
+    Some("filename" | "dirname") => matches!(format, OutputFormat::Cjs),
+    Some(_) => false,
+  }
+}
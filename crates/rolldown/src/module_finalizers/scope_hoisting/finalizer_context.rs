@@ -1,3 +1,4 @@
+use oxc::span::Span;
 use rolldown_common::{IndexModules, ModuleIdx, NormalModule, SymbolRef};
 
 use rolldown_rstr::Rstr;
@@ -24,4 +25,6 @@ pub struct ScopeHoistingFinalizerContext<'me> {
   pub runtime: &'me RuntimeModuleBrief,
   pub chunk_graph: &'me ChunkGraph,
   pub options: &'me SharedOptions,
+  /// Code returned by the `resolve_import_meta` hook for `import.meta` references of the module, by their spans.
+  pub resolved_import_meta: &'me FxHashMap<Span, String>,
 }
@@ -12,7 +12,9 @@ use oxc::{
 use rolldown_common::{ExportsKind, Module, ModuleType, StmtInfoIdx, SymbolRef, WrapKind};
 use rolldown_ecmascript::{AllocatorExt, ExpressionExt, StatementExt, TakeIn};
 
-use crate::utils::call_expression_ext::CallExpressionExt;
+use crate::{
+  module_finalizers::rewrite_new_url_expr, utils::call_expression_ext::CallExpressionExt,
+};
 
 use super::ScopeHoistingFinalizer;
 
@@ -85,7 +87,6 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                     let importer_namespace_name =
                       self.canonical_name_for(self.ctx.module.namespace_object_ref);
                     // __reExport(exports, __toESM(require_xxxx()))
-                    let to_esm_fn_name = self.canonical_name_for_runtime("__toESM");
                     let importee_wrapper_ref_name =
                       self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
                     program.body.push(
@@ -94,10 +95,9 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                         .alloc_call_expr_with_2arg_expr_expr(
                           re_export_fn_name,
                           self.snippet.id_ref_expr(importer_namespace_name, SPAN),
-                          self.snippet.to_esm_call_with_interop(
-                            to_esm_fn_name,
+                          self.to_esm_call_for(
+                            importee,
                             self.snippet.call_expr_expr(importee_wrapper_ref_name),
-                            importee.interop(),
                           ),
                         )
                         .into_in(self.alloc),
@@ -110,6 +110,8 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                 match self.ctx.options.format {
                   rolldown_common::OutputFormat::Esm
                   | rolldown_common::OutputFormat::Iife
+                  | rolldown_common::OutputFormat::Umd
+                  | rolldown_common::OutputFormat::Amd
                   | rolldown_common::OutputFormat::Cjs => {
                     // Just remove the statement
                     return;
@@ -208,6 +210,25 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
 
     walk_mut::walk_program(self, program);
 
+    if is_namespace_referenced {
+      if let Some(synthetic_named_exports_ref) = self.ctx.linking_info.synthetic_named_exports_ref {
+        // Copy properties of the fallback export to the namespace object after it's initialized
+        // `__reExport(ns, fallback)`
+        let re_export_fn_name = self.canonical_name_for_runtime("__reExport");
+        let namespace_name = self.canonical_name_for(self.ctx.module.namespace_object_ref);
+        program.body.push(
+          self
+            .snippet
+            .alloc_call_expr_with_2arg_expr_expr(
+              re_export_fn_name,
+              self.snippet.id_ref_expr(namespace_name, SPAN),
+              self.finalized_expr_for_symbol_ref(synthetic_named_exports_ref, false),
+            )
+            .into_in(self.alloc),
+        );
+      }
+    }
+
     // check if we need to add wrapper
     let needs_wrapper = self
       .ctx
@@ -339,6 +360,20 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
     );
   }
 
+  fn visit_new_expression(&mut self, expr: &mut ast::NewExpression<'ast>) {
+    if let Some(chunk_idx) = self.ctx.chunk_graph.module_to_chunk[self.ctx.id] {
+      rewrite_new_url_expr(
+        expr,
+        self.ctx.module,
+        &self.ctx.chunk_graph.chunk_table[chunk_idx],
+        self.ctx.options,
+        &self.snippet,
+      );
+    }
+
+    walk_mut::walk_new_expression(self, expr);
+  }
+
   fn visit_call_expression(&mut self, expr: &mut ast::CallExpression<'ast>) {
     self.try_rewrite_identifier_reference_expr(&mut expr.callee, true);
 
@@ -442,6 +477,20 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
     };
 
     // inline dynamic import
+    if let Some(rewritten) = self.try_rewrite_import_meta(expr) {
+      *expr = rewritten;
+      return;
+    }
+
+    if matches!(self.ctx.options.format, rolldown_common::OutputFormat::Amd)
+      && !self.ctx.options.inline_dynamic_imports
+    {
+      if let Some(rewritten) = self.try_rewrite_amd_dynamic_import(expr) {
+        *expr = rewritten;
+        return;
+      }
+    }
+
     if self.ctx.options.inline_dynamic_imports {
       if let Expression::ImportExpression(import_expr) = expr {
         let rec_id = self.ctx.module.imports[&import_expr.span];
@@ -470,19 +519,15 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
               }
               WrapKind::Cjs => {
                 //  `__toESM(require_foo())`
-                let to_esm_fn_name = self.canonical_name_for_runtime("__toESM");
                 let importee_wrapper_ref_name =
                   self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
 
                 *expr = self.snippet.promise_resolve_then_call_expr(
                   expr.span(),
-                  self.snippet.builder.vec1(self.snippet.return_stmt(
-                    self.snippet.to_esm_call_with_interop(
-                      to_esm_fn_name,
-                      self.snippet.call_expr_expr(importee_wrapper_ref_name),
-                      importee.interop(),
-                    ),
-                  )),
+                  self.snippet.builder.vec1(self.snippet.return_stmt(self.to_esm_call_for(
+                    importee,
+                    self.snippet.call_expr_expr(importee_wrapper_ref_name),
+                  ))),
                 );
               }
               WrapKind::None => {}
@@ -1,10 +1,13 @@
 use oxc::{
   allocator::{Allocator, IntoIn},
   ast::ast::{self, IdentifierReference, Statement},
-  span::{Atom, SPAN},
+  parser::Parser,
+  span::{Atom, SourceType, SPAN},
 };
-use rolldown_common::{AstScopes, ImportRecordIdx, Module, OutputFormat, SymbolRef, WrapKind};
-use rolldown_ecmascript::{AstSnippet, BindingPatternExt, TakeIn};
+use rolldown_common::{
+  AstScopes, ImportRecordIdx, Interop, Module, NormalModule, OutputFormat, SymbolRef, WrapKind,
+};
+use rolldown_ecmascript::{AstSnippet, BindingPatternExt, ExpressionExt, TakeIn};
 
 mod finalizer_context;
 mod impl_visit_mut;
@@ -14,6 +17,9 @@ use rolldown_utils::ecma_script::is_validate_identifier_name;
 
 mod rename;
 
+use super::import_meta_url_expr;
+use crate::ecmascript::format::amd::render_amd_import_path;
+
 /// Finalizer for emitting output code with scope hoisting.
 pub struct ScopeHoistingFinalizer<'me, 'ast> {
   pub ctx: ScopeHoistingFinalizerContext<'me>,
@@ -41,6 +47,120 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     self.canonical_name_for(sym_ref)
   }
 
+  /// Wraps the wrapper call of the CommonJS module with `__toESM`, according to `output.interop`.
+  fn to_esm_call_for(
+    &self,
+    importee: &NormalModule,
+    wrapper_call: ast::Expression<'ast>,
+  ) -> ast::Expression<'ast> {
+    let interop = self.ctx.options.interop.for_normal_modules().resolve(importee.interop());
+    if interop.is_none() {
+      return wrapper_call;
+    }
+    let to_esm_fn_name = self.canonical_name_for_runtime("__toESM");
+    self.snippet.to_esm_call_with_interop(to_esm_fn_name, wrapper_call, interop)
+  }
+
+  /// AMD has no native `import()`, so `import('./foo')` is rewritten to a `require` call wrapped in a promise.
+  fn try_rewrite_amd_dynamic_import(
+    &self,
+    expr: &ast::Expression<'ast>,
+  ) -> Option<ast::Expression<'ast>> {
+    let ast::Expression::ImportExpression(import_expr) = expr else {
+      return None;
+    };
+    if !matches!(import_expr.source, ast::Expression::StringLiteral(_))
+      || !import_expr.arguments.is_empty()
+    {
+      return None;
+    }
+    let rec = &self.ctx.module.import_records[*self.ctx.module.imports.get(&import_expr.span)?];
+    let (source, interop) = match &self.ctx.modules[rec.resolved_module] {
+      Module::Normal(importee) => {
+        let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+          .expect("Normal module should belong to a chunk");
+        let importer_chunk = &self.ctx.chunk_graph.chunk_table[importer_chunk_id];
+        let importee_chunk_id = self.ctx.chunk_graph.entry_module_to_entry_chunk[&importee.idx];
+        let importee_chunk = &self.ctx.chunk_graph.chunk_table[importee_chunk_id];
+        // Entry chunks with the `default` export mode return the default export itself, so the required value is
+        // always wrapped like a CommonJS module.
+        (
+          render_amd_import_path(importer_chunk.import_path_for(importee_chunk)),
+          Some(Interop::Babel),
+        )
+      }
+      Module::External(importee) => (importee.name.to_string(), importee.to_esm_interop()),
+    };
+    let to_esm =
+      interop.map(|interop| (self.canonical_name_for_runtime("__toESM").as_str(), interop));
+    Some(self.snippet.amd_dynamic_require_expr(import_expr.span, &source, to_esm))
+  }
+
+  /// Lowers `import.meta.url` to the url of the chunk containing the module.
+  fn import_meta_url_expr(&self) -> Option<ast::Expression<'ast>> {
+    let chunk_idx = self.ctx.chunk_graph.module_to_chunk[self.ctx.id]?;
+    import_meta_url_expr(
+      self.ctx.options,
+      &self.ctx.chunk_graph.chunk_table[chunk_idx],
+      &self.snippet,
+    )
+  }
+
+  /// Rewrites `import.meta` and `import.meta.<property>` to the code returned by the `resolve_import_meta` hook, or
+  /// lowers them for formats without `import.meta`.
+  fn try_rewrite_import_meta(&self, expr: &ast::Expression<'ast>) -> Option<ast::Expression<'ast>> {
+    let (span, property) = match expr {
+      ast::Expression::StaticMemberExpression(member_expr)
+        if member_expr.object.is_import_meta() =>
+      {
+        (member_expr.span, Some(member_expr.property.name.as_str()))
+      }
+      ast::Expression::MetaProperty(meta) if expr.is_import_meta() => (meta.span, None),
+      _ => return None,
+    };
+    if let Some(code) = self.ctx.resolved_import_meta.get(&span) {
+      // The code is checked to be a valid expression when it's returned by the hook.
+      let code = self.alloc.alloc_str(code);
+      return Parser::new(self.alloc, code, SourceType::default()).parse_expression().ok();
+    }
+    let format = &self.ctx.options.format;
+    match (format, property) {
+      (OutputFormat::Esm, _) => None,
+      (_, None) => {
+        // `{ url: ..., filename: __filename, dirname: __dirname }`
+        let mut props = self.snippet.builder.vec();
+        let mut push_prop = |name: &'static str, value: ast::Expression<'ast>| {
+          props.push(self.snippet.builder.object_property_kind_object_property(
+            SPAN,
+            ast::PropertyKind::Init,
+            self.snippet.builder.property_key_identifier_name(SPAN, name),
+            value,
+            None,
+            false,
+            false,
+            false,
+          ));
+        };
+        if let Some(url) = self.import_meta_url_expr() {
+          push_prop("url", url);
+        }
+        if matches!(format, OutputFormat::Cjs) {
+          push_prop("filename", self.snippet.id_ref_expr("__filename", SPAN));
+          push_prop("dirname", self.snippet.id_ref_expr("__dirname", SPAN));
+        }
+        Some(self.snippet.builder.expression_object(span, props, None))
+      }
+      (_, Some("url")) => self.import_meta_url_expr(),
+      (OutputFormat::Cjs, Some("filename")) => Some(self.snippet.id_ref_expr("__filename", span)),
+      (OutputFormat::Cjs, Some("dirname")) => Some(self.snippet.id_ref_expr("__dirname", span)),
+      (_, Some("env")) => {
+        Some(self.snippet.builder.expression_object(span, self.snippet.builder.vec(), None))
+      }
+      // Unsupported properties are warned about in the generate stage.
+      (_, Some(_)) => Some(self.snippet.void_zero()),
+    }
+  }
+
   fn should_remove_import_export_stmt(
     &self,
     stmt: &mut Statement<'ast>,
@@ -57,17 +177,12 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       }
       WrapKind::Cjs => {
         // Replace the statement with something like `var import_foo = __toESM(require_foo())`
-        let to_esm_fn_name = self.canonical_name_for_runtime("__toESM");
         let importee_wrapper_ref_name =
           self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
         let binding_name_for_wrapper_call_ret = self.canonical_name_for(rec.namespace_ref);
         *stmt = self.snippet.var_decl_stmt(
           binding_name_for_wrapper_call_ret,
-          self.snippet.to_esm_call_with_interop(
-            to_esm_fn_name,
-            self.snippet.call_expr_expr(importee_wrapper_ref_name),
-            importee.interop(),
-          ),
+          self.to_esm_call_for(importee, self.snippet.call_expr_expr(importee_wrapper_ref_name)),
         );
         return false;
       }
@@ -95,7 +210,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     }
 
     let mut expr = match self.ctx.options.format {
-      rolldown_common::OutputFormat::Cjs => {
+      rolldown_common::OutputFormat::Cjs | rolldown_common::OutputFormat::Amd => {
         if canonical_symbol.chunk_id.is_none() {
           // Scoped scopes must belong to its own chunk, so they don't get assigned to a chunk.
           self.snippet.id_ref_expr(self.canonical_name_for(canonical_ref), SPAN)
@@ -253,7 +368,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           });
           re_export_external_stmts = Some(stmts.collect::<Vec<_>>());
         }
-        OutputFormat::Cjs | OutputFormat::Iife => {
+        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
           let stmts = export_all_externals_rec_ids.iter().copied().map(|idx| {
             // Insert `__reExport(exports, require('ext'))`
             let importer_namespace_name =
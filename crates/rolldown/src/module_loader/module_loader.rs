@@ -7,7 +7,11 @@ use crate::module_loader::runtime_module_task::RuntimeModuleTaskResult;
 use crate::module_loader::task_context::TaskContext;
 use crate::runtime::{RuntimeModuleBrief, RUNTIME_MODULE_ID};
 use crate::type_alias::IndexEcmaAst;
+use crate::types::scan_stage_cache::{CachedNormalModule, ScanStageCache};
 use crate::types::symbols::Symbols;
+use crate::utils::bundle_worker::WorkerBundles;
+use crate::utils::persistent_cache::PersistentCache;
+use crate::utils::resolve_id::resolve_id;
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
 use oxc::minifier::ReplaceGlobalDefinesConfig;
@@ -15,14 +19,16 @@ use oxc::span::Span;
 use rolldown_common::side_effects::{DeterminedSideEffects, HookSideEffects};
 use rolldown_common::{
   EntryPoint, EntryPointKind, ExternalModule, ImportKind, ImportRecordIdx, ImporterRecord, Module,
-  ModuleIdx, ModuleTable, ResolvedId,
+  ModuleIdx, ModuleTable, ResolvedId, SharedFileEmitter,
 };
 use rolldown_error::{BuildDiagnostic, DiagnosableResult};
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::SharedPluginDriver;
+use rolldown_resolver::ResolveError;
 use rolldown_utils::ecma_script::legitimize_identifier_name;
 use rolldown_utils::rustc_hash::FxHashSetExt;
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::{SharedOptions, SharedResolver};
@@ -50,7 +56,7 @@ impl IntermediateNormalModules {
   }
 }
 
-pub struct ModuleLoader {
+pub struct ModuleLoader<'a> {
   options: SharedOptions,
   shared_context: Arc<TaskContext>,
   rx: tokio::sync::mpsc::Receiver<Msg>,
@@ -59,6 +65,13 @@ pub struct ModuleLoader {
   remaining: u32,
   intermediate_normal_modules: IntermediateNormalModules,
   symbols: Symbols,
+  cache: Option<&'a mut ScanStageCache>,
+  /// Results of unchanged modules that are taken from the cache and waiting to be processed.
+  cached_task_results: Vec<NormalModuleTaskResult>,
+  /// The number of chunks emitted by plugins that are already added as entries.
+  added_emitted_chunks: usize,
+  /// Receives modules requested by `PluginContext::load`.
+  load_rx: tokio::sync::mpsc::UnboundedReceiver<ResolvedId>,
 }
 
 pub struct ModuleLoaderOutput {
@@ -72,12 +85,14 @@ pub struct ModuleLoaderOutput {
   pub warnings: Vec<BuildDiagnostic>,
 }
 
-impl ModuleLoader {
+impl<'a> ModuleLoader<'a> {
   pub fn new(
     options: SharedOptions,
     plugin_driver: SharedPluginDriver,
-    fs: OsFileSystem,
+    file_emitter: SharedFileEmitter,
+    fs: SharedFileSystem,
     resolver: SharedResolver,
+    cache: Option<&'a mut ScanStageCache>,
   ) -> anyhow::Result<Self> {
     // 1024 should be enough for most cases
     // over 1024 pending tasks are insane
@@ -100,12 +115,19 @@ impl ModuleLoader {
         })?)
       },
     };
+    let persistent_cache = options
+      .persistent_cache_dir
+      .clone()
+      .and_then(|dir| PersistentCache::new(dir, &options, &plugin_driver));
     let common_data = Arc::new(TaskContext {
       options: Arc::clone(&options),
       tx,
       resolver,
       fs,
       plugin_driver,
+      file_emitter,
+      persistent_cache,
+      worker_bundles: WorkerBundles::default(),
       meta,
     });
 
@@ -113,6 +135,8 @@ impl ModuleLoader {
     let mut symbols = Symbols::default();
     let runtime_id = intermediate_normal_modules.alloc_ecma_module_idx(&mut symbols);
 
+    let load_rx = common_data.plugin_driver.start_module_loading();
+
     let task = RuntimeModuleTask::new(runtime_id, tx_to_runtime_module);
 
     #[cfg(target_family = "wasm")]
@@ -137,6 +161,10 @@ impl ModuleLoader {
       remaining: 1,
       intermediate_normal_modules,
       symbols,
+      cache,
+      cached_task_results: vec![],
+      added_emitted_chunks: 0,
+      load_rx,
     })
   }
 
@@ -144,12 +172,13 @@ impl ModuleLoader {
     &mut self,
     resolved_id: ResolvedId,
     owner: Option<ModuleTaskOwner>,
+    is_user_defined_entry: bool,
   ) -> ModuleIdx {
     match self.visited.entry(resolved_id.id.clone()) {
       std::collections::hash_map::Entry::Occupied(visited) => *visited.get(),
       std::collections::hash_map::Entry::Vacant(not_visited) => {
+        let idx = self.intermediate_normal_modules.alloc_ecma_module_idx(&mut self.symbols);
         if resolved_id.is_external {
-          let idx = self.intermediate_normal_modules.alloc_ecma_module_idx(&mut self.symbols);
           not_visited.insert(idx);
           let external_module_side_effects = if let Some(hook_side_effects) =
             resolved_id.side_effects
@@ -180,15 +209,36 @@ impl ModuleLoader {
             ArcStr::clone(&resolved_id.id),
             external_module_side_effects,
             symbol_ref,
+            resolved_id.meta,
           );
           self.intermediate_normal_modules.modules[idx] = Some(ext.into());
           idx
         } else {
-          let idx = self.intermediate_normal_modules.alloc_ecma_module_idx(&mut self.symbols);
           not_visited.insert(idx);
           self.remaining += 1;
 
-          let task = ModuleTask::new(Arc::clone(&self.shared_context), idx, resolved_id, owner);
+          if let Some(cached) = self.cache.as_deref_mut().and_then(|cache| {
+            cache.modules.get_mut(&resolved_id.id).filter(|cached| {
+              cached
+                .module
+                .as_normal()
+                .is_some_and(|module| module.is_user_defined_entry == is_user_defined_entry)
+            })
+          }) {
+            cached.set_module_idx(idx);
+            // The module isn't loaded again, so the files emitted while loading it are emitted again here.
+            self.shared_context.file_emitter.reemit_module_files(&resolved_id.id);
+            self.cached_task_results.push(cached.to_task_result());
+            return idx;
+          }
+
+          let task = ModuleTask::new(
+            Arc::clone(&self.shared_context),
+            idx,
+            resolved_id,
+            owner,
+            is_user_defined_entry,
+          );
           #[cfg(target_family = "wasm")]
           {
             let handle = tokio::runtime::Handle::current();
@@ -205,6 +255,63 @@ impl ModuleLoader {
     }
   }
 
+  /// Add chunks emitted by plugins since the last call as user-defined entries.
+  async fn add_emitted_chunks(
+    &mut self,
+    entry_points: &mut Vec<EntryPoint>,
+    user_defined_entry_ids: &mut FxHashSet<ModuleIdx>,
+    errors: &mut Vec<BuildDiagnostic>,
+  ) -> anyhow::Result<()> {
+    let chunks = self.shared_context.file_emitter.emitted_chunks_from(self.added_emitted_chunks);
+    self.added_emitted_chunks += chunks.len();
+    for (reference_id, chunk) in chunks {
+      let resolved_id = resolve_id(
+        &self.shared_context.resolver,
+        &self.shared_context.plugin_driver,
+        &chunk.id,
+        chunk.importer.as_deref(),
+        true,
+        ImportKind::Import,
+        None,
+        Arc::default(),
+        true,
+      )
+      .await?;
+      let resolved_id = match resolved_id {
+        Ok(resolved_id) if resolved_id.is_external => {
+          errors.push(BuildDiagnostic::entry_cannot_be_external(resolved_id.id.as_str()));
+          continue;
+        }
+        Ok(resolved_id) => resolved_id,
+        Err(ResolveError::NotFound(..)) => {
+          errors.push(BuildDiagnostic::unresolved_entry(&chunk.id, None));
+          continue;
+        }
+        Err(err @ ResolveError::PackagePathNotExported(..)) => {
+          errors.push(BuildDiagnostic::unresolved_entry(&chunk.id, Some(err)));
+          continue;
+        }
+        Err(err) => return Err(err.into()),
+      };
+
+      self
+        .shared_context
+        .file_emitter
+        .set_chunk_entry_module(reference_id, ArcStr::clone(&resolved_id.id));
+      let id = self.try_spawn_new_task(resolved_id, None, true);
+      // Emitting the same module multiple times or emitting an existing entry doesn't create new chunks.
+      if user_defined_entry_ids.insert(id) {
+        entry_points.push(EntryPoint {
+          name: chunk.name.clone().map(ArcStr::from),
+          id,
+          kind: EntryPointKind::EmittedUserDefined,
+          file_name: chunk.file_name.clone(),
+        });
+      }
+    }
+    Ok(())
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn fetch_all_modules(
     mut self,
@@ -228,8 +335,9 @@ impl ModuleLoader {
       .into_iter()
       .map(|(name, info)| EntryPoint {
         name,
-        id: self.try_spawn_new_task(info, /* is_user_defined_entry */ None),
+        id: self.try_spawn_new_task(info, None, /* is_user_defined_entry */ true),
         kind: EntryPointKind::UserDefined,
+        file_name: None,
       })
       .inspect(|e| {
         user_defined_entry_ids.insert(e.id);
@@ -240,12 +348,38 @@ impl ModuleLoader {
 
     let mut runtime_brief: Option<RuntimeModuleBrief> = None;
 
+    // Chunks emitted in `buildStart`.
+    self.add_emitted_chunks(&mut entry_points, &mut user_defined_entry_ids, &mut errors).await?;
+
     while self.remaining > 0 {
-      let Some(msg) = self.rx.recv().await else {
-        break;
+      let msg = if let Some(task_result) = self.cached_task_results.pop() {
+        // Hooks are not called for cached modules, so `PluginContext::load` needs to be resolved here.
+        if let Some(module) = task_result.module.as_normal() {
+          self.shared_context.plugin_driver.set_module_loaded(Arc::new(module.to_module_info()));
+        }
+        Msg::NormalModuleDone(task_result)
+      } else {
+        let msg = tokio::select! {
+          msg = self.rx.recv() => msg,
+          Some(resolved_id) = self.load_rx.recv() => Some(Msg::FetchModule(resolved_id)),
+        };
+        let Some(msg) = msg else {
+          break;
+        };
+        msg
       };
       match msg {
         Msg::NormalModuleDone(task_result) => {
+          if let Some(cache) = self.cache.as_deref_mut() {
+            let id = task_result.module.id();
+            let is_cached = cache
+              .modules
+              .get(id)
+              .is_some_and(|cached| cached.module_idx == task_result.module_idx);
+            if !is_cached {
+              cache.modules.insert(id.into(), CachedNormalModule::from_task_result(&task_result));
+            }
+          }
           let NormalModuleTaskResult {
             module_idx,
             resolved_deps,
@@ -267,7 +401,7 @@ impl ModuleLoader {
                   normal_module.stable_id.as_str().into(),
                   Span::new(raw_rec.module_request_start, raw_rec.module_request_end()),
                 );
-                let id = self.try_spawn_new_task(info, Some(owner));
+                let id = self.try_spawn_new_task(info, Some(owner), false);
                 // Dynamic imported module will be considered as an entry
                 self.intermediate_normal_modules.importers[id].push(ImporterRecord {
                   kind: raw_rec.kind,
@@ -299,6 +433,11 @@ impl ModuleLoader {
           self.symbols.add_ast_symbols(self.runtime_id, ast_symbols);
           runtime_brief = Some(runtime);
         }
+        Msg::FetchModule(resolved_id) => {
+          // Modules requested by `PluginContext::load` are not entries, and requesting them is not a finished task.
+          self.try_spawn_new_task(resolved_id, None, false);
+          continue;
+        }
         Msg::BuildErrors(e) => {
           errors.extend(e);
         }
@@ -306,8 +445,13 @@ impl ModuleLoader {
         // `remaining` will overflow
         #[allow(clippy::cast_possible_truncation)]
         Msg::Panics(err) => {
+          // Pending `PluginContext::load` calls would block the working threads forever.
+          self.shared_context.plugin_driver.finish_module_loading();
           // `self.remaining -1` for the panic task it self
           self.remaining -= 1;
+          // Cached results are not sent through the channel
+          self.remaining -= self.cached_task_results.len() as u32;
+          self.cached_task_results.clear();
           // gracefully shutdown all working thread, only receive and do not spawn
           while self.remaining > 0 {
             let mut task = Vec::with_capacity(self.remaining as usize);
@@ -318,13 +462,31 @@ impl ModuleLoader {
         }
       }
       self.remaining -= 1;
+      // Plugins might emit chunks in hooks called by the finished task.
+      self.add_emitted_chunks(&mut entry_points, &mut user_defined_entry_ids, &mut errors).await?;
     }
 
+    self.shared_context.plugin_driver.finish_module_loading();
+
     if !errors.is_empty() {
+      if let Some(cache) = self.cache {
+        // Keep watching the visited files, so the errors could be fixed in watch mode.
+        cache
+          .watch_files
+          .extend(self.visited.into_keys().filter(|id| Path::new(id.as_str()).is_absolute()));
+      }
       return Ok(Err(errors));
     }
 
-    let modules: IndexVec<ModuleIdx, Module> = self
+    self.shared_context.file_emitter.close_chunk_emission();
+
+    if let Some(cache) = self.cache {
+      cache.modules.retain(|id, _| self.visited.contains_key(id));
+      cache.watch_files =
+        self.visited.keys().filter(|id| Path::new(id.as_str()).is_absolute()).cloned().collect();
+    }
+
+    let mut modules: IndexVec<ModuleIdx, Module> = self
       .intermediate_normal_modules
       .modules
       .into_iter()
@@ -333,6 +495,10 @@ impl ModuleLoader {
       .map(|(id, mut module)| {
         let id = ModuleIdx::from(id);
         if let Some(module) = module.as_normal_mut() {
+          // Modules that are emitted as chunks after being imported by others.
+          if user_defined_entry_ids.contains(&id) {
+            module.is_user_defined_entry = true;
+          }
           // Note: (Compat to rollup)
           // The `dynamic_importers/importers` should be added after `module_parsed` hook.
           for importer in std::mem::take(&mut self.intermediate_normal_modules.importers[id]) {
@@ -342,20 +508,39 @@ impl ModuleLoader {
               module.dynamic_importers.push(importer.importer_path);
             }
           }
+        } else if let Some(module) = module.as_external_mut() {
+          for importer in std::mem::take(&mut self.intermediate_normal_modules.importers[id]) {
+            if importer.kind.is_static() {
+              module.importers.push(importer.importer_path);
+            } else {
+              module.dynamic_importers.push(importer.importer_path);
+            }
+          }
         }
         module
       })
       .collect();
 
+    // `output.interop` could be a function, so it's resolved here for external modules, since the later stages are
+    // synchronous.
+    for module in modules.iter_mut().filter_map(Module::as_external_mut) {
+      module.interop = self.options.interop.call(&module.name).await?;
+    }
+
     // if `inline_dynamic_imports` is set to be true, here we should not put dynamic imports to entries
     if !self.options.inline_dynamic_imports {
-      let mut dynamic_import_entry_ids = dynamic_import_entry_ids.into_iter().collect::<Vec<_>>();
+      // Dynamic imported modules might be emitted as chunks later.
+      let mut dynamic_import_entry_ids = dynamic_import_entry_ids
+        .into_iter()
+        .filter(|id| !user_defined_entry_ids.contains(id))
+        .collect::<Vec<_>>();
       dynamic_import_entry_ids.sort_unstable_by_key(|id| modules[*id].stable_id());
 
       entry_points.extend(dynamic_import_entry_ids.into_iter().map(|id| EntryPoint {
         name: None,
         id,
         kind: EntryPointKind::DynamicImport,
+        file_name: None,
       }));
     }
 
@@ -8,12 +8,13 @@ use sugar_path::SugarPath;
 use anyhow::Result;
 use rolldown_common::{
   ModuleId, ModuleIdx, ModuleType, ModuleView, NormalModule, ResolvedId, StrOrBytes,
+  SyntheticNamedExports,
 };
 use rolldown_error::{BuildDiagnostic, UnloadableDependencyContext};
 
 use super::{task_context::TaskContext, Msg};
 use crate::{
-  css::create_css_view,
+  css::{create_css_view, CreateCssViewReturn},
   ecmascript::ecma_module_view_factory::EcmaModuleViewFactory,
   module_loader::NormalModuleTaskResult,
   types::module_factory::{
@@ -49,8 +50,8 @@ impl ModuleTask {
     idx: ModuleIdx,
     resolved_id: ResolvedId,
     owner: Option<ModuleTaskOwner>,
+    is_user_defined_entry: bool,
   ) -> Self {
-    let is_user_defined_entry = owner.is_none();
     Self { ctx, module_idx: idx, resolved_id, owner, errors: vec![], is_user_defined_entry }
   }
 
@@ -59,11 +60,18 @@ impl ModuleTask {
     match self.run_inner().await {
       Ok(()) => {
         if !self.errors.is_empty() {
+          self.ctx.plugin_driver.set_module_load_failed(&self.resolved_id.id);
           self.ctx.tx.send(Msg::BuildErrors(self.errors)).await.expect("Send should not fail");
         }
       }
       Err(err) => {
-        self.ctx.tx.send(Msg::Panics(err)).await.expect("Send should not fail");
+        self.ctx.plugin_driver.set_module_load_failed(&self.resolved_id.id);
+        // Errors created by `PluginContext::error` are reported as diagnostics.
+        let msg = match err.downcast::<BuildDiagnostic>() {
+          Ok(diagnostic) => Msg::BuildErrors(vec![diagnostic]),
+          Err(err) => Msg::Panics(err),
+        };
+        self.ctx.tx.send(msg).await.expect("Send should not fail");
       }
     }
   }
@@ -71,6 +79,8 @@ impl ModuleTask {
   #[expect(clippy::too_many_lines)]
   async fn run_inner(&mut self) -> Result<()> {
     let mut hook_side_effects = self.resolved_id.side_effects.take();
+    let mut meta = std::mem::take(&mut self.resolved_id.meta);
+    let mut synthetic_named_exports = None;
     let mut sourcemap_chain = vec![];
     let mut warnings = vec![];
 
@@ -81,38 +91,84 @@ impl ModuleTask {
       &self.ctx.fs,
       &mut sourcemap_chain,
       &mut hook_side_effects,
+      &mut meta,
+      &mut synthetic_named_exports,
       &self.ctx.options,
     )
     .await
     {
       Ok(ret) => ret,
       Err(err) => {
-        self.errors.push(BuildDiagnostic::unloadable_dependency(
-          self.resolved_id.debug_id(self.ctx.options.cwd.as_path()).into(),
-          self.owner.as_ref().map(|owner| UnloadableDependencyContext {
-            importer_id: owner.importer_id.as_str().into(),
-            importee_span: owner.importee_span,
-            source: owner.source.clone(),
-          }),
-          err.to_string().into(),
-        ));
+        let diagnostic = match err.downcast::<BuildDiagnostic>() {
+          // Errors created by `PluginContext::error` are reported as they are.
+          Ok(diagnostic) => diagnostic,
+          Err(err) => BuildDiagnostic::unloadable_dependency(
+            self.resolved_id.debug_id(self.ctx.options.cwd.as_path()).into(),
+            self.owner.as_ref().map(|owner| UnloadableDependencyContext {
+              importer_id: owner.importer_id.as_str().into(),
+              importee_span: owner.importee_span,
+              source: owner.source.clone(),
+            }),
+            err.to_string().into(),
+          ),
+        };
+        self.errors.push(diagnostic);
         return Ok(());
       }
     };
 
     let mut source = match source {
       StrOrBytes::Str(source) => {
-        // Run plugin transform.
-        let source = transform_source(
-          &self.ctx.plugin_driver,
-          &self.resolved_id,
-          source,
-          &mut sourcemap_chain,
-          &mut hook_side_effects,
-          &mut module_type,
-        )
-        .await?;
-        source.into()
+        // Reuse the transformed result of the same loaded source from the persistent cache.
+        let cache = self.ctx.persistent_cache.as_ref().map(|cache| {
+          let key = cache.key(
+            &self.resolved_id,
+            &source,
+            &module_type,
+            hook_side_effects,
+            &meta,
+            synthetic_named_exports.as_ref(),
+          );
+          (cache, key)
+        });
+        if let Some(cached) = cache.as_ref().and_then(|(cache, key)| cache.get(&self.ctx.fs, key)) {
+          sourcemap_chain.extend(cached.sourcemap_chain);
+          hook_side_effects = cached.side_effects;
+          meta = cached.meta;
+          synthetic_named_exports = cached.synthetic_named_exports;
+          module_type = cached.module_type;
+          cached.code.into()
+        } else {
+          let loaded_sourcemap_count = sourcemap_chain.len();
+          let mut has_side_outputs = false;
+          // Run plugin transform.
+          let source = transform_source(
+            &self.ctx.plugin_driver,
+            &self.resolved_id,
+            source,
+            &mut sourcemap_chain,
+            &mut hook_side_effects,
+            &mut meta,
+            &mut synthetic_named_exports,
+            &mut module_type,
+            &mut has_side_outputs,
+          )
+          .await?;
+          // Files emitted, watch files added and logs reported by the hooks can't be replayed from the cache.
+          if let Some((cache, key)) = cache.as_ref().filter(|_| !has_side_outputs) {
+            cache.set(
+              &self.ctx.fs,
+              key,
+              &source,
+              &sourcemap_chain[loaded_sourcemap_count..],
+              hook_side_effects,
+              &meta,
+              synthetic_named_exports.as_ref(),
+              &module_type,
+            );
+          }
+          source.into()
+        }
       }
       StrOrBytes::Bytes(_) => source,
     };
@@ -137,8 +193,16 @@ impl ModuleTask {
     let css_view = if matches!(module_type, ModuleType::Css) {
       let css_source: ArcStr = source.try_into_string()?.into();
       // FIXME: This makes creating `EcmaView` rely on creating `CssView` first, while they should be done in parallel.
-      source = StrOrBytes::Str(String::new());
-      Some(create_css_view(&css_source))
+      match create_css_view(&self.ctx, &self.resolved_id, &css_source, &mut warnings).await? {
+        Ok(CreateCssViewReturn { css_view, ecma_source }) => {
+          source = StrOrBytes::Str(ecma_source);
+          Some(css_view)
+        }
+        Err(errs) => {
+          self.errors.extend(errs);
+          return Ok(());
+        }
+      }
     } else {
       None
     };
@@ -152,6 +216,9 @@ impl ModuleTask {
         warnings: &mut warnings,
         module_type: module_type.clone(),
         resolver: &self.ctx.resolver,
+        fs: &self.ctx.fs,
+        file_emitter: &self.ctx.file_emitter,
+        worker_bundles: &self.ctx.worker_bundles,
         replace_global_define_config: self.ctx.meta.replace_global_define_config.clone(),
       },
       CreateModuleViewArgs { source, sourcemap_chain, hook_side_effects },
@@ -183,6 +250,10 @@ impl ModuleTask {
       module_type: module_type.clone(),
       ecma_view,
       css_view,
+      meta,
+      synthetic_named_exports: synthetic_named_exports
+        .as_ref()
+        .and_then(SyntheticNamedExports::fallback_export_name),
     };
 
     self.ctx.plugin_driver.module_parsed(Arc::new(module.to_module_info())).await?;
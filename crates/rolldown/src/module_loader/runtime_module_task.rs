@@ -3,10 +3,12 @@ use oxc::index::IndexVec;
 use oxc::span::SourceType;
 use rolldown_common::{
   side_effects::DeterminedSideEffects, AstScopes, EcmaView, ExportsKind, ModuleDefFormat, ModuleId,
-  ModuleIdx, ModuleType, NormalModule, SymbolRef,
+  ModuleIdx, ModuleMeta, ModuleType, NormalModule, SymbolRef,
 };
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_error::{BuildDiagnostic, DiagnosableResult, UnhandleableResult};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
 
 use super::Msg;
 use crate::{
@@ -68,6 +70,9 @@ impl RuntimeModuleTask {
       star_exports,
       default_export_ref,
       imports,
+      new_url_references: _,
+      worker_references: _,
+      import_meta_references: _,
       import_records: _,
       exports_kind: _,
       warnings: _,
@@ -105,15 +110,19 @@ impl RuntimeModuleTask {
         named_exports,
         stmt_infos,
         imports,
+        new_url_assets: FxHashMap::default(),
+        import_meta_references: Vec::new(),
         star_exports,
         default_export_ref,
-        scope: ast_scope,
+        scope: Arc::new(ast_scope),
         exports_kind: ExportsKind::Esm,
         namespace_object_ref,
         def_format: ModuleDefFormat::EsmMjs,
         ast_usage,
       },
       css_view: None,
+      meta: ModuleMeta::default(),
+      synthetic_named_exports: None,
     };
 
     if let Err(_err) = self.tx.try_send(Msg::RuntimeNormalModuleDone(RuntimeModuleTaskResult {
@@ -1,8 +1,12 @@
 use oxc::minifier::ReplaceGlobalDefinesConfig;
-use rolldown_fs::OsFileSystem;
+use rolldown_common::SharedFileEmitter;
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 
-use crate::{SharedOptions, SharedResolver};
+use crate::{
+  utils::{bundle_worker::WorkerBundles, persistent_cache::PersistentCache},
+  SharedOptions, SharedResolver,
+};
 
 use super::Msg;
 
@@ -11,8 +15,12 @@ pub struct TaskContext {
   pub options: SharedOptions,
   pub tx: tokio::sync::mpsc::Sender<Msg>,
   pub resolver: SharedResolver,
-  pub fs: OsFileSystem,
+  pub fs: SharedFileSystem,
   pub plugin_driver: SharedPluginDriver,
+  pub file_emitter: SharedFileEmitter,
+  /// `None` if the persistent cache is disabled.
+  pub persistent_cache: Option<PersistentCache>,
+  pub worker_bundles: WorkerBundles,
   pub meta: TaskContextMeta,
 }
 
@@ -1,10 +1,20 @@
-use std::cmp::{Ordering, Reverse};
-
-use crate::{chunk_graph::ChunkGraph, types::linking_metadata::LinkingMetadataVec};
+use std::{
+  cmp::{Ordering, Reverse},
+  sync::Arc,
+};
+
+use crate::{
+  chunk_graph::ChunkGraph,
+  types::linking_metadata::LinkingMetadataVec,
+  utils::preserve_modules_chunk_name::{common_dir_of_modules, preserve_modules_chunk_name},
+};
 use arcstr::ArcStr;
 use itertools::Itertools;
 use oxc::index::IndexVec;
-use rolldown_common::{Chunk, ChunkIdx, ChunkKind, Module, ModuleIdx, ModuleTable, OutputFormat};
+use rolldown_common::{
+  Chunk, ChunkIdx, ChunkKind, MatchGroupName, MatchGroupTest, Module, ModuleIdx, ModuleInfo,
+  ModuleTable, OutputFormat,
+};
 use rolldown_error::{BuildDiagnostic, InvalidOptionTypes};
 use rolldown_utils::{rustc_hash::FxHashMapExt, BitSet};
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -22,11 +32,6 @@ pub type IndexSplittingInfo = IndexVec<ModuleIdx, SplittingInfo>;
 impl<'a> GenerateStage<'a> {
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate_chunks(&mut self) -> anyhow::Result<ChunkGraph> {
-    if matches!(self.options.format, OutputFormat::Iife) {
-      let user_defined_entry_count =
-        self.link_output.entries.iter().filter(|entry| entry.kind.is_user_defined()).count();
-      debug_assert!(user_defined_entry_count == 1, "IIFE format only supports one entry point");
-    }
     let entries_len: u32 =
       self.link_output.entries.len().try_into().expect("Too many entries, u32 overflowed.");
     // If we are in test environment, to make the runtime module always fall into a standalone chunk,
@@ -41,6 +46,21 @@ impl<'a> GenerateStage<'a> {
       }; self.link_output.module_table.modules.len()];
     let mut bits_to_chunk = FxHashMap::with_capacity(self.link_output.entries.len());
 
+    // In `preserveModules` mode, chunks are named by paths of their modules relative to this directory.
+    let preserve_modules_input_base = self.options.preserve_modules.then(|| {
+      common_dir_of_modules(
+        self
+          .link_output
+          .module_table
+          .modules
+          .iter()
+          .filter_map(Module::as_normal)
+          .filter(|module| module.is_included)
+          .map(|module| module.id.as_str()),
+      )
+      .unwrap_or_else(|| self.options.cwd.clone())
+    });
+
     let mut entry_module_to_entry_chunk: FxHashMap<ModuleIdx, ChunkIdx> =
       FxHashMap::with_capacity(self.link_output.entries.len());
     // Create chunk for each static and dynamic entry
@@ -51,7 +71,7 @@ impl<'a> GenerateStage<'a> {
       let Module::Normal(module) = &self.link_output.module_table.modules[entry_point.id] else {
         continue;
       };
-      let chunk = chunk_graph.add_chunk(Chunk::new(
+      let mut chunk = Chunk::new(
         entry_point.name.clone(),
         bits.clone(),
         vec![],
@@ -60,7 +80,19 @@ impl<'a> GenerateStage<'a> {
           bit: count,
           module: entry_point.id,
         },
-      ));
+      );
+      chunk.file_name.clone_from(&entry_point.file_name);
+      if let Some(input_base) = &preserve_modules_input_base {
+        chunk.name.get_or_insert_with(|| {
+          preserve_modules_chunk_name(
+            &module.id,
+            input_base,
+            self.options.preserve_modules_root.as_deref(),
+          )
+          .into()
+        });
+      }
+      let chunk = chunk_graph.add_chunk(chunk);
       bits_to_chunk.insert(bits, chunk);
       entry_module_to_entry_chunk.insert(entry_point.id, chunk);
     }
@@ -77,7 +109,37 @@ impl<'a> GenerateStage<'a> {
     let mut module_to_assigned: IndexVec<ModuleIdx, bool> =
       oxc::index::index_vec![false; self.link_output.module_table.modules.len()];
 
-    self.apply_advanced_chunks(&index_splitting_info, &mut module_to_assigned, &mut chunk_graph);
+    if let Some(input_base) = &preserve_modules_input_base {
+      self.check_preserve_modules_options();
+      // Every included module is put into its own chunk, or the chunk of the entry if it's an entry module.
+      for normal_module in
+        self.link_output.module_table.modules.iter().filter_map(Module::as_normal)
+      {
+        if !normal_module.is_included {
+          continue;
+        }
+        module_to_assigned[normal_module.idx] = true;
+        let chunk_id =
+          entry_module_to_entry_chunk.get(&normal_module.idx).copied().unwrap_or_else(|| {
+            let name = preserve_modules_chunk_name(
+              &normal_module.id,
+              input_base,
+              self.options.preserve_modules_root.as_deref(),
+            );
+            chunk_graph.add_chunk(Chunk::new(
+              Some(name.into()),
+              index_splitting_info[normal_module.idx].bits.clone(),
+              vec![],
+              ChunkKind::Common,
+            ))
+          });
+        chunk_graph.add_module_to_chunk(normal_module.idx, chunk_id);
+      }
+    } else {
+      self
+        .apply_advanced_chunks(&index_splitting_info, &mut module_to_assigned, &mut chunk_graph)
+        .await?;
+    }
 
     // 1. Assign modules to corresponding chunks
     // 2. Create shared chunks to store modules that belong to multiple chunks.
@@ -109,7 +171,9 @@ impl<'a> GenerateStage<'a> {
       }
     }
 
-    if matches!(self.options.format, OutputFormat::Iife) && chunk_graph.chunk_table.len() > 1 {
+    if matches!(self.options.format, OutputFormat::Iife | OutputFormat::Umd)
+      && chunk_graph.chunk_table.len() > 1
+    {
       self.link_output.errors.push(BuildDiagnostic::invalid_option(
         InvalidOptionTypes::UnsupportedCodeSplittingFormat,
         self.options.format.to_string(),
@@ -223,6 +287,22 @@ impl<'a> GenerateStage<'a> {
     Ok(chunk_graph)
   }
 
+  /// Modules can't be merged in `preserveModules` mode, so options that merge modules are reported as invalid.
+  fn check_preserve_modules_options(&mut self) {
+    if self.options.inline_dynamic_imports {
+      self.link_output.errors.push(BuildDiagnostic::invalid_option(
+        InvalidOptionTypes::UnsupportedWithPreserveModules,
+        "inlineDynamicImports".to_string(),
+      ));
+    }
+    if self.options.advanced_chunks.is_some() {
+      self.link_output.errors.push(BuildDiagnostic::invalid_option(
+        InvalidOptionTypes::UnsupportedWithPreserveModules,
+        "advancedChunks".to_string(),
+      ));
+    }
+  }
+
   fn determine_reachable_modules_for_entry(
     &self,
     module_id: ModuleIdx,
@@ -251,12 +331,12 @@ impl<'a> GenerateStage<'a> {
   }
 
   #[allow(clippy::too_many_lines)] // TODO(hyf0): refactor
-  fn apply_advanced_chunks(
+  async fn apply_advanced_chunks(
     &mut self,
     index_splitting_info: &IndexSplittingInfo,
     module_to_assigned: &mut IndexVec<ModuleIdx, bool>,
     chunk_graph: &mut ChunkGraph,
-  ) {
+  ) -> anyhow::Result<()> {
     fn add_module_and_dependencies_to_group_recursively(
       module_group: &mut ModuleGroup,
       module: ModuleIdx,
@@ -305,6 +385,39 @@ impl<'a> GenerateStage<'a> {
         }
       }
 
+      /// Splits modules into parts in the execution order, so that each part is no larger than `max_size` unless it only
+      /// contains a single module. Modules executed together tend to stay in the same part.
+      #[allow(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
+      pub fn split_by_max_size(
+        &self,
+        max_size: Option<f64>,
+        module_table: &ModuleTable,
+      ) -> Vec<Vec<ModuleIdx>> {
+        let mut modules = self.modules.iter().copied().collect::<Vec<_>>();
+        modules.sort_unstable_by_key(|module_idx| module_table.modules[*module_idx].exec_order());
+
+        let Some(max_size) = max_size.filter(|max_size| self.sizes > *max_size) else {
+          return vec![modules];
+        };
+
+        let mut parts: Vec<Vec<ModuleIdx>> = vec![];
+        let mut part_size = 0.0;
+        for module_idx in modules {
+          let size = module_table.modules[module_idx].size() as f64;
+          match parts.last_mut() {
+            Some(part) if part_size + size <= max_size => {
+              part.push(module_idx);
+              part_size += size;
+            }
+            _ => {
+              parts.push(vec![module_idx]);
+              part_size = size;
+            }
+          }
+        }
+        parts
+      }
+
       #[allow(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
       pub fn remove_module(&mut self, module_idx: ModuleIdx, module_table: &ModuleTable) {
         if self.modules.remove(&module_idx) {
@@ -315,17 +428,17 @@ impl<'a> GenerateStage<'a> {
     }
 
     let Some(chunking_options) = &self.options.advanced_chunks else {
-      return;
+      return Ok(());
     };
 
     let Some(match_groups) =
       chunking_options.groups.as_ref().map(|inner| inner.iter().collect::<Vec<_>>())
     else {
-      return;
+      return Ok(());
     };
 
     if match_groups.is_empty() {
-      return;
+      return Ok(());
     }
 
     let mut index_module_groups: IndexVec<ModuleGroupIdx, ModuleGroup> = IndexVec::new();
@@ -342,10 +455,28 @@ impl<'a> GenerateStage<'a> {
       }
 
       let splitting_info = &index_splitting_info[normal_module.idx];
+      // Only created if a callback needs it.
+      let mut module_info: Option<Arc<ModuleInfo>> = None;
 
       for (match_group_index, match_group) in match_groups.iter().copied().enumerate() {
-        let is_matched =
-          match_group.test.as_ref().map_or(true, |test| test.matches(&normal_module.id));
+        if let Some(min_module_size) = match_group.min_module_size {
+          #[allow(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
+          let module_size = normal_module.source.len() as f64;
+          if module_size < min_module_size {
+            continue;
+          }
+        }
+
+        let is_matched = match &match_group.test {
+          None => true,
+          Some(MatchGroupTest::Regex(regex)) => regex.matches(&normal_module.id),
+          Some(MatchGroupTest::Fn(test)) => {
+            let module_info = Arc::clone(
+              module_info.get_or_insert_with(|| Arc::new(normal_module.to_linked_module_info())),
+            );
+            test(&normal_module.id, module_info).await?
+          }
+        };
 
         if !is_matched {
           continue;
@@ -359,7 +490,18 @@ impl<'a> GenerateStage<'a> {
           }
         }
 
-        let group_name = ArcStr::from(&match_group.name);
+        let group_name = match &match_group.name {
+          MatchGroupName::Static(name) => ArcStr::from(name),
+          MatchGroupName::Fn(name) => {
+            let module_info = Arc::clone(
+              module_info.get_or_insert_with(|| Arc::new(normal_module.to_linked_module_info())),
+            );
+            match name(&normal_module.id, module_info).await? {
+              Some(name) => ArcStr::from(name),
+              None => continue,
+            }
+          }
+        };
 
         let module_group_idx =
           name_to_module_group.entry(group_name.clone()).or_insert_with(|| {
@@ -402,26 +544,31 @@ impl<'a> GenerateStage<'a> {
         }
       }
 
-      let chunk = Chunk::new(
-        Some(this_module_group.name.clone()),
-        index_splitting_info
-          [this_module_group.modules.iter().next().copied().expect("must have one")]
-        .bits
-        .clone(),
-        vec![],
-        ChunkKind::Common,
-      );
+      let max_size = match_groups[this_module_group.match_group_index]
+        .max_size
+        .map_or(chunking_options.max_size, Some);
 
-      let chunk_idx = chunk_graph.add_chunk(chunk);
+      for modules in this_module_group.split_by_max_size(max_size, &self.link_output.module_table) {
+        let chunk = Chunk::new(
+          Some(this_module_group.name.clone()),
+          index_splitting_info[modules[0]].bits.clone(),
+          vec![],
+          ChunkKind::Common,
+        );
+
+        let chunk_idx = chunk_graph.add_chunk(chunk);
 
-      this_module_group.modules.iter().copied().for_each(|module_idx| {
-        module_groups.iter_mut().for_each(|group| {
-          group.remove_module(module_idx, &self.link_output.module_table);
+        modules.into_iter().for_each(|module_idx| {
+          module_groups.iter_mut().for_each(|group| {
+            group.remove_module(module_idx, &self.link_output.module_table);
+          });
+          chunk_graph.chunk_table[chunk_idx].bits.union(&index_splitting_info[module_idx].bits);
+          chunk_graph.add_module_to_chunk(module_idx, chunk_idx);
+          module_to_assigned[module_idx] = true;
         });
-        chunk_graph.chunk_table[chunk_idx].bits.union(&index_splitting_info[module_idx].bits);
-        chunk_graph.add_module_to_chunk(module_idx, chunk_idx);
-        module_to_assigned[module_idx] = true;
-      });
+      }
     }
+
+    Ok(())
   }
 }
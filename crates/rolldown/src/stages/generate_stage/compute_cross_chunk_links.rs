@@ -11,6 +11,7 @@ use rolldown_common::{
   NamedImport, OutputFormat, SymbolRef, WrapKind,
 };
 use rolldown_rstr::{Rstr, ToRstr};
+use rolldown_utils::ecma_script::legitimize_identifier_name;
 use rolldown_utils::rayon::IntoParallelIterator;
 use rolldown_utils::rayon::{ParallelBridge, ParallelIterator};
 use rolldown_utils::rustc_hash::FxHashMapExt;
@@ -85,7 +86,8 @@ impl<'a> GenerateStage<'a> {
         .keys()
         .map(|id| {
           let chunk = &chunk_graph.chunk_table[*id];
-          (*id, chunk.name.clone().unwrap().to_string())
+          // Names of chunks could contain `/` in `preserveModules` mode.
+          (*id, legitimize_identifier_name(chunk.name.as_ref().unwrap()).into_owned())
         })
         .collect();
     });
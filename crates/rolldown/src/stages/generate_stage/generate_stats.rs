@@ -0,0 +1,166 @@
+//! The stats asset describes the chunk graph in the format of esbuild's metafile, so it could be consumed by
+//! existing visualizers such as https://esbuild.github.io/analyze/ and `esbuild-visualizer`.
+//!
+//! All paths are relative to `cwd` and separated by `/`. Maps are ordered by keys to keep the output stable.
+
+use std::collections::BTreeMap;
+
+use rolldown_common::{ExportsKind, Module, Output, OutputAsset};
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use sugar_path::SugarPath;
+
+use super::GenerateStage;
+
+#[derive(Serialize)]
+struct Stats {
+  inputs: BTreeMap<String, StatsInput>,
+  outputs: BTreeMap<String, StatsOutput>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsInput {
+  bytes: usize,
+  imports: Vec<StatsInputImport>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  format: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsInputImport {
+  path: String,
+  kind: String,
+  #[serde(skip_serializing_if = "std::ops::Not::not")]
+  external: bool,
+  original: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsOutput {
+  bytes: usize,
+  inputs: BTreeMap<String, StatsOutputInput>,
+  imports: Vec<StatsOutputImport>,
+  exports: Vec<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  entry_point: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsOutputInput {
+  bytes_in_output: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsOutputImport {
+  path: String,
+  kind: &'static str,
+}
+
+impl<'a> GenerateStage<'a> {
+  /// Returns the stats asset if `stats` is enabled. `output` should contain every other emitted file.
+  pub fn generate_stats(&self, output: &[Output]) -> anyhow::Result<Option<Output>> {
+    let Some(stats_filename) = &self.options.stats_filename else {
+      return Ok(None);
+    };
+
+    let modules = &self.link_output.module_table.modules;
+    // The runtime module is an implementation detail, so it's not reported as an input.
+    let runtime_idx = self.link_output.runtime.id();
+    let normal_modules = || {
+      modules.iter().filter_map(Module::as_normal).filter(move |module| module.idx != runtime_idx)
+    };
+    let id_to_stable_id = normal_modules()
+      .map(|module| (module.id.as_str(), module.stable_id.as_str()))
+      .collect::<FxHashMap<_, _>>();
+
+    let inputs = normal_modules()
+      .map(|module| {
+        let imports = module
+          .import_records
+          .iter()
+          .filter(|record| record.resolved_module != runtime_idx)
+          .map(|record| {
+            let importee = &modules[record.resolved_module];
+            StatsInputImport {
+              path: importee.stable_id().to_string(),
+              kind: record.kind.to_string(),
+              external: !importee.is_normal(),
+              original: record.module_request.to_string(),
+            }
+          })
+          .collect();
+        let format = match module.exports_kind {
+          ExportsKind::Esm => Some("esm"),
+          ExportsKind::CommonJs => Some("cjs"),
+          ExportsKind::None => None,
+        };
+        (module.stable_id.to_string(), StatsInput { bytes: module.source.len(), imports, format })
+      })
+      .collect();
+
+    let output_path = |filename: &str| {
+      let dir = self.options.cwd.join(&self.options.dir);
+      dir.join(filename).relative(&self.options.cwd).to_slash_lossy().into_owned()
+    };
+
+    let outputs = output
+      .iter()
+      .map(|output| {
+        let stats_output = match output {
+          Output::Chunk(chunk) => StatsOutput {
+            bytes: chunk.code.len(),
+            inputs: chunk
+              .modules
+              .iter()
+              .filter_map(|(id, module)| {
+                let stable_id = id_to_stable_id.get(id.as_str())?;
+                Some((
+                  (*stable_id).to_string(),
+                  StatsOutputInput { bytes_in_output: module.rendered_length },
+                ))
+              })
+              .collect(),
+            imports: chunk
+              .imports
+              .iter()
+              .map(|filename| StatsOutputImport {
+                path: output_path(filename),
+                kind: "import-statement",
+              })
+              .chain(chunk.dynamic_imports.iter().map(|filename| StatsOutputImport {
+                path: output_path(filename),
+                kind: "dynamic-import",
+              }))
+              .collect(),
+            exports: chunk.exports.clone(),
+            entry_point: chunk.facade_module_id.as_ref().map(|id| {
+              id_to_stable_id.get(id.as_str()).copied().unwrap_or(id.as_str()).to_string()
+            }),
+          },
+          Output::Asset(_) => StatsOutput {
+            bytes: output.content_as_bytes().len(),
+            inputs: BTreeMap::default(),
+            imports: vec![],
+            exports: vec![],
+            entry_point: None,
+          },
+        };
+        (output_path(output.filename()), stats_output)
+      })
+      .collect();
+
+    let source = serde_json::to_string_pretty(&Stats { inputs, outputs })?;
+
+    Ok(Some(Output::Asset(Box::new(OutputAsset {
+      filename: stats_filename.as_str().into(),
+      source: source.into(),
+      original_file_name: None,
+      name: None,
+    }))))
+  }
+}
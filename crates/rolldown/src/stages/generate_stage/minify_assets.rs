@@ -1,3 +1,5 @@
+use rolldown_common::InstantiationKind;
+use rolldown_css::{CssCompiler, CssMinifyOptions};
 use rolldown_ecmascript::EcmaCompiler;
 use rolldown_sourcemap::collapse_sourcemaps;
 use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
@@ -8,24 +10,37 @@ use super::GenerateStage;
 
 impl<'a> GenerateStage<'a> {
   pub fn minify_assets(&mut self, assets: &mut IndexAssets) -> anyhow::Result<()> {
-    if self.options.minify {
+    if let Some(minify_options) = &self.options.minify {
       assets.par_iter_mut().try_for_each(|asset| -> anyhow::Result<()> {
-        match asset.meta {
-          rolldown_common::InstantiationKind::Ecma(_) => {
+        let (minified_content, new_map) = match asset.meta {
+          InstantiationKind::Ecma(_) => {
             // TODO: Do we need to ensure `asset.filename` to be absolute path?
-            let (minified_content, new_map) =
-              EcmaCompiler::minify(&asset.content, asset.map.is_some(), &asset.filename)?;
-            asset.content = minified_content;
-            match (&asset.map, &new_map) {
-              (Some(origin_map), Some(new_map)) => {
-                asset.map = Some(collapse_sourcemaps(vec![origin_map, new_map]));
-              }
-              _ => {
-                // TODO: Map is dirty. Should we reset the `asset.map` to `None`?
-              }
-            }
+            EcmaCompiler::minify(
+              &asset.content,
+              asset.map.is_some(),
+              &asset.filename,
+              minify_options,
+            )?
+          }
+          InstantiationKind::Css => CssCompiler::minify(
+            &asset.content,
+            &asset.filename,
+            &CssMinifyOptions {
+              compress: minify_options.compress,
+              whitespace: minify_options.whitespace,
+              sourcemap: asset.map.is_some(),
+            },
+          )?,
+          InstantiationKind::None => return Ok(()),
+        };
+        asset.content = minified_content;
+        match (&asset.map, &new_map) {
+          (Some(origin_map), Some(new_map)) => {
+            asset.map = Some(collapse_sourcemaps(vec![origin_map, new_map]));
+          }
+          _ => {
+            // TODO: Map is dirty. Should we reset the `asset.map` to `None`?
           }
-          rolldown_common::InstantiationKind::None => {}
         }
         Ok(())
       })?;
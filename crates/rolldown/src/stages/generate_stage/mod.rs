@@ -35,8 +35,10 @@ use crate::{
 
 mod code_splitting;
 mod compute_cross_chunk_links;
+mod generate_stats;
 mod minify_assets;
 mod render_chunk_to_assets;
+mod resolve_import_meta;
 
 pub struct GenerateStage<'a> {
   link_output: &'a mut LinkStageOutput,
@@ -65,6 +67,14 @@ impl<'a> GenerateStage<'a> {
       deconflict_chunk_symbols(chunk, self.link_output, &self.options.format);
     });
 
+    let resolved_import_meta = if self.options.format.requires_scope_hoisting() {
+      self.resolve_import_meta(&chunk_graph).await?
+    } else {
+      FxHashMap::default()
+    };
+    self.mark_document_current_script_accesses(&mut chunk_graph, &resolved_import_meta);
+    let no_resolved_import_meta = FxHashMap::default();
+
     let ast_table_iter = self.link_output.ast_table.par_iter_mut();
     ast_table_iter
       .filter(|(_ast, owner)| {
@@ -91,6 +101,9 @@ impl<'a> GenerateStage<'a> {
               runtime: &self.link_output.runtime,
               chunk_graph: &chunk_graph,
               options: self.options,
+              resolved_import_meta: resolved_import_meta
+                .get(&module.idx)
+                .unwrap_or(&no_resolved_import_meta),
             },
             ast,
           );
@@ -104,6 +117,8 @@ impl<'a> GenerateStage<'a> {
                 module,
                 modules: &self.link_output.module_table.modules,
                 symbols: &self.link_output.symbols,
+                chunk,
+                options: self.options,
               },
               snippet: AstSnippet::new(alloc),
               generated_imports_set: FxHashSet::default(),
@@ -218,17 +233,24 @@ impl<'a> GenerateStage<'a> {
         pre_generated_name.clone()
       };
 
-      let hash_placeholder =
-        extracted_hash_pattern.map(|p| hash_placeholder_generator.generate(p.len.unwrap_or(8)));
+      let hash_placeholder = if chunk.file_name.is_some() {
+        None
+      } else {
+        extracted_hash_pattern.map(|p| hash_placeholder_generator.generate(p.len.unwrap_or(8)))
+      };
 
       let css_hash_placeholder =
         extracted_css_hash_pattern.map(|p| hash_placeholder_generator.generate(p.len.unwrap_or(8)));
 
-      let preliminary = filename_template.render(&FileNameRenderOptions {
-        name: Some(&chunk_name),
-        hash: hash_placeholder.as_deref(),
-        ..Default::default()
-      });
+      let preliminary = if let Some(file_name) = &chunk.file_name {
+        file_name.to_string()
+      } else {
+        filename_template.render(&FileNameRenderOptions {
+          name: Some(&chunk_name),
+          hash: hash_placeholder.as_deref(),
+          ..Default::default()
+        })
+      };
 
       let css_preliminary = css_filename_template.render(&FileNameRenderOptions {
         name: Some(&chunk_name),
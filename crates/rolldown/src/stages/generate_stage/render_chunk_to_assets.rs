@@ -6,6 +6,7 @@ use oxc::index::{index_vec, IndexVec};
 use rolldown_common::{Asset, InstantiationKind, Output, OutputAsset, OutputChunk, SourceMapType};
 use rolldown_ecmascript::EcmaCompiler;
 use rolldown_error::BuildDiagnostic;
+use rolldown_sourcemap::SourceMap;
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
 use sugar_path::SugarPath;
 
@@ -57,66 +58,15 @@ impl<'a> GenerateStage<'a> {
     {
       if let InstantiationKind::Ecma(ecma_meta) = rendered_chunk {
         let rendered_chunk = ecma_meta.rendered_chunk;
+        #[allow(clippy::cast_possible_truncation)]
+        let rendered_length = code.len() as u32;
         if let Some(map) = map.as_mut() {
           map.set_file(&rendered_chunk.filename);
-
-          let map_filename = format!("{}.map", rendered_chunk.filename.as_str());
-          let map_path = file_dir.join(&map_filename);
-
-          if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
-            let mut x_google_ignore_list = vec![];
-            for (index, source) in map.get_sources().enumerate() {
-              if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
-                #[allow(clippy::cast_possible_truncation)]
-                x_google_ignore_list.push(index as u32);
-              }
-            }
-            if !x_google_ignore_list.is_empty() {
-              map.set_x_google_ignore_list(x_google_ignore_list);
-            }
-          }
-
-          if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
-            let mut sources = Vec::with_capacity(map.get_sources().count());
-            for source in map.get_sources() {
-              sources.push(
-                sourcemap_path_transform.call(source, map_path.to_string_lossy().as_ref()).await?,
-              );
-            }
-            map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
-          }
-
-          // Normalize the windows path at final.
-          let sources =
-            map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
-          map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
-
-          if let Some(sourcemap) = &self.options.sourcemap {
-            match sourcemap {
-              SourceMapType::File | SourceMapType::Hidden => {
-                let source = map.to_json_string();
-                output_assets.push(Output::Asset(Box::new(OutputAsset {
-                  filename: map_filename.as_str().into(),
-                  source: source.into(),
-                  original_file_name: None,
-                  name: None,
-                })));
-                if matches!(sourcemap, SourceMapType::File) {
-                  code.push_str(&format!(
-                    "\n//# sourceMappingURL={}",
-                    Path::new(&map_filename)
-                      .file_name()
-                      .expect("should have filename")
-                      .to_string_lossy()
-                  ));
-                }
-              }
-              SourceMapType::Inline => {
-                let data_url = map.to_data_url();
-                code.push_str(&format!("\n//# sourceMappingURL={data_url}"));
-              }
-            }
-          }
+          output_assets.extend(
+            self
+              .finalize_sourcemap(map, &rendered_chunk.filename, &file_dir, &mut code, false)
+              .await?,
+          );
         }
 
         let sourcemap_filename =
@@ -125,6 +75,8 @@ impl<'a> GenerateStage<'a> {
           } else {
             Some(format!("{}.map", rendered_chunk.filename.as_str()))
           };
+        let original_length =
+          rendered_chunk.modules.values().map(|module| module.original_length).sum();
         output.push(Output::Chunk(Box::new(OutputChunk {
           name: rendered_chunk.name,
           filename: rendered_chunk.filename,
@@ -137,11 +89,19 @@ impl<'a> GenerateStage<'a> {
           module_ids: rendered_chunk.module_ids,
           imports: rendered_chunk.imports,
           dynamic_imports: rendered_chunk.dynamic_imports,
+          original_length,
+          rendered_length,
           map,
           sourcemap_filename,
           preliminary_filename: preliminary_filename.to_string(),
         })));
       } else {
+        if let Some(map) = map.as_mut() {
+          map.set_file(&filename);
+          let is_css = matches!(rendered_chunk, InstantiationKind::Css);
+          output_assets
+            .extend(self.finalize_sourcemap(map, &filename, &file_dir, &mut code, is_css).await?);
+        }
         output.push(Output::Asset(Box::new(OutputAsset {
           filename: filename.clone().into(),
           source: code.into(),
@@ -169,7 +129,83 @@ impl<'a> GenerateStage<'a> {
 
     output.extend(output_assets);
 
-    Ok(BundleOutput { assets: output, errors, warnings })
+    if let Some(stats) = self.generate_stats(&output)? {
+      output.push(stats);
+    }
+
+    Ok(BundleOutput { assets: output, errors, warnings, ..Default::default() })
+  }
+
+  /// Apply `sourcemapIgnoreList` and `sourcemapPathTransform` to the sourcemap of `filename`, then reference it
+  /// from `code` according to the `sourcemap` option. Returns the `.map` asset if it should be emitted.
+  async fn finalize_sourcemap(
+    &self,
+    map: &mut SourceMap,
+    filename: &str,
+    file_dir: &Path,
+    code: &mut String,
+    is_css: bool,
+  ) -> anyhow::Result<Option<Output>> {
+    let map_filename = format!("{filename}.map");
+    let map_path = file_dir.join(&map_filename);
+
+    if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
+      let mut x_google_ignore_list = vec![];
+      for (index, source) in map.get_sources().enumerate() {
+        if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
+          #[allow(clippy::cast_possible_truncation)]
+          x_google_ignore_list.push(index as u32);
+        }
+      }
+      if !x_google_ignore_list.is_empty() {
+        map.set_x_google_ignore_list(x_google_ignore_list);
+      }
+    }
+
+    if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
+      let mut sources = Vec::with_capacity(map.get_sources().count());
+      for source in map.get_sources() {
+        sources
+          .push(sourcemap_path_transform.call(source, map_path.to_string_lossy().as_ref()).await?);
+      }
+      map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+    }
+
+    // Normalize the windows path at final.
+    let sources = map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
+    map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+
+    // CSS doesn't support line comments.
+    let append_source_mapping_url = |code: &mut String, url: &str| {
+      if is_css {
+        code.push_str(&format!("\n/*# sourceMappingURL={url} */"));
+      } else {
+        code.push_str(&format!("\n//# sourceMappingURL={url}"));
+      }
+    };
+
+    match &self.options.sourcemap {
+      Some(sourcemap @ (SourceMapType::File | SourceMapType::Hidden)) => {
+        let source = map.to_json_string();
+        if matches!(sourcemap, SourceMapType::File) {
+          append_source_mapping_url(
+            code,
+            &Path::new(&map_filename).file_name().expect("should have filename").to_string_lossy(),
+          );
+        }
+        Ok(Some(Output::Asset(Box::new(OutputAsset {
+          filename: map_filename.as_str().into(),
+          source: source.into(),
+          original_file_name: None,
+          name: None,
+        }))))
+      }
+      Some(SourceMapType::Inline) => {
+        append_source_mapping_url(code, &map.to_data_url());
+        Ok(None)
+      }
+      None => Ok(None),
+    }
   }
 
   async fn instantiate_chunks(
@@ -0,0 +1,120 @@
+use std::collections::hash_map::Entry;
+
+use oxc::{allocator::Allocator, parser::Parser, span::SourceType, span::Span};
+use rolldown_common::{Module, ModuleIdx};
+use rolldown_error::BuildDiagnostic;
+use rolldown_plugin::HookResolveImportMetaArgs;
+use rustc_hash::FxHashMap;
+
+use crate::{
+  chunk_graph::ChunkGraph,
+  module_finalizers::{
+    is_import_meta_property_supported, lowers_import_meta_url_to_current_script,
+  },
+};
+
+use super::GenerateStage;
+
+impl<'a> GenerateStage<'a> {
+  /// Calls the `resolve_import_meta` hook for `import.meta` references of included modules, and returns the code
+  /// replacing them by their spans. References that are neither replaced by plugins nor supported by the output
+  /// format are warned about.
+  pub async fn resolve_import_meta(
+    &mut self,
+    chunk_graph: &ChunkGraph,
+  ) -> anyhow::Result<FxHashMap<ModuleIdx, FxHashMap<Span, String>>> {
+    let mut resolved_import_meta = FxHashMap::default();
+    for module in self.link_output.module_table.modules.iter().filter_map(Module::as_normal) {
+      if !module.is_included || module.import_meta_references.is_empty() {
+        continue;
+      }
+      let Some(chunk_idx) = chunk_graph.module_to_chunk[module.idx] else {
+        continue;
+      };
+      let chunk_id = chunk_graph.chunk_table[chunk_idx].preliminary_filename.as_deref().unwrap();
+
+      // The hook gets the same arguments for the same property, so it's called once per property.
+      let mut replacement_by_property = FxHashMap::default();
+      let mut replacements = FxHashMap::default();
+      for (span, property) in &module.import_meta_references {
+        let property = property.as_deref();
+        let replacement = match replacement_by_property.entry(property) {
+          Entry::Occupied(entry) => entry.into_mut(),
+          Entry::Vacant(entry) => {
+            let replacement = self
+              .plugin_driver
+              .resolve_import_meta(&HookResolveImportMetaArgs {
+                property,
+                chunk_id,
+                module_id: &module.id,
+                format: &self.options.format,
+              })
+              .await?;
+            if let Some(code) = &replacement {
+              let allocator = Allocator::default();
+              if Parser::new(&allocator, code, SourceType::default()).parse_expression().is_err() {
+                anyhow::bail!(
+                  "The `resolveImportMeta` hook returned invalid code for `{}` in {}: {code}",
+                  property
+                    .map_or_else(|| "import.meta".to_string(), |p| format!("import.meta.{p}")),
+                  module.stable_id
+                );
+              }
+            }
+            entry.insert(replacement)
+          }
+        };
+        match (replacement, property) {
+          (Some(code), _) => {
+            replacements.insert(*span, code.clone());
+          }
+          (None, Some(property))
+            if !is_import_meta_property_supported(&self.options.format, Some(property)) =>
+          {
+            self.link_output.warnings.push(
+              BuildDiagnostic::unsupported_import_meta_property(
+                module.id.to_string(),
+                module.source.clone(),
+                *span,
+                property.into(),
+                self.options.format.to_string().into(),
+              )
+              .with_severity_warning(),
+            );
+          }
+          (None, _) => {}
+        }
+      }
+      if !replacements.is_empty() {
+        resolved_import_meta.insert(module.idx, replacements);
+      }
+    }
+    Ok(resolved_import_meta)
+  }
+
+  /// Marks chunks whose lowered `import.meta.url` reads `document.currentScript`, so their wrappers capture it.
+  /// References replaced by the `resolve_import_meta` hook don't count.
+  pub fn mark_document_current_script_accesses(
+    &self,
+    chunk_graph: &mut ChunkGraph,
+    resolved_import_meta: &FxHashMap<ModuleIdx, FxHashMap<Span, String>>,
+  ) {
+    if !lowers_import_meta_url_to_current_script(self.options) {
+      return;
+    }
+    let modules = &self.link_output.module_table.modules;
+    chunk_graph.chunk_table.iter_mut().for_each(|chunk| {
+      chunk.accesses_document_current_script = chunk.modules.iter().any(|module_idx| {
+        let Some(module) = modules[*module_idx].as_normal() else {
+          return false;
+        };
+        let resolved = resolved_import_meta.get(module_idx);
+        module.is_included
+          && module.import_meta_references.iter().any(|(span, property)| {
+            matches!(property.as_deref(), None | Some("url"))
+              && !resolved.is_some_and(|resolved| resolved.contains_key(span))
+          })
+      });
+    });
+  }
+}
@@ -148,12 +148,28 @@ impl<'link> LinkStage<'link> {
       options: self.options,
       errors: Vec::default(),
       warnings: Vec::default(),
+      synthetic_named_exports_refs: FxHashMap::default(),
     };
 
     self.module_table.modules.iter().for_each(|module| {
       binding_ctx.match_imports_with_exports(module.idx());
     });
 
+    // Modules imported with `import * as ns` need the fallback export even if no named import is missing.
+    self.module_table.modules.iter().filter_map(Module::as_normal).for_each(|module| {
+      let Some(fallback_export_name) = &module.synthetic_named_exports else {
+        return;
+      };
+      let synthetic_named_exports_ref = binding_ctx.resolve_synthetic_named_exports(module.idx);
+      if synthetic_named_exports_ref.is_none() {
+        binding_ctx.errors.push(BuildDiagnostic::synthetic_named_exports_need_namespace_export(
+          module.stable_id.to_string(),
+          fallback_export_name.to_string(),
+        ));
+      }
+      binding_ctx.metas[module.idx].synthetic_named_exports_ref = synthetic_named_exports_ref;
+    });
+
     self.errors.extend(binding_ctx.errors);
     self.warnings.extend(binding_ctx.warnings);
 
@@ -332,6 +348,8 @@ struct BindImportsAndExportsContext<'a> {
   pub options: &'a SharedOptions,
   pub errors: Vec<BuildDiagnostic>,
   pub warnings: Vec<BuildDiagnostic>,
+  /// Resolved fallback exports of modules marked with `syntheticNamedExports`. `None` means it can't be resolved.
+  pub synthetic_named_exports_refs: FxHashMap<ModuleIdx, Option<SymbolRef>>,
 }
 
 impl<'a> BindImportsAndExportsContext<'a> {
@@ -533,7 +551,17 @@ impl<'a> BindImportsAndExportsContext<'a> {
           }
         },
         ImportStatus::NoMatch { .. } => {
-          break MatchImportKind::NoMatch;
+          // Missing named imports of modules marked with `syntheticNamedExports` are resolved as properties of
+          // the fallback export.
+          let Specifier::Literal(alias) = &tracker.imported else {
+            break MatchImportKind::NoMatch;
+          };
+          match self.resolve_synthetic_named_exports(tracker.importee) {
+            Some(namespace_ref) => {
+              MatchImportKind::NormalAndNamespace { namespace_ref, alias: alias.clone() }
+            }
+            None => break MatchImportKind::NoMatch,
+          }
         }
         ImportStatus::Found { symbol, potentially_ambiguous_export_star_refs, .. } => {
           for ambiguous_ref in &potentially_ambiguous_export_star_refs {
@@ -638,4 +666,51 @@ impl<'a> BindImportsAndExportsContext<'a> {
 
     ret
   }
+
+  /// Resolves the fallback export of a module marked with `syntheticNamedExports` to the symbol that declares it.
+  ///
+  /// Returns `None` if the module isn't marked, the fallback export doesn't exist, or the fallback export can't be
+  /// resolved to a declared symbol, e.g. it re-exports a missing export of the same module.
+  fn resolve_synthetic_named_exports(&mut self, module_idx: ModuleIdx) -> Option<SymbolRef> {
+    if let Some(resolved) = self.synthetic_named_exports_refs.get(&module_idx) {
+      return *resolved;
+    }
+    let normal_modules = self.normal_modules;
+    let fallback_export_name =
+      normal_modules[module_idx].as_normal()?.synthetic_named_exports.as_ref()?;
+
+    // Mark it as unresolved first to bail out of cycles while resolving the fallback export.
+    self.synthetic_named_exports_refs.insert(module_idx, None);
+
+    let symbol = self.metas[module_idx].resolved_exports.get(fallback_export_name)?.symbol_ref;
+    let owner =
+      normal_modules[symbol.owner].as_normal().expect("exports are declared by normal modules");
+    let resolved = match owner.named_imports.get(&symbol) {
+      // The fallback export is a re-export of another import, so resolve that import first.
+      Some(named_import) => {
+        let rec = &owner.import_records[named_import.record_id];
+        match &normal_modules[rec.resolved_module] {
+          Module::External(_) => Some(named_import.imported_as),
+          Module::Normal(importee) => match self.match_import_with_export(
+            normal_modules,
+            &mut MatchingContext { tracker_stack: Vec::default() },
+            ImportTracker {
+              importer: owner.idx,
+              importee: importee.idx,
+              imported: named_import.imported.clone(),
+              imported_as: named_import.imported_as,
+            },
+          ) {
+            MatchImportKind::Normal { symbol } => Some(symbol),
+            MatchImportKind::Namespace { namespace_ref } => Some(namespace_ref),
+            _ => None,
+          },
+        }
+      }
+      None => Some(symbol),
+    };
+
+    self.synthetic_named_exports_refs.insert(module_idx, resolved);
+    resolved
+  }
 }
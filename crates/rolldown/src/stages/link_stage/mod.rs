@@ -265,6 +265,8 @@ impl<'a> LinkStage<'a> {
       let stmt_infos = unsafe { &mut *(addr_of!(importer.stmt_infos).cast_mut()) };
       // store the symbol reference to the declared statement index
       let mut declared_symbol_for_stmt_pairs = vec![];
+      let is_amd_dynamic_require =
+        matches!(self.options.format, OutputFormat::Amd) && !self.options.inline_dynamic_imports;
       stmt_infos.infos.iter_mut_enumerated().for_each(|(stmt_idx, stmt_info)| {
         stmt_info.import_records.iter().for_each(|rec_id| {
           let rec = &importer.import_records[*rec_id];
@@ -280,19 +282,37 @@ impl<'a> LinkStage<'a> {
                       format!("import_{}", legitimize_identifier_name(&importee.name)).into();
                   } else {
                     // import ... from 'external' or export ... from 'external'
-                    let cjs_format = matches!(self.options.format, OutputFormat::Cjs);
-                    if cjs_format && !rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT) {
+                    // Formats getting external modules through `require()` or wrapper arguments need `__toESM`.
+                    let require_format = matches!(
+                      self.options.format,
+                      OutputFormat::Cjs
+                        | OutputFormat::Iife
+                        | OutputFormat::Umd
+                        | OutputFormat::Amd
+                    );
+                    if require_format
+                      && !rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT)
+                      && importee.to_esm_interop().is_some()
+                    {
                       stmt_info
                         .referenced_symbols
                         .push(self.runtime.resolve_symbol("__toESM").into());
                     }
                   }
                 }
+                // `import('external')` is turned into `require(['external'], (m) => resolve(__toESM(m)))` in AMD format.
+                ImportKind::DynamicImport
+                  if is_amd_dynamic_require && importee.to_esm_interop().is_some() =>
+                {
+                  stmt_info.referenced_symbols.push(self.runtime.resolve_symbol("__toESM").into());
+                }
                 _ => {}
               }
             }
             Module::Normal(importee) => {
               let importee_linking_info = &self.metas[importee.idx];
+              let needs_to_esm =
+                self.options.interop.for_normal_modules().resolve(importee.interop()).is_some();
               match rec.kind {
                 ImportKind::Import => {
                   let is_reexport_all = importer.star_exports.contains(rec_id);
@@ -329,9 +349,11 @@ impl<'a> LinkStage<'a> {
                         stmt_info
                           .referenced_symbols
                           .push(importee_linking_info.wrapper_ref.unwrap().into());
-                        stmt_info
-                          .referenced_symbols
-                          .push(self.runtime.resolve_symbol("__toESM").into());
+                        if needs_to_esm {
+                          stmt_info
+                            .referenced_symbols
+                            .push(self.runtime.resolve_symbol("__toESM").into());
+                        }
                         stmt_info
                           .referenced_symbols
                           .push(self.runtime.resolve_symbol("__reExport").into());
@@ -345,9 +367,11 @@ impl<'a> LinkStage<'a> {
                           .referenced_symbols
                           .push(importee_linking_info.wrapper_ref.unwrap().into());
                         // dbg!(&importee_linking_info.wrapper_ref);
-                        stmt_info
-                          .referenced_symbols
-                          .push(self.runtime.resolve_symbol("__toESM").into());
+                        if needs_to_esm {
+                          stmt_info
+                            .referenced_symbols
+                            .push(self.runtime.resolve_symbol("__toESM").into());
+                        }
                         declared_symbol_for_stmt_pairs.push((stmt_idx, rec.namespace_ref));
                         symbols.lock().unwrap().get_mut(rec.namespace_ref).name =
                           format!("import_{}", &importee.repr_name).into();
@@ -402,9 +426,11 @@ impl<'a> LinkStage<'a> {
                         stmt_info
                           .referenced_symbols
                           .push(importee_linking_info.wrapper_ref.unwrap().into());
-                        stmt_info
-                          .referenced_symbols
-                          .push(self.runtime.resolve_symbol("__toESM").into());
+                        if needs_to_esm {
+                          stmt_info
+                            .referenced_symbols
+                            .push(self.runtime.resolve_symbol("__toESM").into());
+                        }
                       }
                       WrapKind::Esm => {
                         // `(init_foo(), foo_exports)`
@@ -414,6 +440,11 @@ impl<'a> LinkStage<'a> {
                         stmt_info.referenced_symbols.push(importee.namespace_object_ref.into());
                       }
                     }
+                  } else if is_amd_dynamic_require {
+                    // `require(['./foo'], (m) => resolve(__toESM(m)))`
+                    stmt_info
+                      .referenced_symbols
+                      .push(self.runtime.resolve_symbol("__toESM").into());
                   }
                 }
               }
@@ -476,9 +507,17 @@ impl<'a> LinkStage<'a> {
                   declared_symbols.push(ecma_module.import_records[rec_idx].namespace_ref);
                 });
               }
-              OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::App => {}
+              OutputFormat::Cjs
+              | OutputFormat::Iife
+              | OutputFormat::Umd
+              | OutputFormat::Amd
+              | OutputFormat::App => {}
             }
           };
+          if let Some(synthetic_named_exports_ref) = meta.synthetic_named_exports_ref {
+            referenced_symbols.push(self.runtime.resolve_symbol("__reExport").into());
+            referenced_symbols.push(synthetic_named_exports_ref.into());
+          }
           // Create a StmtInfo to represent the statement that declares and constructs the Module Namespace Object.
           // Corresponding AST for this statement will be created by the finalizer.
           declared_symbols.push(ecma_module.namespace_object_ref);
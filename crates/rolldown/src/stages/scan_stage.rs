@@ -3,9 +3,9 @@ use std::sync::Arc;
 use anyhow::Result;
 use arcstr::ArcStr;
 use futures::future::join_all;
-use rolldown_common::{EntryPoint, ImportKind, ModuleTable, ResolvedId};
+use rolldown_common::{EntryPoint, ImportKind, ModuleTable, ResolvedId, SharedFileEmitter};
 use rolldown_error::{BuildDiagnostic, DiagnosableResult};
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_resolver::ResolveError;
 
@@ -13,7 +13,7 @@ use crate::{
   module_loader::{module_loader::ModuleLoaderOutput, ModuleLoader},
   runtime::RuntimeModuleBrief,
   type_alias::IndexEcmaAst,
-  types::symbols::Symbols,
+  types::{scan_stage_cache::ScanStageCache, symbols::Symbols},
   utils::resolve_id::resolve_id,
   SharedOptions, SharedResolver,
 };
@@ -21,7 +21,8 @@ use crate::{
 pub struct ScanStage {
   options: SharedOptions,
   plugin_driver: SharedPluginDriver,
-  fs: OsFileSystem,
+  file_emitter: SharedFileEmitter,
+  fs: SharedFileSystem,
   resolver: SharedResolver,
 }
 
@@ -40,25 +41,24 @@ impl ScanStage {
   pub fn new(
     options: SharedOptions,
     plugin_driver: SharedPluginDriver,
-    fs: OsFileSystem,
+    file_emitter: SharedFileEmitter,
+    fs: SharedFileSystem,
     resolver: SharedResolver,
   ) -> Self {
-    Self { options, plugin_driver, fs, resolver }
+    Self { options, plugin_driver, file_emitter, fs, resolver }
   }
 
+  /// If `cache` is provided, unchanged modules in it will be reused instead of being loaded and parsed again,
+  /// and the cache will be updated with the modules of this build.
   #[tracing::instrument(level = "debug", skip_all)]
-  pub async fn scan(&mut self) -> anyhow::Result<DiagnosableResult<ScanStageOutput>> {
+  pub async fn scan(
+    &mut self,
+    cache: Option<&mut ScanStageCache>,
+  ) -> anyhow::Result<DiagnosableResult<ScanStageOutput>> {
     if self.options.input.is_empty() {
       return Err(anyhow::format_err!("You must supply options.input to rolldown"));
     }
 
-    let module_loader = ModuleLoader::new(
-      Arc::clone(&self.options),
-      Arc::clone(&self.plugin_driver),
-      self.fs,
-      Arc::clone(&self.resolver),
-    )?;
-
     let user_entries = match self.resolve_user_defined_entries().await? {
       Ok(entries) => entries,
       Err(errors) => {
@@ -66,6 +66,15 @@ impl ScanStage {
       }
     };
 
+    let module_loader = ModuleLoader::new(
+      Arc::clone(&self.options),
+      Arc::clone(&self.plugin_driver),
+      Arc::clone(&self.file_emitter),
+      self.fs.clone(),
+      Arc::clone(&self.resolver),
+      cache,
+    )?;
+
     let ModuleLoaderOutput {
       module_table,
       entry_points,
@@ -4,7 +4,7 @@ use oxc::{
   span::{CompactStr as CompactString, Span},
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct AstSymbols {
   pub names: IndexVec<SymbolId, CompactString>,
   pub scope_ids: IndexVec<SymbolId, ScopeId>,
@@ -1,9 +1,14 @@
+use arcstr::ArcStr;
 use rolldown_common::Output;
 use rolldown_error::BuildDiagnostic;
+use rolldown_utils::indexmap::FxIndexSet;
 
 #[derive(Default)]
 pub struct BundleOutput {
   pub warnings: Vec<BuildDiagnostic>,
   pub errors: Vec<BuildDiagnostic>,
   pub assets: Vec<Output>,
+  /// Files the build depends on, which are the files of the modules and the files added by plugins through
+  /// `add_watch_file`.
+  pub watch_files: FxIndexSet<ArcStr>,
 }
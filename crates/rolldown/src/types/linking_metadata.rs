@@ -48,6 +48,9 @@ pub struct LinkingMetadata {
   // esbuild add it to `ExportKind`, but the linker shouldn't mutate the module.
   pub has_dynamic_exports: bool,
   pub shimmed_missing_exports: FxHashMap<Rstr, SymbolRef>,
+  /// The symbol of the fallback export if the module is marked with `syntheticNamedExports`. Missing named imports
+  /// from the module are rewritten to property accesses on it, and its properties are copied to the namespace object.
+  pub synthetic_named_exports_ref: Option<SymbolRef>,
 
   // Entry chunks need to generate code that doesn't belong to any module. This is the list of symbols are referenced by the
   // generated code. Tree-shaking will cares about these symbols to make sure they are not removed.
@@ -10,4 +10,5 @@ pub mod linking_metadata;
 pub mod module_factory;
 pub mod namespace_alias;
 pub mod oxc_parse_type;
+pub mod scan_stage_cache;
 pub mod symbols;
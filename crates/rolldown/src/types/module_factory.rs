@@ -5,16 +5,19 @@ use oxc::minifier::ReplaceGlobalDefinesConfig;
 use oxc::span::Span;
 use rolldown_common::{
   side_effects::HookSideEffects, ImportKind, ImportRecordIdx, ModuleDefFormat, ModuleIdx,
-  ModuleType, ModuleView, RawImportRecord, ResolvedId, StrOrBytes,
+  ModuleMeta, ModuleType, ModuleView, RawImportRecord, ResolvedId, SharedFileEmitter, StrOrBytes,
 };
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::{BuildDiagnostic, DiagnosableResult};
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::{SharedPluginDriver, __inner::resolve_id_check_external};
 use rolldown_resolver::ResolveError;
 use rolldown_sourcemap::SourceMap;
 use std::sync::Arc;
 
-use crate::{runtime::RUNTIME_MODULE_ID, SharedOptions, SharedResolver};
+use crate::{
+  runtime::RUNTIME_MODULE_ID, utils::bundle_worker::WorkerBundles, SharedOptions, SharedResolver,
+};
 
 use super::ast_symbols::AstSymbols;
 
@@ -26,6 +29,9 @@ pub struct CreateModuleContext<'a> {
   pub module_type: ModuleType,
   pub warnings: &'a mut Vec<BuildDiagnostic>,
   pub resolver: &'a SharedResolver,
+  pub fs: &'a SharedFileSystem,
+  pub file_emitter: &'a SharedFileEmitter,
+  pub worker_bundles: &'a WorkerBundles,
   pub replace_global_define_config: Option<ReplaceGlobalDefinesConfig>,
 }
 
@@ -47,6 +53,7 @@ impl<'a> CreateModuleContext<'a> {
         is_external: false,
         package_json: None,
         side_effects: None,
+        meta: ModuleMeta::default(),
       }));
     }
 
@@ -113,6 +120,7 @@ impl<'a> CreateModuleContext<'a> {
               is_external: true,
               package_json: None,
               side_effects: None,
+              meta: ModuleMeta::default(),
             });
           }
           e => {
@@ -0,0 +1,156 @@
+use std::path::PathBuf;
+
+use arcstr::ArcStr;
+use oxc::index::IndexVec;
+use rolldown_common::{ImportRecordIdx, Module, ModuleIdx, RawImportRecord, ResolvedId};
+use rolldown_ecmascript::EcmaAst;
+use rolldown_plugin::PluginWatchFiles;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::ast_symbols::AstSymbols;
+use crate::module_loader::task_result::NormalModuleTaskResult;
+
+/// Stores the outputs of `ModuleTask`s in the previous build, so rebuilds in watch mode only need to re-run
+/// `ModuleTask`s for the modules that have changed.
+#[derive(Default)]
+pub struct ScanStageCache {
+  pub modules: FxHashMap<ArcStr, CachedNormalModule>,
+  /// Files that are visited in the last build and should be watched.
+  pub watch_files: FxHashSet<ArcStr>,
+  /// Files added by plugins through `add_watch_file` in the previous builds. Cached modules don't call hooks again,
+  /// so the files added in their hooks are kept until the files or the modules are changed.
+  ///
+  /// Paths probed but not found by the resolver are tracked here as well, with the importers whose imports could
+  /// resolve to them once they're created, since the imports of cached modules aren't resolved again.
+  pub plugin_watch_files: PluginWatchFiles,
+}
+
+/// A snapshot of `NormalModuleTaskResult` taken before the module is mutated by the link stage and the generate stage.
+pub struct CachedNormalModule {
+  pub module_idx: ModuleIdx,
+  pub module: Module,
+  pub resolved_deps: IndexVec<ImportRecordIdx, ResolvedId>,
+  pub raw_import_records: IndexVec<ImportRecordIdx, RawImportRecord>,
+  pub ecma_related: Option<(EcmaAst, AstSymbols)>,
+}
+
+impl CachedNormalModule {
+  pub fn from_task_result(task_result: &NormalModuleTaskResult) -> Self {
+    Self {
+      module_idx: task_result.module_idx,
+      module: task_result.module.clone(),
+      resolved_deps: task_result.resolved_deps.clone(),
+      raw_import_records: task_result.raw_import_records.clone(),
+      ecma_related: task_result
+        .ecma_related
+        .as_ref()
+        .map(|(ast, symbols)| (ast.clone_with_another_arena(), symbols.clone())),
+    }
+  }
+
+  /// Assigns the module to `idx`, since modules are not fetched in the same order in each build.
+  pub fn set_module_idx(&mut self, idx: ModuleIdx) {
+    let from = std::mem::replace(&mut self.module_idx, idx);
+    if from == idx {
+      return;
+    }
+    if let Module::Normal(module) = &mut self.module {
+      module.set_idx(idx);
+    }
+    for rec in &mut self.raw_import_records {
+      if rec.namespace_ref.owner == from {
+        rec.namespace_ref.owner = idx;
+      }
+    }
+  }
+
+  /// Warnings of cached modules are reported in the build that creates them, so they are not replayed.
+  pub fn to_task_result(&self) -> NormalModuleTaskResult {
+    NormalModuleTaskResult {
+      module_idx: self.module_idx,
+      resolved_deps: self.resolved_deps.clone(),
+      raw_import_records: self.raw_import_records.clone(),
+      warnings: vec![],
+      module: self.module.clone(),
+      ecma_related: self
+        .ecma_related
+        .as_ref()
+        .map(|(ast, symbols)| (ast.clone_with_another_arena(), symbols.clone())),
+    }
+  }
+}
+
+impl ScanStageCache {
+  /// Remove the cached module of the changed file. Returns `true` if the file is part of the module graph.
+  ///
+  /// If the file is added by plugins, the modules depending on it are removed as well, or all modules if they are
+  /// unknown.
+  pub fn invalidate(&mut self, id: &str) -> bool {
+    match self.plugin_watch_files.shift_remove(id) {
+      Some(Some(importers)) => {
+        for importer in importers {
+          self.invalidate(&importer);
+        }
+      }
+      Some(None) => {
+        self.modules.clear();
+        self.plugin_watch_files.clear();
+      }
+      None => {}
+    }
+    // Hooks of the module will be called again, which add the files it depends on again.
+    self.plugin_watch_files.retain(|_, importers| {
+      importers.as_mut().map_or(true, |importers| {
+        importers.remove(id);
+        !importers.is_empty()
+      })
+    });
+    self.modules.remove(id).is_some()
+  }
+
+  /// Remove the cached modules importing the deleted file, so their imports are resolved again.
+  pub fn invalidate_importers(&mut self, id: &str) {
+    let importers = self
+      .modules
+      .iter()
+      .filter(|(_, cached)| cached.resolved_deps.iter().any(|dep| dep.id == id))
+      .map(|(importer, _)| importer.clone())
+      .collect::<Vec<_>>();
+    for importer in importers {
+      self.invalidate(&importer);
+    }
+  }
+
+  /// Watch the paths the resolver missed in the last build, and invalidate their importers once they're created.
+  pub fn add_missing_files(&mut self, files: Vec<(PathBuf, ArcStr)>) {
+    let mut missing_files = PluginWatchFiles::default();
+    for (path, importer) in files {
+      let path: ArcStr = path.to_string_lossy().as_ref().into();
+      missing_files
+        .entry(path)
+        .or_insert_with(|| Some(FxHashSet::default()))
+        .get_or_insert_with(FxHashSet::default)
+        .insert(importer);
+    }
+    self.add_plugin_watch_files(missing_files);
+  }
+
+  /// Merge the files added by plugins in the last build, and watch all of them.
+  pub fn add_plugin_watch_files(&mut self, files: PluginWatchFiles) {
+    for (file, importers) in files {
+      match importers {
+        Some(importers) => {
+          if let Some(existing) =
+            self.plugin_watch_files.entry(file).or_insert_with(|| Some(FxHashSet::default()))
+          {
+            existing.extend(importers);
+          }
+        }
+        None => {
+          self.plugin_watch_files.insert(file, None);
+        }
+      }
+    }
+    self.watch_files.extend(self.plugin_watch_files.keys().cloned());
+  }
+}
@@ -0,0 +1,97 @@
+use std::sync::{Arc, Mutex};
+
+use arcstr::ArcStr;
+use futures::future::BoxFuture;
+use rolldown_common::{FileEmitter, InputItem, NormalizedBundlerOptions, OutputFormat};
+use rolldown_fs::SharedFileSystem;
+use rolldown_plugin::PluginDriver;
+use rustc_hash::FxHashMap;
+use tokio::sync::OnceCell;
+
+use crate::{ast_scanner::WorkerType, types::bundle_output::BundleOutput, Bundler, SharedResolver};
+
+/// Bundles the worker in a separate build, so it gets its own runtime and doesn't share chunks with the build that
+/// references it. Plugins, the resolver and the file system are shared between the builds.
+///
+/// Since the plugins are shared, the hooks bracketing a build, e.g. `buildStart`, `generateBundle` and `closeBundle`,
+/// are only called for the outermost build. The outputs of the worker are emitted as assets of the build referencing
+/// it, so they are seen by its `generateBundle` hooks.
+///
+/// The returned future is boxed, since bundling the worker creates the modules of the worker, which may bundle other
+/// workers again.
+pub fn bundle_worker(
+  options: &NormalizedBundlerOptions,
+  plugin_driver: &PluginDriver,
+  resolver: &SharedResolver,
+  fs: &SharedFileSystem,
+  worker_id: &str,
+  worker_type: WorkerType,
+) -> BoxFuture<'static, anyhow::Result<BundleOutput>> {
+  let mut options = options.clone();
+  options.enclosing_workers.push(worker_id.into());
+  options.input = vec![InputItem { name: None, import: worker_id.to_string() }];
+  options.format = match worker_type {
+    WorkerType::Module => OutputFormat::Esm,
+    WorkerType::Classic => OutputFormat::Iife,
+  };
+  // The names of chunks are only unique in a build, so the outputs of workers always contain their hashes to not
+  // conflict with the chunks of the build referencing them or other workers.
+  options.entry_filenames = "assets/[name]-[hash].js".to_string().into();
+  options.chunk_filenames = "assets/[name]-[hash].js".to_string().into();
+  options.css_entry_filenames = "assets/[name]-[hash].css".to_string().into();
+  options.css_chunk_filenames = "assets/[name]-[hash].css".to_string().into();
+  // Classic workers can't import other chunks.
+  options.inline_dynamic_imports |= matches!(worker_type, WorkerType::Classic);
+  options.name = None;
+  options.preserve_modules = false;
+  // Logs of the worker are reported by the build referencing it.
+  options.on_log = None;
+  options.stats_filename = None;
+  options.depfile_filename = None;
+  let options = Arc::new(options);
+
+  let file_emitter = Arc::new(FileEmitter::new(Arc::clone(&options)));
+  let mut bundler = Bundler {
+    closed: false,
+    plugin_driver: PluginDriver::new_shared(
+      plugin_driver.plugins(),
+      resolver,
+      &file_emitter,
+      &options,
+    ),
+    file_emitter,
+    resolver: Arc::clone(resolver),
+    options,
+    fs: fs.clone(),
+    cache: None,
+    _log_guard: None,
+  };
+  Box::pin(async move { bundler.generate().await })
+}
+
+/// The outputs of a worker, which are emitted as assets of the build referencing it.
+pub struct WorkerBundle {
+  /// The file name of the entry chunk, which the url of the worker is rewritten to.
+  pub entry_file_name: ArcStr,
+  /// Reference ids of the emitted outputs.
+  pub reference_ids: Vec<ArcStr>,
+  pub watch_files: Vec<ArcStr>,
+}
+
+type WorkerBundleCell = Arc<OnceCell<Option<WorkerBundle>>>;
+
+/// Workers bundled in a build, so a worker referenced by several modules is only bundled and emitted once.
+///
+/// A worker is bundled differently for each [WorkerType], so they are cached separately. `None` is cached if the
+/// worker failed to bundle, and the errors are only reported by the module that bundled it.
+#[derive(Default)]
+pub struct WorkerBundles {
+  cells: Mutex<FxHashMap<(ArcStr, WorkerType), WorkerBundleCell>>,
+}
+
+impl WorkerBundles {
+  pub fn cell(&self, worker_id: &ArcStr, worker_type: WorkerType) -> WorkerBundleCell {
+    let mut cells = self.cells.lock().expect("worker bundles lock poisoned");
+    Arc::clone(cells.entry((worker_id.clone(), worker_type)).or_default())
+  }
+}
@@ -1,5 +1,5 @@
 use arcstr::ArcStr;
-use rolldown_common::{Chunk, Specifier, SymbolRef};
+use rolldown_common::{Chunk, ModuleIdx, Specifier, SymbolRef};
 
 use crate::{chunk_graph::ChunkGraph, stages::link_stage::LinkStageOutput};
 
@@ -15,6 +15,7 @@ pub enum RenderImportDeclarationSpecifier {
 }
 
 pub struct ExternalRenderImportStmt {
+  pub importee: ModuleIdx,
   pub path: ArcStr,
   pub symbol_ref: SymbolRef, // for cjs __toESM(require('foo')) and iife get deconflict name
   pub specifiers: RenderImportDeclarationSpecifier,
@@ -44,10 +45,6 @@ impl RenderImportStmt {
       Self::NormalRenderImportStmt(n) => &n.specifiers,
     }
   }
-
-  pub fn is_external(&self) -> bool {
-    matches!(self, Self::ExternalRenderImportStmt(_))
-  }
 }
 
 pub fn collect_render_chunk_imports(
@@ -108,6 +105,7 @@ pub fn collect_render_chunk_imports(
             has_importee_imported = true;
             render_import_stmts.push(RenderImportStmt::ExternalRenderImportStmt(
               ExternalRenderImportStmt {
+                importee: importee.idx,
                 path: importee.name.clone(),
                 symbol_ref: importee.symbol_ref,
                 specifiers: RenderImportDeclarationSpecifier::ImportStarSpecifier(
@@ -131,6 +129,7 @@ pub fn collect_render_chunk_imports(
     {
       render_import_stmts.push(RenderImportStmt::ExternalRenderImportStmt(
         ExternalRenderImportStmt {
+          importee: importee.idx,
           path: importee.name.clone(),
           symbol_ref: importee.symbol_ref,
           specifiers: RenderImportDeclarationSpecifier::ImportSpecifier(specifiers),
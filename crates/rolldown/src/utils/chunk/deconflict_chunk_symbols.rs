@@ -13,9 +13,8 @@ pub fn deconflict_chunk_symbols(
   let mut renamer =
     Renamer::new(&link_output.symbols, link_output.module_table.modules.len(), format);
 
-  if matches!(format, OutputFormat::Iife) {
-    // deconflict iife introduce symbols by external
-    // Also for UMD, AMD, but we don't support them yet.
+  if matches!(format, OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd) {
+    // deconflict symbols introduced by externals, since they are passed as arguments of the wrapper function
     chunk
       .imports_from_external_modules
       .iter()
@@ -0,0 +1,11 @@
+use crate::{module_finalizers::DOCUMENT_CURRENT_SCRIPT, types::generator::GenerateContext};
+
+/// Captures `document.currentScript` while the chunk is evaluated, since it's `null` in callbacks that might read the
+/// lowered `import.meta.url` later.
+pub fn render_document_current_script(ctx: &GenerateContext) -> Option<String> {
+  ctx.chunk.accesses_document_current_script.then(|| {
+    format!(
+      "var {DOCUMENT_CURRENT_SCRIPT} = typeof document !== 'undefined' ? document.currentScript : null;"
+    )
+  })
+}
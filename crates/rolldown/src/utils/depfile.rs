@@ -0,0 +1,63 @@
+//! Depfiles list the files a build depends on with the syntax of Makefile rules, which is understood by Make,
+//! Ninja and Bazel, so they know when the bundle needs to be rebuilt.
+
+use std::path::Path;
+
+use sugar_path::SugarPath;
+
+/// Renders a rule whose targets are `outputs` and whose prerequisites are `inputs`.
+pub fn render_depfile(outputs: &[String], inputs: &[String]) -> String {
+  let mut depfile = outputs.iter().map(|output| escape(output)).collect::<Vec<_>>().join(" ");
+  depfile.push(':');
+  for input in inputs {
+    depfile.push_str(" \\\n  ");
+    depfile.push_str(&escape(input));
+  }
+  depfile.push('\n');
+  depfile
+}
+
+/// Paths in `cwd` are written as relative paths, so depfiles don't change if the project is moved.
+pub fn depfile_path(path: &Path, cwd: &Path) -> String {
+  path.strip_prefix(cwd).unwrap_or(path).to_slash_lossy().into_owned()
+}
+
+fn escape(path: &str) -> String {
+  let mut escaped = String::with_capacity(path.len());
+  for c in path.chars() {
+    match c {
+      ' ' | '#' => {
+        escaped.push('\\');
+        escaped.push(c);
+      }
+      '$' => escaped.push_str("$$"),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_render_depfile() {
+    assert_eq!(
+      render_depfile(
+        &["dist/main.js".to_string(), "dist/main.js.map".to_string()],
+        &["src/main.js".to_string(), "src/my file#1.js".to_string(), "src/$id.js".to_string()]
+      ),
+      "dist/main.js dist/main.js.map: \\\n  src/main.js \\\n  src/my\\ file\\#1.js \\\n  src/$$id.js\n"
+    );
+  }
+
+  #[test]
+  fn test_depfile_path() {
+    assert_eq!(
+      depfile_path(Path::new("/project/src/main.js"), Path::new("/project")),
+      "src/main.js"
+    );
+    assert_eq!(depfile_path(Path::new("/other/main.js"), Path::new("/project")), "/other/main.js");
+  }
+}
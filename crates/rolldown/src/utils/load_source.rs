@@ -1,17 +1,21 @@
 use rolldown_common::{
-  side_effects::HookSideEffects, ModuleType, NormalizedBundlerOptions, ResolvedId, StrOrBytes,
+  side_effects::HookSideEffects, ModuleMeta, ModuleType, NormalizedBundlerOptions, ResolvedId,
+  StrOrBytes, SyntheticNamedExports,
 };
 use rolldown_plugin::{HookLoadArgs, PluginDriver};
 use rolldown_sourcemap::SourceMap;
 use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn load_source(
   plugin_driver: &PluginDriver,
   resolved_id: &ResolvedId,
   fs: &dyn rolldown_fs::FileSystem,
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
+  meta: &mut ModuleMeta,
+  synthetic_named_exports: &mut Option<SyntheticNamedExports>,
   options: &NormalizedBundlerOptions,
 ) -> anyhow::Result<(StrOrBytes, ModuleType)> {
   let (maybe_source, maybe_module_type) = if let Some(load_hook_output) =
@@ -21,6 +25,12 @@ pub async fn load_source(
     if let Some(v) = load_hook_output.side_effects {
       *side_effects = Some(v);
     }
+    if let Some(v) = load_hook_output.meta {
+      meta.extend(v);
+    }
+    if let Some(v) = load_hook_output.synthetic_named_exports {
+      *synthetic_named_exports = Some(v);
+    }
 
     (Some(load_hook_output.code), load_hook_output.module_type)
   } else if resolved_id.ignored {
@@ -7,22 +7,29 @@ use super::module_finalizers::scope_hoisting::{
 };
 pub mod apply_inner_plugins;
 pub mod augment_chunk_hash;
+pub mod bundle_worker;
 pub mod call_expression_ext;
 pub mod chunk;
+pub mod depfile;
 pub mod ecma_visitors;
 pub mod extract_meaningful_input_name_from_path;
 pub mod hash_placeholder;
 pub mod load_source;
 pub mod make_ast_symbol_and_scope;
+pub mod new_expression_ext;
 pub mod normalize_options;
 pub mod parse_to_ecma_ast;
+pub mod persistent_cache;
 pub mod pre_process_ecma_ast;
+pub mod preserve_modules_chunk_name;
 pub mod renamer;
 pub mod render_chunks;
 pub mod render_ecma_module;
 pub mod resolve_id;
 pub mod transform_source;
 pub mod tweak_ast_for_scanning;
+#[cfg(feature = "deserialize_bundler_options")]
+pub mod validate_options;
 
 #[tracing::instrument(level = "trace", skip_all)]
 pub fn finalize_normal_module(
@@ -0,0 +1,64 @@
+use oxc::ast::ast;
+use rolldown_common::AstScopes;
+use rolldown_ecmascript::ExpressionExt;
+
+use crate::ast_scanner::WorkerType;
+
+pub trait NewExpressionExt<'ast> {
+  /// Returns the string literal in `new URL('./foo.png', import.meta.url)`, where `URL` is the global one.
+  fn new_url_with_import_meta_url(&self, scope: &AstScopes) -> Option<&ast::StringLiteral<'ast>>;
+
+  /// Returns the `new URL(...)` expression and the type of the worker in
+  /// `new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })` or the `SharedWorker` equivalent.
+  fn new_worker_with_url(
+    &self,
+    scope: &AstScopes,
+  ) -> Option<(&ast::NewExpression<'ast>, WorkerType)>;
+}
+
+impl<'ast> NewExpressionExt<'ast> for ast::NewExpression<'ast> {
+  fn new_url_with_import_meta_url(&self, scope: &AstScopes) -> Option<&ast::StringLiteral<'ast>> {
+    if !is_global_callee(&self.callee, "URL", scope) {
+      return None;
+    }
+    match self.arguments.as_slice() {
+      [ast::Argument::StringLiteral(specifier), ast::Argument::StaticMemberExpression(base)]
+        if base.property.name == "url" && base.object.is_import_meta() =>
+      {
+        Some(specifier)
+      }
+      _ => None,
+    }
+  }
+
+  fn new_worker_with_url(
+    &self,
+    scope: &AstScopes,
+  ) -> Option<(&ast::NewExpression<'ast>, WorkerType)> {
+    if !is_global_callee(&self.callee, "Worker", scope)
+      && !is_global_callee(&self.callee, "SharedWorker", scope)
+    {
+      return None;
+    }
+    let (url, options) = match self.arguments.as_slice() {
+      [ast::Argument::NewExpression(url)] => (url, None),
+      [ast::Argument::NewExpression(url), options] => (url, Some(options)),
+      _ => return None,
+    };
+    url.new_url_with_import_meta_url(scope)?;
+    // Workers are classic scripts unless `{ type: 'module' }` is passed.
+    let is_module = matches!(options, Some(ast::Argument::ObjectExpression(options)) if options.properties.iter().any(|prop| {
+      matches!(
+        prop,
+        ast::ObjectPropertyKind::ObjectProperty(prop)
+          if prop.key.is_specific_static_name("type")
+            && matches!(&prop.value, ast::Expression::StringLiteral(value) if value.value == "module")
+      )
+    }));
+    Some((url, if is_module { WorkerType::Module } else { WorkerType::Classic }))
+  }
+}
+
+fn is_global_callee(callee: &ast::Expression, name: &str, scope: &AstScopes) -> bool {
+  matches!(callee, ast::Expression::Identifier(ident) if ident.name == name && ident.reference_id.get().map_or(true, |id| scope.is_unresolved(id)))
+}
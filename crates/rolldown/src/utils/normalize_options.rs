@@ -1,6 +1,9 @@
 use oxc::minifier::InjectGlobalVariablesConfig;
-use rolldown_common::{InjectImport, ModuleType, NormalizedBundlerOptions, Platform};
+use rolldown_common::{
+  InjectImport, MinifyOptions, ModuleType, NormalizedBundlerOptions, Platform,
+};
 use rustc_hash::FxHashMap;
+use sugar_path::SugarPath;
 
 pub struct NormalizeOptionsReturn {
   pub options: NormalizedBundlerOptions,
@@ -74,11 +77,22 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
       .unwrap_or_default(),
   );
 
+  let cwd =
+    raw_options.cwd.unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir"));
+
+  // Relative directories are resolved against `cwd`, and absolute ones are kept as-is by `join`.
+  let persistent_cache_dir = raw_options
+    .persistent_cache
+    .map(|cache| cwd.join(cache.dir.as_deref().unwrap_or("node_modules/.rolldown")));
+
+  let preserve_modules_root =
+    raw_options.preserve_modules_root.map(|root| cwd.join(root).normalize());
+
+  let minify = raw_options.minify.and_then(MinifyOptions::normalize);
+
   let normalized = NormalizedBundlerOptions {
     input: raw_options.input.unwrap_or_default(),
-    cwd: raw_options
-      .cwd
-      .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir")),
+    cwd,
     external: raw_options.external,
     treeshake: raw_options.treeshake,
     platform,
@@ -101,6 +115,7 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     footer: raw_options.footer,
     intro: raw_options.intro,
     outro: raw_options.outro,
+    interop: raw_options.interop.unwrap_or_default(),
     es_module: raw_options.es_module.unwrap_or_default(),
     dir: raw_options.dir.unwrap_or_else(|| "dist".to_string()),
     format: raw_options.format.unwrap_or(crate::OutputFormat::Esm),
@@ -112,17 +127,35 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
     module_types: loaders,
     experimental: raw_options.experimental.unwrap_or_default(),
-    minify: raw_options.minify.unwrap_or(false),
+    profiler_names: raw_options.profiler_names.unwrap_or(minify.is_none()),
+    minify,
     define: raw_options.define.map(|inner| inner.into_iter().collect()).unwrap_or_default(),
     inject: raw_options.inject.unwrap_or_default(),
     oxc_inject_global_variables_config,
     extend: raw_options.extend.unwrap_or(false),
     external_live_bindings: raw_options.external_live_bindings.unwrap_or(true),
     inline_dynamic_imports: raw_options.inline_dynamic_imports.unwrap_or(false),
+    preserve_modules: raw_options.preserve_modules.unwrap_or(false),
+    preserve_modules_root,
     advanced_chunks: raw_options.advanced_chunks,
     checks: raw_options.checks.unwrap_or_default(),
+    log_level: raw_options.log_level.unwrap_or_default(),
+    on_log: raw_options.on_log,
     // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L2767
-    profiler_names: raw_options.profiler_names.unwrap_or(!raw_options.minify.unwrap_or(false)),
+    css_modules_pattern: raw_options
+      .css_modules
+      .and_then(|css_modules| css_modules.pattern)
+      .unwrap_or_else(|| "[hash]_[local]".to_string()),
+    persistent_cache_dir,
+    stats_filename: raw_options
+      .stats
+      .map(|stats| stats.filename.unwrap_or_else(|| "stats.json".to_string())),
+    depfile_json: raw_options.depfile.as_ref().and_then(|depfile| depfile.json).unwrap_or(false),
+    depfile_filename: raw_options
+      .depfile
+      .map(|depfile| depfile.filename.unwrap_or_else(|| "deps.d".to_string())),
+    watch: raw_options.watch.unwrap_or_default(),
+    enclosing_workers: vec![],
   };
 
   NormalizeOptionsReturn { options: normalized, resolve_options: raw_resolve }
@@ -0,0 +1,247 @@
+//! Persistent cache of the `load` + `transform` results, stored on the disk and shared across processes.
+//!
+//! Each entry is a JSON file named by the hash of everything that could affect the transformed result:
+//!
+//! - The version of rolldown and the format of entries.
+//! - The names and the cache keys of the plugins in order, `cwd`, `platform` and whether sourcemaps are enabled.
+//! - The resolved id, the loaded source, the module type, side effects, meta and synthetic named exports before
+//!   `transform`.
+//!
+//! Entries are never updated in place. Changing any of the above produces a different key, so stale entries are
+//! simply not read anymore. Parsing is not cached, since ASTs live in arenas and can't be persisted.
+
+use std::path::PathBuf;
+
+use rolldown_common::{
+  side_effects::HookSideEffects, ModuleMeta, ModuleType, NormalizedBundlerOptions, ResolvedId,
+  SyntheticNamedExports,
+};
+use rolldown_fs::FileSystem;
+use rolldown_plugin::PluginDriver;
+use rolldown_sourcemap::SourceMap;
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::Xxh3;
+
+/// Bump this if the format of entries or the meaning of cached data changes.
+const CACHE_FORMAT_VERSION: &str = "4";
+
+pub struct PersistentCache {
+  dir: PathBuf,
+  /// Hash of the inputs shared by all modules in this build.
+  fingerprint: u128,
+}
+
+/// The result of running `transform` hooks on a loaded module.
+pub struct CachedTransformResult {
+  pub code: String,
+  /// Sourcemaps returned by `transform` hooks. Sourcemaps returned by `load` hooks are not included.
+  pub sourcemap_chain: Vec<SourceMap>,
+  pub side_effects: Option<HookSideEffects>,
+  /// The meta after `transform` hooks, including the meta before them.
+  pub meta: ModuleMeta,
+  pub synthetic_named_exports: Option<SyntheticNamedExports>,
+  pub module_type: ModuleType,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawEntry {
+  code: String,
+  sourcemaps: Vec<String>,
+  side_effects: Option<String>,
+  meta: ModuleMeta,
+  synthetic_named_exports: Option<serde_json::Value>,
+  module_type: String,
+}
+
+impl PersistentCache {
+  /// Returns `None` if any plugin doesn't provide a cache key, since its results can't be safely reused.
+  pub fn new(
+    dir: PathBuf,
+    options: &NormalizedBundlerOptions,
+    plugin_driver: &PluginDriver,
+  ) -> Option<Self> {
+    let mut hasher = Xxh3::default();
+    write_field(&mut hasher, CACHE_FORMAT_VERSION);
+    write_field(&mut hasher, env!("CARGO_PKG_VERSION"));
+    for (name, cache_key) in plugin_driver.plugin_cache_keys()? {
+      write_field(&mut hasher, &name);
+      write_field(&mut hasher, &cache_key);
+    }
+    write_field(&mut hasher, &options.cwd.to_string_lossy());
+    write_field(&mut hasher, &format!("{:?}", options.platform));
+    write_field(&mut hasher, if options.sourcemap.is_some() { "sourcemap" } else { "" });
+    Some(Self { dir, fingerprint: hasher.digest128() })
+  }
+
+  pub fn key(
+    &self,
+    resolved_id: &ResolvedId,
+    source: &str,
+    module_type: &ModuleType,
+    side_effects: Option<HookSideEffects>,
+    meta: &ModuleMeta,
+    synthetic_named_exports: Option<&SyntheticNamedExports>,
+  ) -> String {
+    let mut hasher = Xxh3::default();
+    hasher.update(&self.fingerprint.to_le_bytes());
+    write_field(&mut hasher, &resolved_id.id);
+    write_field(&mut hasher, &module_type.to_string());
+    write_field(&mut hasher, side_effects.map_or("", encode_side_effects));
+    write_field(&mut hasher, &serde_json::Value::Object(meta.clone()).to_string());
+    write_field(
+      &mut hasher,
+      &synthetic_named_exports.map(encode_synthetic_named_exports).unwrap_or_default().to_string(),
+    );
+    write_field(&mut hasher, source);
+    format!("{:032x}", hasher.digest128())
+  }
+
+  /// Returns `None` if the entry doesn't exist or can't be read, e.g. it's written by a crashed process.
+  pub fn get(&self, fs: &dyn FileSystem, key: &str) -> Option<CachedTransformResult> {
+    let content = fs.read(&self.entry_path(key)).ok()?;
+    let raw: RawEntry = serde_json::from_slice(&content).ok()?;
+    let sourcemap_chain = raw
+      .sourcemaps
+      .iter()
+      .map(|map| SourceMap::from_json_string(map).ok())
+      .collect::<Option<Vec<_>>>()?;
+    let side_effects = match raw.side_effects {
+      Some(side_effects) => Some(decode_side_effects(&side_effects)?),
+      None => None,
+    };
+    let synthetic_named_exports = match raw.synthetic_named_exports {
+      Some(synthetic_named_exports) => {
+        Some(decode_synthetic_named_exports(synthetic_named_exports)?)
+      }
+      None => None,
+    };
+    Some(CachedTransformResult {
+      code: raw.code,
+      sourcemap_chain,
+      side_effects,
+      meta: raw.meta,
+      synthetic_named_exports,
+      module_type: decode_module_type(&raw.module_type),
+    })
+  }
+
+  /// Failing to write the cache doesn't fail the build, the module will be transformed again next time.
+  #[allow(clippy::too_many_arguments)]
+  pub fn set(
+    &self,
+    fs: &dyn FileSystem,
+    key: &str,
+    code: &str,
+    sourcemap_chain: &[SourceMap],
+    side_effects: Option<HookSideEffects>,
+    meta: &ModuleMeta,
+    synthetic_named_exports: Option<&SyntheticNamedExports>,
+    module_type: &ModuleType,
+  ) {
+    let raw = RawEntry {
+      code: code.to_string(),
+      sourcemaps: sourcemap_chain.iter().map(SourceMap::to_json_string).collect(),
+      side_effects: side_effects.map(|side_effects| encode_side_effects(side_effects).to_string()),
+      meta: meta.clone(),
+      synthetic_named_exports: synthetic_named_exports.map(encode_synthetic_named_exports),
+      module_type: module_type.to_string(),
+    };
+    let ret = serde_json::to_vec(&raw).map_err(std::io::Error::from).and_then(|content| {
+      fs.create_dir_all(&self.dir)?;
+      fs.write(&self.entry_path(key), &content)
+    });
+    if let Err(err) = ret {
+      tracing::debug!("Failed to write persistent cache entry {key}: {err}");
+    }
+  }
+
+  fn entry_path(&self, key: &str) -> PathBuf {
+    self.dir.join(format!("{key}.json"))
+  }
+}
+
+/// Fields are terminated by `\0`, so moving bytes between adjacent fields changes the hash.
+fn write_field(hasher: &mut Xxh3, field: &str) {
+  hasher.update(field.as_bytes());
+  hasher.update(&[0]);
+}
+
+fn encode_side_effects(side_effects: HookSideEffects) -> &'static str {
+  match side_effects {
+    HookSideEffects::True => "true",
+    HookSideEffects::False => "false",
+    HookSideEffects::NoTreeshake => "no-treeshake",
+  }
+}
+
+fn decode_side_effects(side_effects: &str) -> Option<HookSideEffects> {
+  match side_effects {
+    "true" => Some(HookSideEffects::True),
+    "false" => Some(HookSideEffects::False),
+    "no-treeshake" => Some(HookSideEffects::NoTreeshake),
+    _ => None,
+  }
+}
+
+fn encode_synthetic_named_exports(
+  synthetic_named_exports: &SyntheticNamedExports,
+) -> serde_json::Value {
+  match synthetic_named_exports {
+    SyntheticNamedExports::Bool(value) => serde_json::Value::Bool(*value),
+    SyntheticNamedExports::Name(name) => serde_json::Value::String(name.clone()),
+  }
+}
+
+fn decode_synthetic_named_exports(
+  synthetic_named_exports: serde_json::Value,
+) -> Option<SyntheticNamedExports> {
+  match synthetic_named_exports {
+    serde_json::Value::Bool(value) => Some(SyntheticNamedExports::Bool(value)),
+    serde_json::Value::String(name) => Some(SyntheticNamedExports::Name(name)),
+    _ => None,
+  }
+}
+
+/// `ModuleType::from_str_with_fallback` doesn't recognize `css`, which is only assigned by `moduleTypes`.
+fn decode_module_type(module_type: &str) -> ModuleType {
+  match module_type {
+    "css" => ModuleType::Css,
+    _ => ModuleType::from_str_with_fallback(module_type),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_module_type_round_trip() {
+    for module_type in [ModuleType::Js, ModuleType::Tsx, ModuleType::Css, ModuleType::Base64] {
+      assert_eq!(decode_module_type(&module_type.to_string()), module_type);
+    }
+  }
+
+  #[test]
+  fn test_side_effects_round_trip() {
+    for side_effects in
+      [HookSideEffects::True, HookSideEffects::False, HookSideEffects::NoTreeshake]
+    {
+      let decoded = decode_side_effects(encode_side_effects(side_effects));
+      assert_eq!(decoded.map(encode_side_effects), Some(encode_side_effects(side_effects)));
+    }
+  }
+
+  #[test]
+  fn test_synthetic_named_exports_round_trip() {
+    for synthetic_named_exports in [
+      SyntheticNamedExports::Bool(true),
+      SyntheticNamedExports::Bool(false),
+      SyntheticNamedExports::Name("true".to_string()),
+    ] {
+      let decoded =
+        decode_synthetic_named_exports(encode_synthetic_named_exports(&synthetic_named_exports));
+      assert_eq!(decoded, Some(synthetic_named_exports));
+    }
+  }
+}
@@ -0,0 +1,89 @@
+use std::{
+  ffi::OsStr,
+  path::{Component, Path, PathBuf},
+};
+
+use rolldown_utils::{path_ext::clean_url, sanitize_file_name::sanitize_invalid_file_name_chars};
+use sugar_path::SugarPath;
+
+/// Extensions of modules that are compiled to JavaScript, which are removed from names of chunks.
+const NON_ASSET_EXTENSIONS: [&str; 8] = ["js", "jsx", "ts", "tsx", "mjs", "mts", "cjs", "cts"];
+
+/// Returns the `[name]` of the chunk of a module in `preserveModules` mode, which is the path of the module relative
+/// to `preserve_modules_root` or `input_base`. Modules without an absolute path are put in `_virtual`.
+///
+/// ref: https://github.com/rollup/rollup/blob/a8647dac0fe46c86183be8596ef7de25bc5b4e4b/src/Chunk.ts#L1047-L1070
+pub fn preserve_modules_chunk_name(
+  id: &str,
+  input_base: &Path,
+  preserve_modules_root: Option<&Path>,
+) -> String {
+  let path = Path::new(clean_url(id));
+  let path_without_extension = match path.extension().and_then(OsStr::to_str) {
+    Some(ext) if NON_ASSET_EXTENSIONS.contains(&ext) => path.with_extension(""),
+    _ => path.to_path_buf(),
+  };
+
+  if !path.is_absolute() {
+    let file_name = path_without_extension.file_name().unwrap_or(path.as_os_str());
+    return format!("_virtual/{}", sanitize_invalid_file_name_chars(&file_name.to_string_lossy()));
+  }
+
+  let relative = match preserve_modules_root {
+    Some(root) if path_without_extension.starts_with(root) => path_without_extension.relative(root),
+    _ => path_without_extension.relative(input_base),
+  };
+  relative
+    .components()
+    .filter(|component| matches!(component, Component::Normal(_) | Component::ParentDir))
+    .map(|component| sanitize_invalid_file_name_chars(&component.as_os_str().to_string_lossy()))
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+/// Returns the deepest directory containing all the given modules, which is the default root of `preserveModules`.
+pub fn common_dir_of_modules<'a>(ids: impl Iterator<Item = &'a str>) -> Option<PathBuf> {
+  let mut common_dir: Option<PathBuf> = None;
+  for path in ids.map(|id| Path::new(clean_url(id))).filter(|path| path.is_absolute()) {
+    let dir = path.parent().unwrap_or(path);
+    common_dir = Some(match common_dir {
+      None => dir.to_path_buf(),
+      Some(common_dir) => common_dir
+        .components()
+        .zip(dir.components())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a)
+        .collect(),
+    });
+  }
+  common_dir
+}
+
+#[test]
+fn test_preserve_modules_chunk_name() {
+  let input_base = Path::new("/project");
+  assert_eq!(
+    preserve_modules_chunk_name("/project/src/utils/a.ts", input_base, None),
+    "src/utils/a"
+  );
+  assert_eq!(
+    preserve_modules_chunk_name(
+      "/project/src/a.js?raw",
+      input_base,
+      Some(Path::new("/project/src"))
+    ),
+    "a"
+  );
+  assert_eq!(preserve_modules_chunk_name("/project/src/a.css", input_base, None), "src/a.css");
+  assert_eq!(preserve_modules_chunk_name("/project/foo.config.ts", input_base, None), "foo.config");
+  assert_eq!(
+    preserve_modules_chunk_name("\0virtual:config", input_base, None),
+    "_virtual/_virtual_config"
+  );
+}
+
+#[test]
+fn test_common_dir_of_modules() {
+  let ids = ["/project/src/a.js", "/project/src/utils/b.js", "\0virtual"];
+  assert_eq!(common_dir_of_modules(ids.into_iter()), Some(PathBuf::from("/project/src")));
+}
@@ -7,6 +7,7 @@ use rustc_hash::FxHashMap;
 use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 
+use crate::module_finalizers::DOCUMENT_CURRENT_SCRIPT;
 use crate::types::symbols::Symbols;
 
 #[derive(Debug)]
@@ -38,8 +39,13 @@ impl<'name> Renamer<'name> {
     // Port from https://github.com/rollup/rollup/blob/master/src/Chunk.ts#L1377-L1394.
     let manual_reserved = match format {
       OutputFormat::Esm | OutputFormat::App => vec![],
-      OutputFormat::Cjs => vec!["module", "require", "__filename", "__dirname", "exports"],
-      OutputFormat::Iife => vec!["exports"], // Also for UMD, AMD, but we don't support them yet.
+      OutputFormat::Cjs => {
+        vec!["module", "require", "__filename", "__dirname", "exports", DOCUMENT_CURRENT_SCRIPT]
+      }
+      OutputFormat::Iife => vec!["exports", DOCUMENT_CURRENT_SCRIPT],
+      OutputFormat::Umd | OutputFormat::Amd => {
+        vec!["module", "require", "define", "exports", DOCUMENT_CURRENT_SCRIPT]
+      }
     };
     Self {
       canonical_names: FxHashMap::default(),
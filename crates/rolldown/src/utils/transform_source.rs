@@ -1,24 +1,31 @@
 use anyhow::Result;
-use rolldown_common::ModuleType;
 use rolldown_common::{side_effects::HookSideEffects, ResolvedId};
+use rolldown_common::{ModuleMeta, ModuleType, SyntheticNamedExports};
 use rolldown_plugin::{HookTransformArgs, PluginDriver};
 use rolldown_sourcemap::SourceMap;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn transform_source(
   plugin_driver: &PluginDriver,
   resolved_id: &ResolvedId,
   source: String,
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
+  meta: &mut ModuleMeta,
+  synthetic_named_exports: &mut Option<SyntheticNamedExports>,
   module_type: &mut ModuleType,
+  has_side_outputs: &mut bool,
 ) -> Result<String> {
   plugin_driver
     .transform(
       &HookTransformArgs { id: &resolved_id.id, code: &source, module_type: &ModuleType::Empty },
       sourcemap_chain,
       side_effects,
+      meta,
+      synthetic_named_exports,
       &source,
       module_type,
+      has_side_outputs,
     )
     .await
 }
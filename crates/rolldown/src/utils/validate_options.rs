@@ -0,0 +1,133 @@
+use rolldown_common::{BundlerOptions, OutputFormat};
+use rolldown_error::{BuildDiagnostic, InvalidOptionTypes};
+use serde_json::Value;
+
+/// Deserialize and check the options from the content of a config file. See [deserialize_bundler_options] and
+/// [check_bundler_options].
+pub fn validate_bundler_options(value: Value) -> Result<BundlerOptions, Vec<BuildDiagnostic>> {
+  let options = deserialize_bundler_options(value).map_err(|err| vec![err])?;
+  let errors = check_bundler_options(&options);
+  if errors.is_empty() {
+    Ok(options)
+  } else {
+    Err(errors)
+  }
+}
+
+/// Deserialize the options from the content of a config file, reporting the failure with the JSON pointer to the
+/// invalid value. The top-level `$schema` field is ignored, so config files could reference
+/// [BundlerOptions::json_schema].
+pub fn deserialize_bundler_options(mut value: Value) -> Result<BundlerOptions, BuildDiagnostic> {
+  if let Value::Object(map) = &mut value {
+    map.remove("$schema");
+  }
+  serde_path_to_error::deserialize(value).map_err(|err| {
+    BuildDiagnostic::invalid_option(
+      InvalidOptionTypes::InvalidValue(err.inner().to_string()),
+      to_json_pointer(err.path()),
+    )
+  })
+}
+
+/// Check the options that are valid separately but conflict with each other.
+pub fn check_bundler_options(options: &BundlerOptions) -> Vec<BuildDiagnostic> {
+  let mut errors = vec![];
+  let has_multiple_inputs = options.input.as_ref().is_some_and(|input| input.len() > 1);
+  let inline_dynamic_imports = options.inline_dynamic_imports.unwrap_or(false);
+
+  if has_multiple_inputs {
+    if let Some(format @ (OutputFormat::Iife | OutputFormat::Umd)) = &options.format {
+      errors.push(BuildDiagnostic::invalid_option(
+        InvalidOptionTypes::UnsupportedMultipleInputsFormat(format.to_string()),
+        "/format".to_string(),
+      ));
+    }
+    if inline_dynamic_imports {
+      errors.push(BuildDiagnostic::invalid_option(
+        InvalidOptionTypes::UnsupportedMultipleInputsWithInlineDynamicImports,
+        "/inlineDynamicImports".to_string(),
+      ));
+    }
+  }
+  if inline_dynamic_imports && options.advanced_chunks.is_some() {
+    errors.push(BuildDiagnostic::invalid_option(
+      InvalidOptionTypes::UnsupportedWithInlineDynamicImports,
+      "/advancedChunks".to_string(),
+    ));
+  }
+
+  errors
+}
+
+/// Convert the path to a JSON pointer defined in RFC 6901, e.g. `/input/0/import`.
+fn to_json_pointer(path: &serde_path_to_error::Path) -> String {
+  path
+    .iter()
+    .filter_map(|segment| match segment {
+      serde_path_to_error::Segment::Seq { index } => Some(index.to_string()),
+      serde_path_to_error::Segment::Map { key } => Some(key.replace('~', "~0").replace('/', "~1")),
+      serde_path_to_error::Segment::Enum { variant } => Some(variant.clone()),
+      serde_path_to_error::Segment::Unknown => None,
+    })
+    .fold(String::new(), |pointer, token| pointer + "/" + &token)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rolldown_error::DiagnosticOptions;
+  use serde_json::json;
+
+  fn messages(errors: Vec<BuildDiagnostic>) -> Vec<String> {
+    errors
+      .into_iter()
+      .map(|err| err.into_diagnostic_with(&DiagnosticOptions::default()).to_string())
+      .collect()
+  }
+
+  #[test]
+  fn test_invalid_value() {
+    let err = validate_bundler_options(json!({
+      "$schema": "./bundler_options.schema.json",
+      "input": [{ "import": "./main.js" }, { "import": 1 }]
+    }))
+    .unwrap_err();
+    assert_eq!(err.len(), 1);
+    let message = &messages(err)[0];
+    assert!(message.contains("Invalid value for option \"/input/1/import\""), "{message}");
+
+    let err = validate_bundler_options(json!({ "define": { "a/b~c": 1 } })).unwrap_err();
+    let message = &messages(err)[0];
+    assert!(message.contains("Invalid value for option \"/define/a~1b~0c\""), "{message}");
+
+    let err = validate_bundler_options(json!({ "output": {} })).unwrap_err();
+    let message = &messages(err)[0];
+    assert!(
+      message.contains("Invalid value for option \"/output\": unknown field `output`"),
+      "{message}"
+    );
+  }
+
+  #[test]
+  fn test_conflicting_options() {
+    let err = validate_bundler_options(json!({
+      "input": [{ "import": "./a.js" }, { "import": "./b.js" }],
+      "format": "iife",
+      "inlineDynamicImports": true,
+      "advancedChunks": { "groups": [] }
+    }))
+    .unwrap_err();
+    let messages = messages(err);
+    assert_eq!(messages.len(), 3);
+    assert!(messages[0].contains("Invalid value \"iife\" for option \"/format\""));
+    assert!(messages[1].contains("option \"/inlineDynamicImports\""));
+    assert!(messages[2].contains("option \"/advancedChunks\""));
+
+    assert!(validate_bundler_options(json!({
+      "input": [{ "import": "./a.js" }],
+      "format": "iife",
+      "inlineDynamicImports": true
+    }))
+    .is_ok());
+  }
+}
@@ -0,0 +1,141 @@
+//! Watch mode of the [Bundler].
+//!
+//! The watcher builds the bundle once, and then polls the files of the modules visited in the last build.
+//! Once some of them are changed, it waits until no more changes happen in `watch.buildDelay` milliseconds,
+//! calls the `watch_change` hook for each changed file and rebuilds. Only the changed modules are loaded,
+//! transformed and parsed again in the rebuild, the others are reused from the [ScanStageCache].
+
+mod watched_files;
+
+use std::time::{Duration, Instant};
+
+use rolldown_common::WatcherChangeKind;
+use tokio::sync::{mpsc, oneshot};
+
+use self::watched_files::WatchedFiles;
+use crate::{types::scan_stage_cache::ScanStageCache, BundleOutput, Bundler};
+
+pub enum WatcherEvent {
+  /// A build is started, either the initial build or a rebuild triggered by changed files.
+  BundleStart,
+  /// A build is finished. Errors that could be diagnosed are reported in [BundleOutput::errors].
+  BundleEnd { output: BundleOutput, duration: Duration },
+  /// A build is aborted by an error that rolldown can't handle. The watcher keeps watching, so the build
+  /// will be retried once the files are changed.
+  Error(anyhow::Error),
+}
+
+pub struct Watcher {
+  rx: mpsc::UnboundedReceiver<WatcherEvent>,
+  close_tx: oneshot::Sender<()>,
+  handle: tokio::task::JoinHandle<anyhow::Result<()>>,
+}
+
+impl Watcher {
+  pub(crate) fn new(bundler: Bundler) -> Self {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let (close_tx, close_rx) = oneshot::channel();
+    let handle = tokio::spawn(run(bundler, tx, close_rx));
+    Self { rx, close_tx, handle }
+  }
+
+  /// Receive the next event of the watcher. Returns `None` if the watcher is stopped.
+  pub async fn recv(&mut self) -> Option<WatcherEvent> {
+    self.rx.recv().await
+  }
+
+  /// Stop watching and close the bundler. If a build is in progress, it will be finished first.
+  pub async fn close(self) -> anyhow::Result<()> {
+    // The watcher may already be stopped due to errors, which will be returned by the handle.
+    let _ = self.close_tx.send(());
+    self.handle.await?
+  }
+}
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+async fn run(
+  mut bundler: Bundler,
+  tx: mpsc::UnboundedSender<WatcherEvent>,
+  mut close_rx: oneshot::Receiver<()>,
+) -> anyhow::Result<()> {
+  bundler.cache = Some(ScanStageCache::default());
+
+  let build_delay = Duration::from_millis(bundler.options.watch.build_delay.unwrap_or(0).into());
+  let skip_write = bundler.options.watch.skip_write.unwrap_or(false);
+  let mut watched_files = WatchedFiles::new(bundler.fs.clone());
+
+  loop {
+    // Events are dropped silently if the receiver is dropped, the watcher keeps working until it's closed.
+    let _ = tx.send(WatcherEvent::BundleStart);
+    let start = Instant::now();
+    let ret = if skip_write { bundler.generate().await } else { bundler.write().await };
+
+    // Files are watched before the build is reported, otherwise changes made right after receiving the
+    // `BundleEnd` event could be taken as the initial states of newly watched files and missed.
+    if let Some(cache) = &bundler.cache {
+      watched_files.update(&cache.watch_files);
+    }
+
+    let _ = tx.send(match ret {
+      Ok(output) => WatcherEvent::BundleEnd { output, duration: start.elapsed() },
+      Err(err) => WatcherEvent::Error(err),
+    });
+
+    let changes = tokio::select! {
+      changes = watched_files.wait_for_changes(POLL_INTERVAL, build_delay) => changes,
+      // Dropping the `Watcher` without calling `close` stops watching as well.
+      _ = &mut close_rx => break,
+    };
+
+    for (path, kind) in changes {
+      // Errors of plugins don't stop watching, they're reported like the errors of builds.
+      if let Err(err) = bundler.plugin_driver.watch_change(&path, kind).await {
+        let _ = tx.send(WatcherEvent::Error(err));
+      }
+      if let Some(cache) = &mut bundler.cache {
+        cache.invalidate(&path);
+        if kind == WatcherChangeKind::Delete {
+          cache.invalidate_importers(&path);
+        }
+      }
+    }
+    // The resolver caches the states of files, which are stale now.
+    bundler.resolver.clear_cache();
+  }
+
+  // The bundler is closed even if the `closeWatcher` hook fails.
+  let closed_watcher = bundler.plugin_driver.close_watcher().await;
+  bundler.close().await?;
+  closed_watcher
+}
+
+/// Merge the change of a file into the previous change of it, which is not handled yet.
+///
+/// Returns `None` if the changes cancel each other out, e.g. a file is created and deleted before rebuilding.
+fn merge_change_kind(
+  previous: WatcherChangeKind,
+  current: WatcherChangeKind,
+) -> Option<WatcherChangeKind> {
+  match (previous, current) {
+    (WatcherChangeKind::Create, WatcherChangeKind::Delete) => None,
+    (WatcherChangeKind::Create, _) => Some(WatcherChangeKind::Create),
+    (WatcherChangeKind::Delete, WatcherChangeKind::Create) => Some(WatcherChangeKind::Update),
+    (_, current) => Some(current),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_merge_change_kind() {
+    use WatcherChangeKind::{Create, Delete, Update};
+    assert_eq!(merge_change_kind(Create, Update), Some(Create));
+    assert_eq!(merge_change_kind(Create, Delete), None);
+    assert_eq!(merge_change_kind(Delete, Create), Some(Update));
+    assert_eq!(merge_change_kind(Update, Delete), Some(Delete));
+    assert_eq!(merge_change_kind(Update, Update), Some(Update));
+  }
+}
@@ -0,0 +1,102 @@
+use std::{
+  path::Path,
+  time::{Duration, SystemTime},
+};
+
+use arcstr::ArcStr;
+use rolldown_common::WatcherChangeKind;
+use rolldown_fs::{FileSystem, SharedFileSystem};
+use rolldown_utils::indexmap::FxIndexMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::merge_change_kind;
+
+/// The modified time and the size of a file. `None` means the file doesn't exist.
+type FileState = Option<(SystemTime, u64)>;
+
+pub struct WatchedFiles {
+  /// Files are polled through the file system of the bundler, so they could be watched on any [FileSystem].
+  fs: SharedFileSystem,
+  states: FxHashMap<ArcStr, FileState>,
+}
+
+impl WatchedFiles {
+  pub fn new(fs: SharedFileSystem) -> Self {
+    Self { fs, states: FxHashMap::default() }
+  }
+
+  /// Watch the given files. The states of files that are already watched are kept, so changes happened
+  /// during the build will still be detected.
+  pub fn update(&mut self, files: &FxHashSet<ArcStr>) {
+    self.states.retain(|path, _| files.contains(path));
+    for path in files {
+      self.states.entry(path.clone()).or_insert_with(|| read_file_state(&self.fs, path));
+    }
+  }
+
+  /// Poll the watched files until some of them are changed, and no more changes happen in `build_delay`.
+  pub async fn wait_for_changes(
+    &mut self,
+    poll_interval: Duration,
+    build_delay: Duration,
+  ) -> Vec<(ArcStr, WatcherChangeKind)> {
+    let mut pending: FxIndexMap<ArcStr, WatcherChangeKind> = FxIndexMap::default();
+    let mut has_changes = false;
+    loop {
+      tokio::time::sleep(poll_interval).await;
+      let changes = self.poll();
+      if changes.is_empty() {
+        if has_changes {
+          return pending.into_iter().collect();
+        }
+        continue;
+      }
+      has_changes = true;
+      for (path, kind) in changes {
+        match pending.get(&path).copied() {
+          Some(previous) => match merge_change_kind(previous, kind) {
+            Some(kind) => {
+              pending.insert(path, kind);
+            }
+            None => {
+              pending.shift_remove(&path);
+            }
+          },
+          None => {
+            pending.insert(path, kind);
+          }
+        }
+      }
+      if !build_delay.is_zero() {
+        tokio::time::sleep(build_delay).await;
+      }
+    }
+  }
+
+  fn poll(&mut self) -> Vec<(ArcStr, WatcherChangeKind)> {
+    let fs = &self.fs;
+    let mut changes = self
+      .states
+      .iter_mut()
+      .filter_map(|(path, state)| {
+        let current = read_file_state(fs, path);
+        if current == *state {
+          return None;
+        }
+        let kind = match (state.is_some(), current.is_some()) {
+          (false, true) => WatcherChangeKind::Create,
+          (true, false) => WatcherChangeKind::Delete,
+          _ => WatcherChangeKind::Update,
+        };
+        *state = current;
+        Some((path.clone(), kind))
+      })
+      .collect::<Vec<_>>();
+    changes.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    changes
+  }
+}
+
+fn read_file_state(fs: &SharedFileSystem, path: &str) -> FileState {
+  fs.modified_and_len(Path::new(path)).ok()
+}
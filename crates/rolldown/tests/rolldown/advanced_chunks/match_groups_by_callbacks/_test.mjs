@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import { values } from './dist/main.mjs'
+
+assert.deepEqual(values, ['vendor a', 'vendor b', 'local vendor b'])
+// Only `vendor/b.js` is imported by `local.js`, so `vendor/a.js` stays in the entry chunk.
+const vendor = fs.readFileSync(new URL('./dist/vendor.mjs', import.meta.url), 'utf8')
+assert(vendor.includes('vendor b'))
+assert(!vendor.includes('vendor a'))
@@ -0,0 +1,3 @@
+import { b } from './vendor/b.js'
+
+export const local = `local ${b}`
@@ -0,0 +1,5 @@
+import { a } from './vendor/a.js'
+import { b } from './vendor/b.js'
+import { local } from './local.js'
+
+export const values = [a, b, local]
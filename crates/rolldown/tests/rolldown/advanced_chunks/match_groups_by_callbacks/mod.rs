@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use rolldown::{AdvancedChunksOptions, BundlerOptions, MatchGroup, MatchGroupName, MatchGroupTest};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_match_groups_by_callbacks() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run(BundlerOptions {
+      cwd: Some(cwd),
+      advanced_chunks: Some(AdvancedChunksOptions {
+        groups: Some(vec![MatchGroup {
+          // Like `manualChunks`, modules in `vendor` are put into a chunk named by their directory.
+          name: MatchGroupName::Fn(Arc::new(|id, _| {
+            let name = id.contains("vendor").then(|| "vendor".to_string());
+            Box::pin(async move { Ok(name) })
+          })),
+          // Only modules imported by `local.js` are captured.
+          test: Some(MatchGroupTest::Fn(Arc::new(|_, module_info| {
+            let is_matched =
+              module_info.importers.iter().any(|importer| importer.ends_with("local.js"));
+            Box::pin(async move { Ok(is_matched) })
+          }))),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+    .await;
+}
@@ -0,0 +1 @@
+mod match_groups_by_callbacks;
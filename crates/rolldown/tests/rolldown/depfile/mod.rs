@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use rolldown::{
+  BundlerBuilder, BundlerOptions, DepfileOptions, FileSystem, InputItem, ResolveOptions,
+};
+use rolldown_fs::MemoryFileSystem;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_write_depfile_with_modules_package_json_and_tsconfig_files() {
+  let mut fs = MemoryFileSystem::default();
+  for (path, content) in [
+    ("/project/src/main.js", "import pkg from 'pkg';\nimport './my file.js';\nconsole.log(pkg);\n"),
+    ("/project/src/my file.js", "console.log('space');\n"),
+    ("/project/node_modules/pkg/package.json", r#"{ "name": "pkg", "main": "./index.js" }"#),
+    ("/project/node_modules/pkg/index.js", "export default 'pkg';\n"),
+    (
+      "/project/tsconfig.json",
+      "{\n  // Comments are allowed in tsconfig files.\n  \"extends\": \"./tsconfig.base\"\n}\n",
+    ),
+    ("/project/tsconfig.base.json", "{ \"compilerOptions\": {} }\n"),
+  ] {
+    fs.add_file(Path::new(path), content);
+  }
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./src/main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      resolve: Some(ResolveOptions {
+        tsconfig_filename: Some("tsconfig.json".to_string()),
+        ..Default::default()
+      }),
+      depfile: Some(DepfileOptions { filename: None, json: Some(true) }),
+      ..Default::default()
+    })
+    .with_file_system(fs.clone())
+    .build();
+
+  let output = bundler.write().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let depfile = String::from_utf8(fs.read(Path::new("/project/dist/deps.d")).unwrap()).unwrap();
+  assert_eq!(
+    depfile,
+    "dist/main.js: \\
+  node_modules/pkg/index.js \\
+  node_modules/pkg/package.json \\
+  src/main.js \\
+  src/my\\ file.js \\
+  tsconfig.base.json \\
+  tsconfig.json
+"
+  );
+
+  let json = String::from_utf8(fs.read(Path::new("/project/dist/deps.d.json")).unwrap()).unwrap();
+  assert_eq!(
+    serde_json::from_str::<Vec<String>>(&json).unwrap(),
+    vec![
+      "node_modules/pkg/index.js",
+      "node_modules/pkg/package.json",
+      "src/main.js",
+      "src/my file.js",
+      "tsconfig.base.json",
+      "tsconfig.json",
+    ]
+  );
+}
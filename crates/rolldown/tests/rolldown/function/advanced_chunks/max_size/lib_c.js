@@ -0,0 +1 @@
+console.log('lib_c')
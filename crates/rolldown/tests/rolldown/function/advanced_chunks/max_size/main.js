@@ -0,0 +1,5 @@
+import './lib_a.js'
+import './lib_b.js'
+import './lib_c.js'
+
+console.log('main')
@@ -0,0 +1 @@
+console.log('large: 0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000')
@@ -0,0 +1,2 @@
+import './large.js'
+import './small.js'
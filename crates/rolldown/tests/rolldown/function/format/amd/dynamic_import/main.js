@@ -0,0 +1,2 @@
+export const foo = import('./foo.js')
+export const path = import('node:path')
@@ -0,0 +1,5 @@
+const module = 'module'
+const require = 'require'
+const define = 'define'
+
+export { module, require, define }
@@ -0,0 +1,4 @@
+import ext, { named } from 'ext'
+import * as ns from 'ns'
+
+console.log(ext, named, ns)
@@ -0,0 +1 @@
+export * as default from './lib.js'
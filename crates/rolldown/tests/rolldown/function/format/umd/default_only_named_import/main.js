@@ -0,0 +1,3 @@
+import ext, { named } from 'ext'
+
+console.log(ext, named)
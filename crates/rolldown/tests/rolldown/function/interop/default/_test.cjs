@@ -0,0 +1,8 @@
+const assert = require('node:assert')
+const path = require('node:path')
+const { pathDefault, join, cjs } = require('./dist/main.cjs')
+
+assert.equal(pathDefault, path)
+assert.equal(join, path.join)
+// `module.exports` is the `default` export even if it's marked with `__esModule`.
+assert.deepEqual(cjs, { __esModule: true, default: 'default', value: 1 })
@@ -0,0 +1,6 @@
+const assert = require('node:assert')
+const path = require('node:path')
+const { pathDefault, nsDefault } = require('./dist/main.cjs')
+
+assert.equal(pathDefault, path)
+assert.equal(nsDefault, path)
@@ -0,0 +1,5 @@
+import path from 'node:path'
+import * as ns from 'node:path'
+
+export const pathDefault = path
+export const nsDefault = ns.default
@@ -0,0 +1,7 @@
+const assert = require('node:assert')
+const path = require('node:path')
+const { pathDefault, join } = require('./dist/main.cjs')
+
+// The `default` import is `module.exports.default`, which `node:path` doesn't have.
+assert.equal(pathDefault, undefined)
+assert.equal(join, path.join)
@@ -0,0 +1,4 @@
+import path, { join } from 'node:path'
+
+export const pathDefault = path
+export { join }
@@ -0,0 +1,5 @@
+export function run() {
+  debugger
+  console.log('removed')
+  return 'kept'
+}
@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+
+;(() => {
+  function foo() {}
+  const bar = () => {}
+  class Baz {}
+  const values = [foo, bar, Baz].map((value) => value.name)
+  assert.deepEqual(values, ['foo', 'bar', 'Baz'])
+})()
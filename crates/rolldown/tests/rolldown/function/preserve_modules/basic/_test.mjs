@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import { value } from './dist/main.mjs'
+import { a } from './dist/utils/a.mjs'
+
+assert.equal(value, 'a')
+assert.equal(a, 'a')
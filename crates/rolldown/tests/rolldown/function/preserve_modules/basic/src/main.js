@@ -0,0 +1,3 @@
+import { a } from './utils/a.js'
+
+export const value = a
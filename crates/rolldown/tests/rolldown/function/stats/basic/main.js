@@ -0,0 +1,5 @@
+import { used } from './shared.js'
+
+console.log(used)
+
+import('./lazy.js')
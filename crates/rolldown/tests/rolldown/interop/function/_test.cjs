@@ -0,0 +1,9 @@
+const assert = require('node:assert')
+const path = require('node:path')
+const { pathDefault, join, cjs } = require('./dist/main.cjs')
+
+// `node:path` is imported with the `esModule` interop returned by the function.
+assert.equal(pathDefault, undefined)
+assert.equal(join, path.join)
+// Bundled CommonJS modules are not affected by the function.
+assert.equal(cjs, 'default')
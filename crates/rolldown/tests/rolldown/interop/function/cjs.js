@@ -0,0 +1 @@
+module.exports = { __esModule: true, default: 'default', value: 1 }
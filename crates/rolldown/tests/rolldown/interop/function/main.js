@@ -0,0 +1,5 @@
+import path, { join } from 'node:path'
+import cjs from './cjs.js'
+
+export const pathDefault = path
+export { join, cjs }
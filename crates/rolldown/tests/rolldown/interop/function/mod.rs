@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InteropOutputOption, InteropType, OutputFormat};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_call_function_for_external_modules() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run(BundlerOptions {
+      cwd: Some(cwd),
+      external: Some(vec!["node:path".to_string()].into()),
+      format: Some(OutputFormat::Cjs),
+      interop: Some(InteropOutputOption::Fn(Arc::new(|id| {
+        let interop = if id == "node:path" { InteropType::EsModule } else { InteropType::Default };
+        Box::pin(async move { Ok(interop) })
+      }))),
+      ..Default::default()
+    })
+    .await;
+}
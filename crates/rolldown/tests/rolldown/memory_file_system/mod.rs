@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use rolldown::{BundlerBuilder, BundlerOptions, FileSystem, InputItem};
+use rolldown_fs::MemoryFileSystem;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_bundle_and_write_in_memory_file_system() {
+  let main_path = "/project/main.js".to_string();
+  let main_content = "import { value } from './foo.js';\nconsole.log(value);\n".to_string();
+  let foo_path = "/project/foo.js".to_string();
+  let foo_content = "export const value = 'from memory';\n".to_string();
+  let fs = MemoryFileSystem::new(&[(&main_path, &main_content), (&foo_path, &foo_content)]);
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs.clone())
+    .build();
+
+  let output = bundler.write().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let written = fs.read(Path::new("/project/dist/main.js")).unwrap();
+  assert!(String::from_utf8(written).unwrap().contains("from memory"));
+}
@@ -1,3 +1,9 @@
+mod advanced_chunks;
+mod depfile;
 mod errors;
+mod interop;
 mod issues;
+mod memory_file_system;
+mod persistent_cache;
 mod plugin;
+mod watch;
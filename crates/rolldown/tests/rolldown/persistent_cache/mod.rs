@@ -0,0 +1,134 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{BundlerBuilder, BundlerOptions, InputItem, PersistentCacheOptions};
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin, TransformPluginContext,
+};
+
+/// Appends a log of `banner` to modules, and warns while transforming `warn.js`. `banner` is the cache key of the
+/// plugin unless it's created by `without_cache_key`.
+#[derive(Debug)]
+struct BannerPlugin {
+  banner: String,
+  has_cache_key: bool,
+  transformed: Mutex<Vec<String>>,
+}
+
+impl BannerPlugin {
+  fn new(banner: &str) -> Arc<Self> {
+    Arc::new(Self {
+      banner: banner.to_string(),
+      has_cache_key: true,
+      transformed: Mutex::default(),
+    })
+  }
+
+  fn without_cache_key(banner: &str) -> Arc<Self> {
+    Arc::new(Self {
+      banner: banner.to_string(),
+      has_cache_key: false,
+      transformed: Mutex::default(),
+    })
+  }
+
+  fn transformed(&self) -> Vec<String> {
+    let mut transformed = self.transformed.lock().unwrap().clone();
+    transformed.sort_unstable();
+    transformed
+  }
+}
+
+impl Plugin for BannerPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "BannerPlugin".into()
+  }
+
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    self.has_cache_key.then(|| self.banner.clone().into())
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let file_name = Path::new(args.id).file_name().unwrap().to_string_lossy().into_owned();
+    if file_name == "warn.js" {
+      ctx.warn("transforming warn.js");
+    }
+    self.transformed.lock().unwrap().push(file_name);
+    Ok(Some(HookTransformOutput {
+      code: Some(format!("{}\nconsole.log({:?})", args.code, self.banner)),
+      ..Default::default()
+    }))
+  }
+}
+
+async fn build(fs: &MemoryFileSystem, plugin: &Arc<BannerPlugin>) -> (String, usize) {
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      persistent_cache: Some(PersistentCacheOptions { dir: None }),
+      ..Default::default()
+    })
+    .with_file_system(fs.clone())
+    .with_plugins(vec![Arc::clone(plugin) as _])
+    .build();
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  (output.assets[0].content_as_bytes().escape_ascii().to_string(), output.warnings.len())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reuse_transformed_modules_until_sources_or_plugins_change() {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(Path::new("/project/main.js"), "import './foo.js'\nimport './warn.js'");
+  fs.add_file(Path::new("/project/foo.js"), "console.log('foo')");
+  fs.add_file(Path::new("/project/warn.js"), "console.log('warn')");
+
+  let plugin = BannerPlugin::new("v1");
+  let (code, warnings) = build(&fs, &plugin).await;
+  assert_eq!(plugin.transformed(), vec!["foo.js", "main.js", "warn.js"]);
+  assert_eq!(warnings, 1);
+
+  // Modules whose transforms report logs are not cached, so the logs are reported again.
+  let plugin = BannerPlugin::new("v1");
+  let (cached_code, warnings) = build(&fs, &plugin).await;
+  assert_eq!(plugin.transformed(), vec!["warn.js"]);
+  assert_eq!(warnings, 1);
+  assert_eq!(cached_code, code);
+
+  fs.add_file(Path::new("/project/foo.js"), "console.log('foo changed')");
+  let plugin = BannerPlugin::new("v1");
+  let (code, _) = build(&fs, &plugin).await;
+  assert_eq!(plugin.transformed(), vec!["foo.js", "warn.js"]);
+  assert!(code.contains("foo changed"));
+
+  // Changing the cache key of a plugin invalidates all entries.
+  let plugin = BannerPlugin::new("v2");
+  let (code, _) = build(&fs, &plugin).await;
+  assert_eq!(plugin.transformed(), vec!["foo.js", "main.js", "warn.js"]);
+  assert!(code.contains("v2"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn disable_cache_for_plugins_without_cache_key() {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(Path::new("/project/main.js"), "import './foo.js'");
+  fs.add_file(Path::new("/project/foo.js"), "console.log('foo')");
+
+  for _ in 0..2 {
+    let plugin = BannerPlugin::without_cache_key("v1");
+    build(&fs, &plugin).await;
+    assert_eq!(plugin.transformed(), vec!["foo.js", "main.js"]);
+  }
+}
@@ -1 +1,4 @@
 mod plugin_context;
+mod resolve_import_meta;
+mod synthetic_named_exports;
+mod worker;
@@ -0,0 +1,110 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{Arc, Mutex},
+};
+
+use arcstr::ArcStr;
+use rolldown::{BundlerBuilder, BundlerOptions, InputItem};
+use rolldown_common::{EmittedChunk, Output};
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{HookLoadArgs, HookLoadReturn, HookNoopReturn, Plugin, PluginContext};
+
+#[derive(Debug, Default)]
+struct EmitChunkPlugin {
+  reference_ids: Mutex<Vec<ArcStr>>,
+  file_names: Mutex<Vec<String>>,
+}
+
+impl Plugin for EmitChunkPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "EmitChunkPlugin".into()
+  }
+
+  async fn build_start(&self, ctx: &PluginContext) -> HookNoopReturn {
+    let reference_id = ctx.emit_chunk(EmittedChunk {
+      name: Some("worker".to_string()),
+      id: "./worker.js".to_string(),
+      ..Default::default()
+    })?;
+    self.reference_ids.lock().unwrap().push(reference_id);
+    Ok(())
+  }
+
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id.ends_with("main.js") {
+      let reference_id = ctx.emit_chunk(EmittedChunk {
+        file_name: Some("sw.js".into()),
+        id: "./sw.js".to_string(),
+        importer: Some(args.id.to_string()),
+        ..Default::default()
+      })?;
+      self.reference_ids.lock().unwrap().push(reference_id);
+    }
+    Ok(None)
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    _bundle: &mut Vec<Output>,
+    _is_write: bool,
+  ) -> HookNoopReturn {
+    for reference_id in self.reference_ids.lock().unwrap().iter() {
+      self.file_names.lock().unwrap().push(ctx.get_file_name(reference_id).to_string());
+    }
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_bundle_emitted_chunks_as_entries() {
+  let files = [
+    ("/project/main.js", "import { shared } from './shared.js';\nconsole.log(shared);\n"),
+    ("/project/shared.js", "export const shared = 'shared';\n"),
+    ("/project/worker.js", "import { shared } from './shared.js';\npostMessage(shared);\n"),
+    ("/project/sw.js", "self.addEventListener('fetch', () => {});\n"),
+  ];
+  let mut fs = MemoryFileSystem::default();
+  for (path, content) in files {
+    fs.add_file(Path::new(path), content);
+  }
+
+  let plugin = Arc::new(EmitChunkPlugin::default());
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::clone(&plugin) as _])
+    .build();
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let entry_filenames = output
+    .assets
+    .iter()
+    .filter_map(|output| match output {
+      Output::Chunk(chunk) if chunk.is_entry => Some(chunk.filename.to_string()),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+  assert!(entry_filenames.contains(&"worker.js".to_string()), "{entry_filenames:?}");
+  assert!(entry_filenames.contains(&"sw.js".to_string()), "{entry_filenames:?}");
+
+  // `shared.js` is imported by both `main.js` and the emitted `worker.js`, so it's split into a common chunk.
+  let common_chunks = output
+    .assets
+    .iter()
+    .filter(|output| matches!(output, Output::Chunk(chunk) if !chunk.is_entry))
+    .count();
+  assert_eq!(common_chunks, 1);
+
+  assert_eq!(*plugin.file_names.lock().unwrap(), vec!["worker.js", "sw.js"]);
+}
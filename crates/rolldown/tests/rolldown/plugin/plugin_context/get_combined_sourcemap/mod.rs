@@ -0,0 +1,87 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+};
+
+use rolldown::{BundlerBuilder, BundlerOptions, InputItem};
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin, TransformPluginContext,
+};
+use rolldown_sourcemap::SourceMap;
+
+/// Prepends a comment line with a sourcemap, after checking the first line of the original code is mapped correctly
+/// by the combined sourcemap of the previous plugins.
+#[derive(Debug)]
+struct PrependLinePlugin {
+  index: usize,
+  checked: Arc<AtomicUsize>,
+}
+
+impl Plugin for PrependLinePlugin {
+  fn name(&self) -> Cow<'static, str> {
+    format!("PrependLinePlugin{}", self.index).into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if !args.id.ends_with("main.js") {
+      return Ok(None);
+    }
+    let combined = ctx.get_combined_sourcemap();
+    let token = combined
+      .get_tokens()
+      .find(|token| token.get_dst_line() as usize == self.index && token.get_dst_col() == 0)
+      .expect("the first line of the original code should be mapped");
+    assert_eq!((token.get_src_line(), token.get_src_col()), (0, 0));
+    assert_eq!(token.get_source_id().and_then(|id| combined.get_source(id)), Some(args.id));
+    self.checked.fetch_add(1, Ordering::SeqCst);
+
+    // Every line of the incoming code is moved down by one line.
+    let mappings = std::iter::once("AAAA").chain(std::iter::repeat("AACA").take(self.index));
+    let map = SourceMap::from_json_string(&format!(
+      r#"{{"version":3,"sources":[{:?}],"names":[],"mappings":";{}"}}"#,
+      args.id,
+      mappings.collect::<Vec<_>>().join(";")
+    ))
+    .expect("sourcemap should be valid");
+    Ok(Some(HookTransformOutput {
+      code: Some(format!("// {}\n{}", self.index, args.code)),
+      map: Some(map),
+      ..Default::default()
+    }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_combine_sourcemaps_of_previous_transforms() {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(Path::new("/project/main.js"), "console.log(1);\n");
+
+  let checked = Arc::new(AtomicUsize::new(0));
+  let plugin = |index| Arc::new(PrependLinePlugin { index, checked: Arc::clone(&checked) });
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![plugin(0), plugin(1), plugin(2), plugin(3)])
+    .build();
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  assert_eq!(checked.load(Ordering::SeqCst), 4);
+}
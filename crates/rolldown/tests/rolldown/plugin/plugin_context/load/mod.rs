@@ -0,0 +1,128 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{BundlerBuilder, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin, TransformPluginContext,
+};
+
+/// Inlines the number of modules imported by `dep.js` into `main.js`, which requires `dep.js` to be parsed
+/// before `main.js` is transformed.
+#[derive(Debug)]
+struct InspectDependencyPlugin;
+
+impl Plugin for InspectDependencyPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "InspectDependencyPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if !args.id.ends_with("main.js") {
+      return Ok(None);
+    }
+    let resolved_id = ctx.resolve("./dep.js", Some(args.id), None).await??;
+    let module_info = ctx.load(resolved_id).await?;
+    assert!(module_info.id.ends_with("dep.js"));
+    Ok(Some(HookTransformOutput {
+      code: Some(args.code.replace("__DEP_IMPORTS__", &module_info.imported_ids.len().to_string())),
+      ..Default::default()
+    }))
+  }
+}
+
+/// Loads the module being transformed, which can't finish until the hook returns.
+#[derive(Debug, Default)]
+struct LoadSelfPlugin {
+  error: Mutex<Option<String>>,
+}
+
+impl Plugin for LoadSelfPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "LoadSelfPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let resolved_id = ctx.resolve(args.id, None, None).await??;
+    let err = ctx.load(resolved_id).await.unwrap_err();
+    *self.error.lock().unwrap() = Some(err.to_string());
+    Ok(None)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_load_modules_inside_transform() {
+  let files = [
+    ("/project/main.js", "import { dep } from './dep.js';\nconsole.log(dep, __DEP_IMPORTS__);\n"),
+    (
+      "/project/dep.js",
+      "import { a } from './a.js';\nimport { b } from './b.js';\nexport const dep = a + b;\n",
+    ),
+    ("/project/a.js", "export const a = 1;\n"),
+    ("/project/b.js", "export const b = 2;\n"),
+  ];
+  let mut fs = MemoryFileSystem::default();
+  for (path, content) in files {
+    fs.add_file(Path::new(path), content);
+  }
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::new(InspectDependencyPlugin)])
+    .build();
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+  let Some(Output::Chunk(main)) = output.assets.first() else { panic!("should have main chunk") };
+  assert!(main.code.contains("console.log(dep, 2)"), "{}", main.code);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_fail_to_load_the_current_module() {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(Path::new("/project/main.js"), "console.log('main');\n");
+
+  let plugin = Arc::new(LoadSelfPlugin::default());
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::clone(&plugin) as _])
+    .build();
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  assert_eq!(
+    plugin.error.lock().unwrap().as_deref(),
+    Some(
+      "Unable to load module \"/project/main.js\" in its own hooks, since it can't be parsed before they finish."
+    )
+  );
+}
@@ -0,0 +1,122 @@
+use std::{borrow::Cow, path::Path, sync::Arc};
+
+use oxc::span::Span;
+use rolldown::{BundleOutput, BundlerBuilder, BundlerOptions, InputItem, LogLevel, OnLog};
+use rolldown_error::{BuildDiagnostic, DiagnosticOptions, StructuredDiagnostic};
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformReturn, Plugin, PluginLog, TransformPluginContext,
+};
+
+/// Warns about `debugger` statements, and fails on modules containing `FAIL`.
+#[derive(Debug)]
+struct LintPlugin;
+
+impl Plugin for LintPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "LintPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    ctx.debug(format!("Linting {}", args.id));
+    if let Some(start) = args.code.find("debugger") {
+      #[allow(clippy::cast_possible_truncation)]
+      let start = start as u32;
+      ctx.warn(PluginLog {
+        message: "Unexpected `debugger` statement".to_string(),
+        span: Some(Span::new(start, start + 8)),
+        ..Default::default()
+      });
+    }
+    if args.code.contains("FAIL") {
+      return Err(ctx.error("Found `FAIL`"));
+    }
+    ctx.info("Lint passed");
+    Ok(None)
+  }
+}
+
+async fn bundle(main: &str, options: BundlerOptions) -> BundleOutput {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(Path::new("/project/main.js"), main);
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..options
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::new(LintPlugin)])
+    .build();
+
+  bundler.generate().await.unwrap()
+}
+
+fn to_structured(log: BuildDiagnostic) -> StructuredDiagnostic {
+  log.into_diagnostic_with(&DiagnosticOptions { cwd: "/project".into() }).to_structured()
+}
+
+fn codes(logs: &[BuildDiagnostic]) -> Vec<String> {
+  logs.iter().map(|log| log.kind().to_string()).collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_collect_logs_with_positions() {
+  let output = bundle("console.log(1);\ndebugger;\n", BundlerOptions::default()).await;
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  // Debug logs are dropped by default.
+  assert_eq!(codes(&output.warnings), ["PLUGIN_WARNING", "PLUGIN_LOG"]);
+
+  let mut warnings = output.warnings.into_iter();
+  let warning = to_structured(warnings.next().unwrap());
+  assert_eq!(warning.message, "[plugin LintPlugin] Unexpected `debugger` statement");
+  assert_eq!(warning.file.as_deref(), Some("main.js"));
+  let range = warning.range.unwrap();
+  assert_eq!((range.start.line, range.start.column), (1, 0));
+  let info = to_structured(warnings.next().unwrap());
+  assert_eq!(info.message, "[plugin LintPlugin] main.js: Lint passed");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_filter_logs_by_log_level_and_on_log() {
+  let output = bundle(
+    "debugger;\n",
+    BundlerOptions { log_level: Some(LogLevel::Debug), ..Default::default() },
+  )
+  .await;
+  assert_eq!(codes(&output.warnings), ["PLUGIN_LOG", "PLUGIN_WARNING", "PLUGIN_LOG"]);
+
+  let output = bundle(
+    "debugger;\n",
+    BundlerOptions {
+      log_level: Some(LogLevel::Debug),
+      on_log: Some(OnLog::new(Box::new(|log| log.level == LogLevel::Debug))),
+      ..Default::default()
+    },
+  )
+  .await;
+  assert_eq!(codes(&output.warnings), ["PLUGIN_LOG"]);
+  let debug = to_structured(output.warnings.into_iter().next().unwrap());
+  assert_eq!(debug.message, "[plugin LintPlugin] main.js: Linting /project/main.js");
+
+  let output =
+    bundle("debugger;\n", BundlerOptions { log_level: Some(LogLevel::Warn), ..Default::default() })
+      .await;
+  assert_eq!(codes(&output.warnings), ["PLUGIN_WARNING"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_report_errors_as_diagnostics() {
+  let output = bundle("console.log(FAIL);\n", BundlerOptions::default()).await;
+  assert_eq!(codes(&output.errors), ["PLUGIN_ERROR"]);
+  let error = to_structured(output.errors.into_iter().next().unwrap());
+  assert_eq!(error.message, "[plugin LintPlugin] main.js: Found `FAIL`");
+}
@@ -1 +1,7 @@
 mod custom_arg_in_resolve;
+mod emit_chunk;
+mod get_combined_sourcemap;
+mod load;
+mod log;
+mod module_info;
+mod watch_files;
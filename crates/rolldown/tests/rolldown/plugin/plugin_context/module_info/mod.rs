@@ -0,0 +1,118 @@
+use std::{borrow::Cow, path::Path, sync::Arc};
+
+use rolldown::{BundlerBuilder, BundlerOptions, InputItem};
+use rolldown_common::{ModuleMeta, ModuleType};
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookNoopReturn, HookResolveIdArgs,
+  HookResolveIdOutput, HookResolveIdReturn, HookTransformArgs, HookTransformOutput,
+  HookTransformReturn, Plugin, PluginContext, TransformPluginContext,
+};
+use serde_json::json;
+
+fn meta(value: serde_json::Value) -> ModuleMeta {
+  let serde_json::Value::Object(map) = value else { unreachable!() };
+  map
+}
+
+#[derive(Debug)]
+struct ModuleInfoPlugin;
+
+impl Plugin for ModuleInfoPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "ModuleInfoPlugin".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    match args.specifier {
+      "ext" => Ok(Some(HookResolveIdOutput {
+        id: "ext".to_string(),
+        external: Some(true),
+        meta: Some(meta(json!({ "test": { "resolved": "ext" } }))),
+        ..Default::default()
+      })),
+      "./dep.js" => Ok(Some(HookResolveIdOutput {
+        id: "/project/dep.js".to_string(),
+        meta: Some(meta(json!({ "test": { "resolved": "dep" } }))),
+        ..Default::default()
+      })),
+      _ => Ok(None),
+    }
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id != "/project/dep.js" {
+      return Ok(None);
+    }
+    Ok(Some(HookLoadOutput {
+      code: "export default 1;\nexport const used = 2, unused = 3;\n".to_string(),
+      meta: Some(meta(json!({ "loaded": true }))),
+      ..Default::default()
+    }))
+  }
+
+  async fn transform(
+    &self,
+    _ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if args.id != "/project/dep.js" {
+      return Ok(None);
+    }
+    // Top-level keys are replaced instead of being merged deeply.
+    Ok(Some(HookTransformOutput {
+      meta: Some(meta(json!({ "test": { "transformed": true } }))),
+      ..Default::default()
+    }))
+  }
+
+  async fn render_start(&self, ctx: &PluginContext) -> HookNoopReturn {
+    let dep = ctx.get_module_info("/project/dep.js").expect("dep.js should be in the module table");
+    assert!(!dep.is_external);
+    assert_eq!(dep.is_included, Some(true));
+    assert_eq!(dep.exports, vec!["default", "unused", "used"]);
+    assert!(dep.has_default_export);
+    assert_eq!(dep.module_type, Some(ModuleType::Js));
+    assert_eq!(dep.meta, meta(json!({ "test": { "transformed": true }, "loaded": true })));
+
+    let ext = ctx.get_module_info("ext").expect("external modules should be in the module table");
+    assert!(ext.is_external);
+    assert_eq!(ext.is_included, None);
+    assert_eq!(ext.importers.len(), 1);
+    assert!(ext.importers[0].ends_with("main.js"));
+    assert_eq!(ext.module_type, None);
+    assert_eq!(ext.meta, meta(json!({ "test": { "resolved": "ext" } })));
+
+    assert!(ctx.get_module_ids().unwrap().iter().any(|id| id == "ext"));
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_provide_module_info() {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(
+    Path::new("/project/main.js"),
+    "import 'ext';\nimport value, { used } from './dep.js';\nconsole.log(value, used);\n",
+  );
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::new(ModuleInfoPlugin)])
+    .build();
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+}
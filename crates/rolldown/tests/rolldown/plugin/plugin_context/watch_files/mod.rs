@@ -0,0 +1,78 @@
+use std::{borrow::Cow, path::Path, sync::Arc};
+
+use arcstr::ArcStr;
+use rolldown::{BundlerBuilder, BundlerOptions, InputItem};
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{
+  HookBuildEndArgs, HookNoopReturn, HookTransformArgs, HookTransformReturn, Plugin, PluginContext,
+  TransformPluginContext,
+};
+
+/// Pretends to read a config file in `build_start` and a data file next to each module in `transform`.
+#[derive(Debug)]
+struct ReadFilesPlugin;
+
+impl Plugin for ReadFilesPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "ReadFilesPlugin".into()
+  }
+
+  async fn build_start(&self, ctx: &PluginContext) -> HookNoopReturn {
+    ctx.add_watch_file("/project/config.json");
+    Ok(())
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    _args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    ctx.add_watch_file("./data.txt");
+    Ok(None)
+  }
+
+  async fn build_end(
+    &self,
+    ctx: &PluginContext,
+    _args: Option<&HookBuildEndArgs>,
+  ) -> HookNoopReturn {
+    assert_eq!(
+      ctx.get_watch_files(),
+      vec![ArcStr::from("/project/config.json"), ArcStr::from("/project/data.txt")]
+    );
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watch_files_include_modules_and_files_added_by_plugins() {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(Path::new("/project/main.js"), "import './foo.js'");
+  fs.add_file(Path::new("/project/foo.js"), "console.log('foo')");
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::new(ReadFilesPlugin)])
+    .build();
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty());
+  let mut watch_files = output.watch_files.iter().map(ArcStr::as_str).collect::<Vec<_>>();
+  watch_files.sort_unstable();
+  assert_eq!(
+    watch_files,
+    vec!["/project/config.json", "/project/data.txt", "/project/foo.js", "/project/main.js"]
+  );
+
+  // Files are collected again in the next build.
+  let output = bundler.generate().await.unwrap();
+  assert_eq!(output.watch_files.len(), 4);
+}
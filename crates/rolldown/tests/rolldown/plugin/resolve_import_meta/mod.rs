@@ -0,0 +1,133 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{BundleOutput, BundlerBuilder, BundlerOptions, InputItem, OutputFormat};
+use rolldown_common::Output;
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{
+  HookResolveImportMetaArgs, HookResolveImportMetaReturn, Plugin, PluginContext,
+};
+
+/// Replaces `import.meta.url` with a fixed url, and `import.meta.broken` with invalid code.
+#[derive(Debug, Default)]
+struct ImportMetaPlugin {
+  calls: Mutex<Vec<String>>,
+}
+
+impl Plugin for ImportMetaPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "ImportMetaPlugin".into()
+  }
+
+  async fn resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveImportMetaArgs<'_>,
+  ) -> HookResolveImportMetaReturn {
+    self
+      .calls
+      .lock()
+      .unwrap()
+      .push(format!("{:?} {} {} {}", args.property, args.chunk_id, args.module_id, args.format));
+    Ok(match args.property {
+      Some("url") => Some(format!("'https://example.com/{}'", args.chunk_id)),
+      Some("broken") => Some("(".to_string()),
+      _ => None,
+    })
+  }
+}
+
+async fn bundle(
+  main: &str,
+  format: OutputFormat,
+  plugin: &Arc<ImportMetaPlugin>,
+) -> anyhow::Result<BundleOutput> {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(Path::new("/project/main.js"), main);
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      format: Some(format),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::clone(plugin) as _])
+    .build();
+
+  bundler.generate().await
+}
+
+fn entry_code(output: &BundleOutput) -> &str {
+  output
+    .assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) if chunk.is_entry => Some(chunk.code.as_str()),
+      _ => None,
+    })
+    .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_replace_import_meta_with_hook_results() {
+  let plugin = Arc::new(ImportMetaPlugin::default());
+  let output = bundle(
+    "console.log(import.meta.url, import.meta.url);\nconsole.log(import.meta.dirname);\n",
+    OutputFormat::Cjs,
+    &plugin,
+  )
+  .await
+  .unwrap();
+  assert!(output.errors.is_empty(), "{:?}", output.errors);
+  assert!(output.warnings.is_empty(), "{:?}", output.warnings);
+
+  let code = entry_code(&output);
+  assert!(
+    code.contains(r#"console.log("https://example.com/main.js", "https://example.com/main.js");"#),
+    "{code}"
+  );
+  assert!(code.contains("console.log(__dirname);"), "{code}");
+
+  // The hook is called once per property of each module.
+  assert_eq!(
+    *plugin.calls.lock().unwrap(),
+    [
+      r#"Some("url") main.js /project/main.js cjs"#,
+      r#"Some("dirname") main.js /project/main.js cjs"#
+    ]
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_replace_import_meta_in_esm() {
+  let plugin = Arc::new(ImportMetaPlugin::default());
+  let output =
+    bundle("console.log(import.meta.url, import.meta.hot);\n", OutputFormat::Esm, &plugin)
+      .await
+      .unwrap();
+  assert!(output.warnings.is_empty(), "{:?}", output.warnings);
+
+  let code = entry_code(&output);
+  assert!(
+    code.contains(r#"console.log("https://example.com/main.js", import.meta.hot);"#),
+    "{code}"
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_fail_on_invalid_hook_results() {
+  let plugin = Arc::new(ImportMetaPlugin::default());
+  let error = bundle("console.log(import.meta.broken);\n", OutputFormat::Cjs, &plugin)
+    .await
+    .map(|_| ())
+    .unwrap_err();
+  assert!(error.to_string().contains("`import.meta.broken` in main.js"), "{error}");
+}
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { values } from './dist/main.mjs'
+
+// `mode` and `port` are read from the `default` export, while `version` is a real export.
+assert.deepEqual(values, ['production', 1, 8080])
@@ -0,0 +1,4 @@
+import { mode, version } from 'virtual:config'
+import * as config from 'virtual:config'
+
+export const values = [mode, version, config.port]
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use rolldown::BundlerOptions;
+use rolldown_common::SyntheticNamedExports;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+use super::VirtualConfigPlugin;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_resolve_missing_exports_from_default_export() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions { cwd: Some(cwd), ..Default::default() },
+      vec![Arc::new(VirtualConfigPlugin {
+        synthetic_named_exports: SyntheticNamedExports::Bool(true),
+      })],
+    )
+    .await;
+}
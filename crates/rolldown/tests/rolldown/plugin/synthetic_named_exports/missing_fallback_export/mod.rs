@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use rolldown::BundlerOptions;
+use rolldown_common::SyntheticNamedExports;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+use super::VirtualConfigPlugin;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_report_missing_fallback_export() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_error: true, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions { cwd: Some(cwd), ..Default::default() },
+      vec![Arc::new(VirtualConfigPlugin {
+        synthetic_named_exports: SyntheticNamedExports::Name("config".to_string()),
+      })],
+    )
+    .await;
+}
@@ -0,0 +1,45 @@
+mod default_export;
+mod missing_fallback_export;
+
+use std::borrow::Cow;
+
+use rolldown_common::SyntheticNamedExports;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, Plugin, PluginContext,
+};
+
+/// Provides `virtual:config`, whose named exports are resolved from the `default` export or the `config` export.
+#[derive(Debug)]
+struct VirtualConfigPlugin {
+  synthetic_named_exports: SyntheticNamedExports,
+}
+
+impl Plugin for VirtualConfigPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "VirtualConfigPlugin".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if args.specifier != "virtual:config" {
+      return Ok(None);
+    }
+    Ok(Some(HookResolveIdOutput { id: "\0virtual:config".to_string(), ..Default::default() }))
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id != "\0virtual:config" {
+      return Ok(None);
+    }
+    Ok(Some(HookLoadOutput {
+      code: "export default { mode: 'production', port: 8080 };\nexport const version = 1;\n"
+        .to_string(),
+      synthetic_named_exports: Some(self.synthetic_named_exports.clone()),
+      ..Default::default()
+    }))
+  }
+}
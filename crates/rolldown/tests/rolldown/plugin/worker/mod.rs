@@ -0,0 +1,154 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{BundlerBuilder, BundlerOptions, InputItem};
+use rolldown_common::Output;
+use rolldown_fs::MemoryFileSystem;
+use rolldown_plugin::{
+  HookBuildEndArgs, HookNoopReturn, HookTransformArgs, HookTransformReturn, Plugin, PluginContext,
+  TransformPluginContext,
+};
+
+/// Records the calls of hooks.
+#[derive(Debug, Default)]
+struct RecordHooksPlugin {
+  calls: Mutex<Vec<String>>,
+}
+
+impl RecordHooksPlugin {
+  fn record(&self, call: String) {
+    self.calls.lock().unwrap().push(call);
+  }
+}
+
+impl Plugin for RecordHooksPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "RecordHooksPlugin".into()
+  }
+
+  async fn build_start(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.record("buildStart".to_string());
+    Ok(())
+  }
+
+  async fn transform(
+    &self,
+    _ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    self.record(format!("transform {}", args.id));
+    Ok(None)
+  }
+
+  async fn build_end(
+    &self,
+    _ctx: &PluginContext,
+    _args: Option<&HookBuildEndArgs>,
+  ) -> HookNoopReturn {
+    self.record("buildEnd".to_string());
+    Ok(())
+  }
+
+  async fn render_start(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.record("renderStart".to_string());
+    Ok(())
+  }
+
+  async fn generate_bundle(
+    &self,
+    _ctx: &PluginContext,
+    bundle: &mut Vec<Output>,
+    _is_write: bool,
+  ) -> HookNoopReturn {
+    let mut file_names =
+      bundle.iter().map(|output| output.filename().to_string()).collect::<Vec<_>>();
+    file_names.sort_unstable();
+    self.record(format!("generateBundle {}", file_names.join(", ")));
+    Ok(())
+  }
+
+  async fn close_bundle(&self, _ctx: &PluginContext) -> HookNoopReturn {
+    self.record("closeBundle".to_string());
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn worker_builds_only_call_hooks_of_modules_and_chunks() {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(
+    Path::new("/project/main.js"),
+    "new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })",
+  );
+  fs.add_file(Path::new("/project/worker.js"), "self.postMessage('ready')");
+  let plugin = Arc::new(RecordHooksPlugin::default());
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::clone(&plugin) as _])
+    .build();
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty());
+  bundler.close().await.unwrap();
+
+  // The worker is bundled while `main.js` is being transformed, and its outputs are seen by `generateBundle` of the
+  // build referencing it.
+  assert_eq!(
+    *plugin.calls.lock().unwrap(),
+    vec![
+      "buildStart",
+      "transform /project/main.js",
+      "transform /project/worker.js",
+      "buildEnd",
+      "renderStart",
+      "generateBundle assets/worker-BEQJWJxr.js, main.js",
+      "closeBundle",
+    ]
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn worker_referenced_by_several_modules_is_bundled_once() {
+  let mut fs = MemoryFileSystem::default();
+  fs.add_file(Path::new("/project/main.js"), "import './a.js';\nimport './b.js';\n");
+  for importer in ["/project/a.js", "/project/b.js"] {
+    fs.add_file(
+      Path::new(importer),
+      "new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })",
+    );
+  }
+  fs.add_file(Path::new("/project/worker.js"), "self.postMessage('ready')");
+  let plugin = Arc::new(RecordHooksPlugin::default());
+
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some("/project".into()),
+      ..Default::default()
+    })
+    .with_file_system(fs)
+    .with_plugins(vec![Arc::clone(&plugin) as _])
+    .build();
+
+  let output = bundler.generate().await.unwrap();
+  assert!(output.errors.is_empty());
+
+  let calls = plugin.calls.lock().unwrap();
+  assert_eq!(calls.iter().filter(|call| *call == "transform /project/worker.js").count(), 1);
+  assert!(calls.contains(&"generateBundle assets/worker-BEQJWJxr.js, main.js".to_string()));
+}
@@ -0,0 +1,3 @@
+import styles, { button } from './button.module.css'
+
+console.log(button, styles['icon-wrapper'])
@@ -0,0 +1 @@
+import './main.css'
\ No newline at end of file
@@ -0,0 +1,10 @@
+import assert from 'node:assert'
+import { meta } from './meta.js'
+
+assert.strictEqual(import.meta.url, `file://${__filename}`)
+assert.strictEqual(import.meta.filename, __filename)
+assert.strictEqual(import.meta.dirname, __dirname)
+assert.deepStrictEqual(import.meta.env, {})
+assert.strictEqual(import.meta.hot, undefined)
+assert.strictEqual(meta.url, import.meta.url)
+assert.strictEqual(meta.filename, __filename)
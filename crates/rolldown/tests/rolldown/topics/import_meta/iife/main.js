@@ -0,0 +1,3 @@
+console.log(import.meta.url, import.meta.env.MODE)
+console.log(import.meta.filename)
+console.log(import.meta)
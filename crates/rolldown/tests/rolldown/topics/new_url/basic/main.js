@@ -0,0 +1,9 @@
+import { workerUrl } from './shared.js'
+
+const logo = new URL('./images/logo.svg', import.meta.url)
+const icon = new URL('images/logo.svg#icon', import.meta.url)
+// Not rewritten
+const remote = new URL('https://example.com/logo.svg', import.meta.url)
+const missing = new URL('./missing.svg', import.meta.url)
+
+export { logo, icon, remote, missing, workerUrl }
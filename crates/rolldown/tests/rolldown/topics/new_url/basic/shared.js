@@ -0,0 +1 @@
+export const workerUrl = new URL('./images/logo.svg', import.meta.url).href
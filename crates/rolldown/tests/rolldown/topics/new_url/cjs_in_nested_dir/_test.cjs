@@ -0,0 +1 @@
+require('./dist/js/main.js')
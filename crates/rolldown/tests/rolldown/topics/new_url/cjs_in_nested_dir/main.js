@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { readFileSync } from 'node:fs'
+
+const logo = new URL('./images/logo.svg', import.meta.url)
+assert.ok(readFileSync(logo, 'utf8').startsWith('<svg'))
@@ -0,0 +1 @@
+export const logo = new URL('./images/logo.svg', import.meta.url)
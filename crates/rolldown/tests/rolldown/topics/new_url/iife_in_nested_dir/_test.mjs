@@ -0,0 +1,22 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import vm from 'node:vm'
+
+const code = fs.readFileSync(new URL('./dist/js/main.js', import.meta.url), 'utf8')
+
+// The page is served from `/app/` and loads the script from `/app/js/main.js`.
+const context = { URL, document: { baseURI: 'https://example.com/app/' } }
+vm.runInNewContext(code, context)
+assert.strictEqual(context.module.logo.href, 'https://example.com/app/assets/logo-1aXBFds3.svg')
+
+// The script is loaded from a CDN, so urls are resolved against its `src` instead of the page.
+const document = {
+  baseURI: 'https://example.com/app/',
+  currentScript: { tagName: 'script', src: 'https://cdn.example.com/static/js/main.js' },
+}
+const cdnContext = { URL, document }
+vm.runInNewContext(code, cdnContext)
+document.currentScript = null
+const expected = 'https://cdn.example.com/static/assets/logo-1aXBFds3.svg'
+assert.strictEqual(cdnContext.module.logo.href, expected)
+assert.strictEqual(cdnContext.module.getLogo().href, expected)
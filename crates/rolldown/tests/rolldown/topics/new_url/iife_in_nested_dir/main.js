@@ -0,0 +1,4 @@
+export const logo = new URL('./images/logo.svg', import.meta.url)
+
+// `document.currentScript` is `null` when callbacks run.
+export const getLogo = () => new URL('./images/logo.svg', import.meta.url)
@@ -0,0 +1 @@
+new Worker(new URL('./b.js', import.meta.url), { type: 'module' })
@@ -0,0 +1 @@
+new Worker(new URL('./a.js', import.meta.url), { type: 'module' })
@@ -0,0 +1,6 @@
+import { greet } from './shared.js'
+
+self.onmessage = async (e) => {
+  const { suffix } = await import('./lazy.js')
+  self.postMessage(greet(e.data) + suffix)
+}
@@ -0,0 +1,2 @@
+// Classic workers have no `document`, but know their own url.
+self.postMessage(import.meta.url)
@@ -0,0 +1,4 @@
+import { greet } from './shared.js'
+
+const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })
+worker.postMessage(greet('main'))
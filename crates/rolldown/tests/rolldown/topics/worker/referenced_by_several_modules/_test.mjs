@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+// The worker is bundled once as a module worker and once as a classic worker.
+const assets = fs.readdirSync(new URL('./dist/assets', import.meta.url))
+assert.equal(assets.length, 2)
@@ -0,0 +1,2 @@
+const worker = new SharedWorker(new URL('./worker.js', import.meta.url), { type: 'module', name: 'shared' })
+worker.port.start()
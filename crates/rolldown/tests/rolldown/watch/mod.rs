@@ -0,0 +1,191 @@
+use std::{
+  borrow::Cow,
+  path::Path,
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use rolldown::{
+  BundleOutput, BundlerBuilder, BundlerOptions, InputItem, WatchOption, Watcher, WatcherEvent,
+};
+use rolldown_common::{AssetSource, EmittedAsset, Output};
+use rolldown_plugin::{HookTransformArgs, HookTransformReturn, Plugin, TransformPluginContext};
+
+/// Records the modules being transformed, and emits an asset while transforming `bar.js`.
+#[derive(Debug, Default)]
+struct RecordTransformPlugin {
+  transformed: Mutex<Vec<String>>,
+}
+
+impl RecordTransformPlugin {
+  fn take_transformed(&self) -> Vec<String> {
+    let mut transformed = std::mem::take(&mut *self.transformed.lock().unwrap());
+    transformed.sort_unstable();
+    transformed
+  }
+}
+
+impl Plugin for RecordTransformPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "RecordTransformPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let file_name = Path::new(args.id).file_name().unwrap().to_string_lossy().into_owned();
+    if file_name == "bar.js" {
+      ctx.emit_file(EmittedAsset {
+        name: Some("bar.txt".to_string()),
+        original_file_name: None,
+        file_name: None,
+        source: AssetSource::String("bar".to_string()),
+      });
+    }
+    self.transformed.lock().unwrap().push(file_name);
+    Ok(None)
+  }
+}
+
+async fn next_output(watcher: &mut Watcher) -> BundleOutput {
+  let event = tokio::time::timeout(Duration::from_secs(10), async {
+    loop {
+      match watcher.recv().await.expect("watcher should keep running") {
+        WatcherEvent::BundleStart => {}
+        WatcherEvent::BundleEnd { output, .. } => return output,
+        WatcherEvent::Error(err) => panic!("{err:#}"),
+      }
+    }
+  });
+  let output = event.await.expect("should rebuild in time");
+  assert!(output.errors.is_empty());
+  output
+}
+
+fn asset_names(output: &BundleOutput) -> Vec<&str> {
+  let mut names = output
+    .assets
+    .iter()
+    .filter(|output| matches!(output, Output::Asset(_)))
+    .map(|output| Path::new(output.filename()).extension().unwrap().to_str().unwrap())
+    .collect::<Vec<_>>();
+  names.sort_unstable();
+  names
+}
+
+fn entry_code(output: &BundleOutput) -> &str {
+  output
+    .assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) if chunk.is_entry => Some(chunk.code.as_str()),
+      _ => None,
+    })
+    .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebuild_only_changed_modules_and_keep_emitted_assets() {
+  let cwd = Path::new(env!("CARGO_TARGET_TMPDIR")).join("watch_rebuild");
+  let _ = std::fs::remove_dir_all(&cwd);
+  std::fs::create_dir_all(&cwd).unwrap();
+  std::fs::write(cwd.join("main.js"), "import './foo.js'\nimport './bar.js'").unwrap();
+  create_file(&cwd.join("foo.js"), "console.log('foo')");
+  std::fs::write(cwd.join("bar.js"), "console.log(new URL('./logo.png', import.meta.url))")
+    .unwrap();
+  std::fs::write(cwd.join("logo.png"), "logo").unwrap();
+
+  let plugin = Arc::new(RecordTransformPlugin::default());
+  let bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd.clone()),
+      watch: Some(WatchOption { build_delay: None, skip_write: Some(true) }),
+      ..Default::default()
+    })
+    .with_plugins(vec![Arc::clone(&plugin) as _])
+    .build();
+  let mut watcher = bundler.watch();
+
+  let output = next_output(&mut watcher).await;
+  assert_eq!(plugin.take_transformed(), vec!["bar.js", "foo.js", "main.js"]);
+  assert!(entry_code(&output).contains("console.log(\"foo\")"));
+  assert_eq!(asset_names(&output), vec!["png", "txt"]);
+
+  // The size of the file is changed as well, in case the modified time isn't precise enough.
+  std::fs::write(cwd.join("foo.js"), "console.log('foo changed')").unwrap();
+  let output = next_output(&mut watcher).await;
+  assert_eq!(plugin.take_transformed(), vec!["foo.js"]);
+  assert!(entry_code(&output).contains("console.log(\"foo changed\")"));
+  // Assets emitted while loading `bar.js` are kept, although it's reused from the cache.
+  assert_eq!(asset_names(&output), vec!["png", "txt"]);
+
+  watcher.close().await.unwrap();
+}
+
+/// Create the file at once, otherwise the watcher may see it empty and rebuild twice.
+fn create_file(path: &Path, content: &str) {
+  let tmp = path.with_extension("tmp");
+  std::fs::write(&tmp, content).unwrap();
+  std::fs::rename(tmp, path).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebuild_importers_when_resolution_changes() {
+  let cwd = Path::new(env!("CARGO_TARGET_TMPDIR")).join("watch_resolution");
+  let _ = std::fs::remove_dir_all(&cwd);
+  std::fs::create_dir_all(cwd.join("foo")).unwrap();
+  std::fs::write(cwd.join("main.js"), "import './foo'\nimport 'pkg'").unwrap();
+  std::fs::write(cwd.join("foo/index.js"), "console.log('foo/index')").unwrap();
+
+  let plugin = Arc::new(RecordTransformPlugin::default());
+  let bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd.clone()),
+      watch: Some(WatchOption { build_delay: None, skip_write: Some(true) }),
+      ..Default::default()
+    })
+    .with_plugins(vec![Arc::clone(&plugin) as _])
+    .build();
+  let mut watcher = bundler.watch();
+
+  let output = next_output(&mut watcher).await;
+  assert_eq!(plugin.take_transformed(), vec!["index.js", "main.js"]);
+  assert!(entry_code(&output).contains("console.log(\"foo/index\")"));
+  // `pkg` can't be resolved, so it's treated as external.
+  assert!(entry_code(&output).contains("import \"pkg\""));
+
+  // `./foo` resolves to `foo.js` before `foo/index.js`.
+  create_file(&cwd.join("foo.js"), "console.log('foo')");
+  let output = next_output(&mut watcher).await;
+  // `foo/index.js` leaves the module graph, and the other modules are still only loaded once.
+  assert_eq!(plugin.take_transformed(), vec!["foo.js", "main.js"]);
+  assert!(entry_code(&output).contains("console.log(\"foo\")"));
+
+  // The package is moved into `node_modules` at once, like package managers do.
+  std::fs::create_dir_all(cwd.join("pkg")).unwrap();
+  std::fs::write(cwd.join("pkg/index.js"), "console.log('pkg')").unwrap();
+  std::fs::create_dir_all(cwd.join("node_modules")).unwrap();
+  std::fs::rename(cwd.join("pkg"), cwd.join("node_modules/pkg")).unwrap();
+  let output = next_output(&mut watcher).await;
+  // The external module `pkg` leaves the module graph as well.
+  assert_eq!(plugin.take_transformed(), vec!["index.js", "main.js"]);
+  assert!(entry_code(&output).contains("console.log(\"pkg\")"));
+
+  // Deleting `foo.js` makes `./foo` resolve to `foo/index.js` again.
+  std::fs::remove_file(cwd.join("foo.js")).unwrap();
+  let output = next_output(&mut watcher).await;
+  assert_eq!(plugin.take_transformed(), vec!["index.js", "main.js"]);
+  assert!(entry_code(&output).contains("console.log(\"foo/index\")"));
+
+  watcher.close().await.unwrap();
+}
@@ -14,7 +14,7 @@ use crate::{
 use napi::{tokio::sync::Mutex, Env};
 use napi_derive::napi;
 use rolldown::Bundler as NativeBundler;
-use rolldown_error::{BuildDiagnostic, DiagnosticOptions};
+use rolldown_error::{BuildDiagnostic, DiagnosticOptions, Severity};
 
 #[napi]
 pub struct Bundler {
@@ -186,9 +186,14 @@ impl Bundler {
 
     if let Some(on_log) = self.on_log.as_ref() {
       for warning in warnings {
+        let level = match warning.severity() {
+          Severity::Info => BindingLogLevel::Info,
+          Severity::Debug => BindingLogLevel::Debug,
+          Severity::Error | Severity::Warning => BindingLogLevel::Warn,
+        };
         on_log
           .call_async((
-            BindingLogLevel::Warn.to_string(),
+            level.to_string(),
             BindingLog {
               code: warning.kind().to_string(),
               message: warning
@@ -11,9 +11,11 @@ use napi::Either;
 use napi_derive::napi;
 use serde::Deserialize;
 use types::binding_advanced_chunks_options::BindingAdvancedChunksOptions;
+pub use types::binding_advanced_chunks_options::{BindingMatchGroupName, BindingMatchGroupTest};
 
 pub type AddonOutputOption = MaybeAsyncJsCallback<RenderedChunk, Option<String>>;
 pub type ChunkFileNamesOutputOption = Either<String, JsCallback<PreRenderedChunk, String>>;
+pub type InteropOutputOption = Either<String, JsCallback<String, Option<String>>>;
 
 #[napi(object, object_to_js = false)]
 #[derive(Deserialize, Derivative)]
@@ -56,7 +58,7 @@ pub struct BindingOutputOptions {
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
   pub footer: Option<AddonOutputOption>,
-  #[napi(ts_type = "'es' | 'cjs' | 'iife'")]
+  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd' | 'amd'")]
   pub format: Option<String>,
   // freeze: boolean;
   // generatedCode: NormalizedGeneratedCodeOptions;
@@ -64,7 +66,12 @@ pub struct BindingOutputOptions {
   // hoistTransitiveImports: boolean;
   // indent: true | string;
   pub inline_dynamic_imports: Option<bool>,
-  // interop: GetInterop;
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "'default' | 'esModule' | 'auto' | 'defaultOnly' | ((id: string) => 'default' | 'esModule' | 'auto' | 'defaultOnly' | undefined | null)"
+  )]
+  pub interop: Option<InteropOutputOption>,
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
@@ -82,8 +89,8 @@ pub struct BindingOutputOptions {
   #[napi(ts_type = "(BindingBuiltinPlugin | BindingPluginOptions | undefined)[]")]
   pub plugins: Vec<BindingPluginOrParallelJsPluginPlaceholder>,
   // preferConst: boolean;
-  // preserveModules: boolean;
-  // preserveModulesRoot: string | undefined;
+  pub preserve_modules: Option<bool>,
+  pub preserve_modules_root: Option<String>,
   // sanitizeFileName: (fileName: string) => string;
   #[napi(ts_type = "'file' | 'inline' | 'hidden'")]
   pub sourcemap: Option<String>,
@@ -103,5 +110,6 @@ pub struct BindingOutputOptions {
 
   // --- Enhanced options
   pub minify: Option<bool>,
+  #[serde(skip_deserializing)]
   pub advanced_chunks: Option<BindingAdvancedChunksOptions>,
 }
@@ -1,25 +1,41 @@
 use derivative::Derivative;
-use serde::Deserialize;
+use napi::Either;
+
+use crate::types::{binding_module_info::BindingModuleInfo, js_callback::MaybeAsyncJsCallback};
+
+pub type BindingMatchGroupName =
+  Either<String, MaybeAsyncJsCallback<(String, BindingModuleInfo), Option<String>>>;
+pub type BindingMatchGroupTest =
+  Either<String, MaybeAsyncJsCallback<(String, BindingModuleInfo), bool>>;
 
 #[napi_derive::napi(object, object_to_js = false)]
-#[derive(Deserialize, Derivative)]
-#[serde(rename_all = "camelCase")]
+#[derive(Derivative)]
 #[derivative(Debug)]
 pub struct BindingAdvancedChunksOptions {
   pub min_size: Option<f64>,
+  pub max_size: Option<f64>,
   pub min_share_count: Option<u32>,
   pub groups: Option<Vec<BindingMatchGroup>>,
 }
 
 #[napi_derive::napi(object, object_to_js = false)]
-#[derive(Deserialize, Derivative)]
-#[serde(rename_all = "camelCase")]
+#[derive(Derivative)]
 #[derivative(Debug)]
 pub struct BindingMatchGroup {
-  pub name: String,
-  pub test: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[napi(
+    ts_type = "string | ((id: string, moduleInfo: BindingModuleInfo) => MaybePromise<VoidNullable<string>>)"
+  )]
+  pub name: BindingMatchGroupName,
+  #[derivative(Debug = "ignore")]
+  #[napi(
+    ts_type = "string | ((id: string, moduleInfo: BindingModuleInfo) => MaybePromise<boolean>)"
+  )]
+  pub test: Option<BindingMatchGroupTest>,
   // pub share_count: Option<u32>,
   pub priority: Option<u32>,
   pub min_size: Option<f64>,
+  pub max_size: Option<f64>,
   pub min_share_count: Option<u32>,
+  pub min_module_size: Option<f64>,
 }
@@ -2,12 +2,17 @@ use std::sync::Arc;
 
 use napi_derive::napi;
 
+use rolldown_common::{ModuleDefFormat, ResolvedId};
 use rolldown_plugin::PluginContext;
 
-use crate::{types::binding_module_info::BindingModuleInfo, utils::napi_error};
+use crate::{
+  types::binding_module_info::BindingModuleInfo,
+  utils::{module_meta::parse_module_meta, napi_error},
+};
 
 use super::types::{
-  binding_emitted_asset::BindingEmittedAsset,
+  binding_emitted_asset::BindingEmittedAsset, binding_emitted_chunk::BindingEmittedChunk,
+  binding_hook_side_effects::BindingHookSideEffects,
   binding_plugin_context_resolve_options::BindingPluginContextResolveOptions,
 };
 
@@ -42,14 +47,53 @@ impl BindingPluginContext {
     }))
   }
 
+  #[napi]
+  pub async fn load(
+    &self,
+    id: String,
+    side_effects: Option<BindingHookSideEffects>,
+    meta: Option<String>,
+  ) -> napi::Result<BindingModuleInfo> {
+    let meta = parse_module_meta(meta)
+      .map_err(|err| napi::Error::from_reason(err.to_string()))?
+      .unwrap_or_default();
+    let module_info = self
+      .inner
+      .load(ResolvedId {
+        module_def_format: ModuleDefFormat::from_path(&id),
+        id: id.into(),
+        ignored: false,
+        is_external: false,
+        package_json: None,
+        side_effects: side_effects.map(Into::into),
+        meta,
+      })
+      .await
+      .map_err(|err| napi::Error::from_reason(err.to_string()))?;
+    Ok(BindingModuleInfo::new(module_info))
+  }
+
   #[napi]
   pub fn emit_file(&self, file: BindingEmittedAsset) -> String {
     self.inner.emit_file(file.into()).to_string()
   }
 
   #[napi]
-  pub fn get_file_name(&self, reference_id: String) -> String {
-    self.inner.get_file_name(reference_id.as_str()).to_string()
+  pub fn emit_chunk(&self, chunk: BindingEmittedChunk) -> napi::Result<String> {
+    self
+      .inner
+      .emit_chunk(chunk.into())
+      .map(|reference_id| reference_id.to_string())
+      .map_err(|err| napi::Error::from_reason(err.to_string()))
+  }
+
+  #[napi]
+  pub fn get_file_name(&self, reference_id: String) -> napi::Result<String> {
+    self
+      .inner
+      .try_get_file_name(reference_id.as_str())
+      .map(|file_name| file_name.to_string())
+      .map_err(napi::Error::from_reason)
   }
 
   #[napi]
@@ -61,6 +105,16 @@ impl BindingPluginContext {
   pub fn get_module_ids(&self) -> Option<Vec<String>> {
     self.inner.get_module_ids()
   }
+
+  #[napi]
+  pub fn add_watch_file(&self, file: String) {
+    self.inner.add_watch_file(&file);
+  }
+
+  #[napi]
+  pub fn get_watch_files(&self) -> Vec<String> {
+    self.inner.get_watch_files().iter().map(ToString::to_string).collect()
+  }
 }
 
 impl From<PluginContext> for BindingPluginContext {
@@ -18,6 +18,7 @@ use super::{
     binding_hook_render_chunk_output::BindingHookRenderChunkOutput,
     binding_hook_resolve_id_extra_args::BindingHookResolveIdExtraArgs,
     binding_hook_resolve_id_output::BindingHookResolveIdOutput,
+    binding_hook_resolve_import_meta_extra_args::BindingHookResolveImportMetaExtraArgs,
     binding_hook_transform_output::BindingHookTransformOutput,
     binding_plugin_transform_extra_args::BindingTransformHookExtraArgs,
   },
@@ -32,6 +33,8 @@ pub type BindingPluginOrParallelJsPluginPlaceholder =
 #[serde(rename_all = "camelCase")]
 pub struct BindingPluginOptions {
   pub name: String,
+  pub version: Option<String>,
+  pub cache_key: Option<String>,
 
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(ctx: BindingPluginContext) => MaybePromise<VoidNullable>")]
@@ -124,6 +127,18 @@ pub struct BindingPluginOptions {
   pub render_start: Option<MaybeAsyncJsCallback<BindingPluginContext, ()>>,
   pub render_start_meta: Option<BindingPluginHookMeta>,
 
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, property: Nullable<string>, options: BindingHookResolveImportMetaExtraArgs) => MaybePromise<VoidNullable<string>>"
+  )]
+  pub resolve_import_meta: Option<
+    MaybeAsyncJsCallback<
+      (BindingPluginContext, Option<String>, BindingHookResolveImportMetaExtraArgs),
+      Option<String>,
+    >,
+  >,
+  pub resolve_import_meta_meta: Option<BindingPluginHookMeta>,
+
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(ctx: BindingPluginContext, error: string) => void")]
   pub render_error: Option<MaybeAsyncJsCallback<(BindingPluginContext, String), ()>>,
@@ -172,7 +187,11 @@ pub struct BindingPluginOptions {
 
 impl Debug for BindingPluginOptions {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    f.debug_struct("BindingPluginOptions").field("name", &self.name).finish_non_exhaustive()
+    f.debug_struct("BindingPluginOptions")
+      .field("name", &self.name)
+      .field("version", &self.version)
+      .field("cache_key", &self.cache_key)
+      .finish_non_exhaustive()
   }
 }
 
@@ -2,11 +2,12 @@ use napi_derive::napi;
 
 use rolldown_plugin::TransformPluginContext;
 
-use super::binding_plugin_context::BindingPluginContext;
+use super::{
+  binding_plugin_context::BindingPluginContext, types::binding_emitted_asset::BindingEmittedAsset,
+};
 
 #[napi]
 pub struct BindingTransformPluginContext {
-  #[allow(dead_code)]
   inner: &'static TransformPluginContext<'static>,
 }
 
@@ -16,14 +17,32 @@ impl BindingTransformPluginContext {
     Self { inner }
   }
 
-  // #[napi]
-  // pub fn get_combined_sourcemap(&self) -> napi::Result<String> {
-  //   let sourcemap = self.inner.get_combined_sourcemap();
-  //   sourcemap.to_json_string().map_err(|e| napi::Error::from_reason(format!("{e:?}")))
-  // }
+  /// Returns the combined sourcemap as a JSON string.
+  #[napi]
+  pub fn get_combined_sourcemap(&self) -> String {
+    self.inner.get_combined_sourcemap().to_json_string()
+  }
+
+  /// Adds a file that only the module being transformed depends on.
+  #[napi]
+  pub fn add_watch_file(&self, file: String) {
+    self.inner.add_watch_file(&file);
+  }
+
+  /// Emits an asset, which is emitted again if the module being transformed is reused from the cache in watch mode.
+  #[napi]
+  pub fn emit_file(&self, file: BindingEmittedAsset) -> String {
+    self.inner.emit_file(file.into()).to_string()
+  }
+
+  /// Marks that the hook produces outputs besides the transformed result, so the result isn't persistently cached.
+  #[napi]
+  pub fn mark_side_output(&self) {
+    self.inner.mark_side_output();
+  }
 
   #[napi]
   pub fn inner(&self) -> BindingPluginContext {
-    self.inner.inner.clone().into()
+    self.inner.unmarked_inner().clone().into()
   }
 }
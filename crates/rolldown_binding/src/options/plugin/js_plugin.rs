@@ -2,6 +2,7 @@ use crate::types::{
   binding_module_info::BindingModuleInfo, binding_outputs::BindingOutputs,
   js_callback::MaybeAsyncJsCallbackExt,
 };
+use rolldown_common::OutputFormat;
 use rolldown_plugin::{
   Plugin, __inner::SharedPluginable, typedmap::TypedMapKey, LoadHookFilter, ResolvedIdHookFilter,
   TransformHookFilter,
@@ -17,6 +18,7 @@ use std::{
 use super::{
   binding_transform_context::BindingTransformPluginContext,
   types::binding_hook_resolve_id_extra_args::BindingHookResolveIdExtraArgs,
+  types::binding_hook_resolve_import_meta_extra_args::BindingHookResolveImportMetaExtraArgs,
   types::binding_plugin_transform_extra_args::BindingTransformHookExtraArgs, BindingPluginOptions,
 };
 
@@ -56,6 +58,11 @@ impl Plugin for JsPlugin {
     Cow::Owned(self.name.clone())
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    let cache_key = self.cache_key.as_deref()?;
+    Some(format!("{}:{cache_key}", self.version.as_deref().unwrap_or_default()).into())
+  }
+
   // --- Build hooks ---
 
   async fn build_start(
@@ -94,7 +101,8 @@ impl Plugin for JsPlugin {
           },
         ))
         .await?
-        .map(Into::into),
+        .map(TryInto::try_into)
+        .transpose()?,
       )
     } else {
       Ok(None)
@@ -118,7 +126,8 @@ impl Plugin for JsPlugin {
           args.importer.map(str::to_string),
         ))
         .await?
-        .map(Into::into),
+        .map(TryInto::try_into)
+        .transpose()?,
       )
     } else {
       Ok(None)
@@ -222,6 +231,36 @@ impl Plugin for JsPlugin {
     self.render_start_meta.as_ref().map(Into::into)
   }
 
+  async fn resolve_import_meta(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookResolveImportMetaArgs<'_>,
+  ) -> rolldown_plugin::HookResolveImportMetaReturn {
+    if let Some(cb) = &self.resolve_import_meta {
+      Ok(
+        cb.await_call((
+          ctx.clone().into(),
+          args.property.map(str::to_string),
+          BindingHookResolveImportMetaExtraArgs {
+            chunk_id: args.chunk_id.to_string(),
+            module_id: args.module_id.to_string(),
+            format: match args.format {
+              OutputFormat::Esm => "es".to_string(),
+              format => format.to_string(),
+            },
+          },
+        ))
+        .await?,
+      )
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn resolve_import_meta_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.resolve_import_meta_meta.as_ref().map(Into::into)
+  }
+
   async fn banner(
     &self,
     ctx: &rolldown_plugin::PluginContext,
@@ -74,6 +74,10 @@ impl Plugin for ParallelJsPlugin {
     self.first_plugin().call_name()
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    self.first_plugin().call_cache_key()
+  }
+
   // --- Build hooks ---
 
   async fn build_start(
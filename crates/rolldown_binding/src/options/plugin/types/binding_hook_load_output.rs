@@ -3,7 +3,10 @@ use rolldown::ModuleType;
 use serde::Deserialize;
 
 use super::binding_hook_side_effects::BindingHookSideEffects;
-use crate::types::binding_sourcemap::BindingSourcemap;
+use crate::{
+  types::binding_sourcemap::BindingSourcemap,
+  utils::{module_meta::parse_module_meta, synthetic_named_exports::parse_synthetic_named_exports},
+};
 
 #[napi_derive::napi(object)]
 #[derive(Deserialize, Default, Derivative)]
@@ -12,8 +15,12 @@ use crate::types::binding_sourcemap::BindingSourcemap;
 pub struct BindingHookLoadOutput {
   pub code: String,
   pub side_effects: Option<BindingHookSideEffects>,
+  /// JSON string of the `meta` object.
+  pub meta: Option<String>,
   pub map: Option<BindingSourcemap>,
   pub module_type: Option<String>,
+  /// JSON string of `syntheticNamedExports`, which is either a boolean or a string.
+  pub synthetic_named_exports: Option<String>,
 }
 
 impl TryFrom<BindingHookLoadOutput> for rolldown_plugin::HookLoadOutput {
@@ -24,7 +31,9 @@ impl TryFrom<BindingHookLoadOutput> for rolldown_plugin::HookLoadOutput {
       code: value.code,
       map: value.map.map(TryInto::try_into).transpose()?,
       side_effects: value.side_effects.map(Into::into),
+      meta: parse_module_meta(value.meta)?,
       module_type: value.module_type.map(|ty| ModuleType::from_str_with_fallback(ty.as_str())),
+      synthetic_named_exports: parse_synthetic_named_exports(value.synthetic_named_exports)?,
     })
   }
 }
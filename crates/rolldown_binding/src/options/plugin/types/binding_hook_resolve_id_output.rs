@@ -2,6 +2,7 @@ use derivative::Derivative;
 use serde::Deserialize;
 
 use super::binding_hook_side_effects::BindingHookSideEffects;
+use crate::utils::module_meta::parse_module_meta;
 
 #[napi_derive::napi(object)]
 #[derive(Deserialize, Default, Derivative)]
@@ -11,14 +12,19 @@ pub struct BindingHookResolveIdOutput {
   pub id: String,
   pub external: Option<bool>,
   pub side_effects: Option<BindingHookSideEffects>,
+  /// JSON string of the `meta` object.
+  pub meta: Option<String>,
 }
 
-impl From<BindingHookResolveIdOutput> for rolldown_plugin::HookResolveIdOutput {
-  fn from(value: BindingHookResolveIdOutput) -> Self {
-    Self {
+impl TryFrom<BindingHookResolveIdOutput> for rolldown_plugin::HookResolveIdOutput {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingHookResolveIdOutput) -> Result<Self, Self::Error> {
+    Ok(Self {
       id: value.id,
       external: value.external,
       side_effects: value.side_effects.map(Into::into),
-    }
+      meta: parse_module_meta(value.meta)?,
+    })
   }
 }
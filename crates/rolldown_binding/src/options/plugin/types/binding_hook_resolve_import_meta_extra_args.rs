@@ -0,0 +1,13 @@
+use derivative::Derivative;
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Default, Derivative)]
+#[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
+pub struct BindingHookResolveImportMetaExtraArgs {
+  pub chunk_id: String,
+  pub module_id: String,
+  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'app'")]
+  pub format: String,
+}
@@ -1,5 +1,6 @@
 use derivative::Derivative;
 use napi_derive::napi;
+use rolldown_common::side_effects::DeterminedSideEffects;
 use serde::Deserialize;
 
 #[derive(Deserialize, Derivative, Debug, PartialEq)]
@@ -19,3 +20,17 @@ impl From<BindingHookSideEffects> for rolldown_common::side_effects::HookSideEff
     }
   }
 }
+
+impl From<DeterminedSideEffects> for BindingHookSideEffects {
+  fn from(value: DeterminedSideEffects) -> Self {
+    match value {
+      DeterminedSideEffects::UserDefined(true) | DeterminedSideEffects::Analyzed(true) => {
+        Self::True
+      }
+      DeterminedSideEffects::UserDefined(false) | DeterminedSideEffects::Analyzed(false) => {
+        Self::False
+      }
+      DeterminedSideEffects::NoTreeshake => Self::NoTreeshake,
+    }
+  }
+}
@@ -1,10 +1,12 @@
 pub mod binding_asset_source;
 pub mod binding_emitted_asset;
+pub mod binding_emitted_chunk;
 pub mod binding_hook_filter;
 pub mod binding_hook_load_output;
 pub mod binding_hook_render_chunk_output;
 pub mod binding_hook_resolve_id_extra_args;
 pub mod binding_hook_resolve_id_output;
+pub mod binding_hook_resolve_import_meta_extra_args;
 pub mod binding_hook_side_effects;
 pub mod binding_hook_transform_output;
 pub mod binding_js_or_regex;
@@ -2,6 +2,11 @@ use std::sync::Arc;
 
 use napi_derive::napi;
 
+use crate::{
+  options::plugin::types::binding_hook_side_effects::BindingHookSideEffects,
+  utils::module_meta::stringify_module_meta,
+};
+
 #[napi]
 pub struct BindingModuleInfo {
   inner: Arc<rolldown_common::ModuleInfo>,
@@ -11,6 +16,11 @@ pub struct BindingModuleInfo {
   pub imported_ids: Vec<String>,
   pub dynamically_imported_ids: Vec<String>,
   pub is_entry: bool,
+  pub is_external: bool,
+  pub is_included: Option<bool>,
+  pub exports: Vec<String>,
+  pub has_default_export: bool,
+  pub module_type: Option<String>,
 }
 
 #[napi]
@@ -27,6 +37,11 @@ impl BindingModuleInfo {
         .map(|id| id.to_string())
         .collect(),
       is_entry: inner.is_entry,
+      is_external: inner.is_external,
+      is_included: inner.is_included,
+      exports: inner.exports.clone(),
+      has_default_export: inner.has_default_export,
+      module_type: inner.module_type.as_ref().map(ToString::to_string),
       inner,
     }
   }
@@ -35,4 +50,15 @@ impl BindingModuleInfo {
   pub fn code(&self) -> Option<String> {
     self.inner.code.as_ref().map(ToString::to_string)
   }
+
+  #[napi(getter)]
+  pub fn module_side_effects(&self) -> BindingHookSideEffects {
+    self.inner.side_effects.into()
+  }
+
+  /// JSON string of the `meta` object.
+  #[napi(getter)]
+  pub fn meta(&self) -> String {
+    stringify_module_meta(&self.inner.meta)
+  }
 }
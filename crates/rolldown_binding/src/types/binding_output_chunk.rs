@@ -98,6 +98,16 @@ impl BindingOutputChunk {
     Ok(())
   }
 
+  #[napi(getter)]
+  pub fn original_length(&self) -> u32 {
+    self.inner.original_length
+  }
+
+  #[napi(getter)]
+  pub fn rendered_length(&self) -> u32 {
+    self.inner.rendered_length
+  }
+
   #[napi(getter)]
   pub fn sourcemap_file_name(&self) -> Option<String> {
     self.inner.sourcemap_filename.clone()
@@ -5,16 +5,32 @@ use napi_derive::napi;
 #[napi(object)]
 pub struct BindingRenderedModule {
   pub code: Option<String>,
+  pub original_length: u32,
+  pub rendered_length: u32,
+  pub rendered_exports: Vec<String>,
+  pub removed_exports: Vec<String>,
 }
 
 impl Debug for BindingRenderedModule {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    f.debug_struct("BindingRenderedModule").field("code", &"...").finish()
+    f.debug_struct("BindingRenderedModule")
+      .field("code", &"...")
+      .field("original_length", &self.original_length)
+      .field("rendered_length", &self.rendered_length)
+      .field("rendered_exports", &self.rendered_exports)
+      .field("removed_exports", &self.removed_exports)
+      .finish()
   }
 }
 
 impl From<rolldown_common::RenderedModule> for BindingRenderedModule {
   fn from(value: rolldown_common::RenderedModule) -> Self {
-    Self { code: value.code }
+    Self {
+      code: value.code,
+      original_length: value.original_length,
+      rendered_length: value.rendered_length,
+      rendered_exports: value.rendered_exports,
+      removed_exports: value.removed_exports,
+    }
   }
 }
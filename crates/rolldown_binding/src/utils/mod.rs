@@ -1,7 +1,9 @@
 use napi::Env;
 use rolldown_tracing::try_init_tracing;
+pub mod module_meta;
 pub mod napi_error;
 pub mod normalize_binding_options;
+pub mod synthetic_named_exports;
 
 pub fn try_init_custom_trace_subscriber(mut napi_env: Env) {
   let maybe_guard = try_init_tracing();
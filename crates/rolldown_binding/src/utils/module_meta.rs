@@ -0,0 +1,16 @@
+//! `meta` of modules crosses the boundary as JSON strings, since it could contain arbitrary values of plugins.
+
+use rolldown_common::ModuleMeta;
+
+pub fn parse_module_meta(meta: Option<String>) -> anyhow::Result<Option<ModuleMeta>> {
+  meta
+    .map(|meta| {
+      serde_json::from_str::<ModuleMeta>(&meta)
+        .map_err(|err| anyhow::anyhow!("`meta` should be a JSON object: {err}"))
+    })
+    .transpose()
+}
+
+pub fn stringify_module_meta(meta: &ModuleMeta) -> String {
+  serde_json::to_string(meta).expect("ModuleMeta should be serializable")
+}
@@ -1,4 +1,5 @@
-use crate::options::ChunkFileNamesOutputOption;
+use crate::options::{BindingMatchGroupName, BindingMatchGroupTest, ChunkFileNamesOutputOption};
+use crate::types::binding_module_info::BindingModuleInfo;
 use crate::{
   options::binding_inject_import::normalize_binding_inject_import,
   types::js_callback::JsCallbackExt,
@@ -11,7 +12,8 @@ use crate::{
 use napi::bindgen_prelude::Either;
 use rolldown::{
   AddonOutputOption, AdvancedChunksOptions, BundlerOptions, ChunkFilenamesOutputOption,
-  ExperimentalOptions, IsExternal, MatchGroup, ModuleType, OutputExports, OutputFormat, Platform,
+  ExperimentalOptions, InteropOutputOption, InteropType, IsExternal, LogLevel, MatchGroup,
+  MatchGroupName, MatchGroupTest, ModuleType, OutputExports, OutputFormat, Platform,
 };
 use rolldown_plugin::__inner::SharedPluginable;
 use rolldown_utils::indexmap::FxIndexMap;
@@ -34,7 +36,7 @@ fn normalize_addon_option(
   addon_option: Option<crate::options::AddonOutputOption>,
 ) -> Option<AddonOutputOption> {
   addon_option.map(move |value| {
-    AddonOutputOption::Fn(Box::new(move |chunk| {
+    AddonOutputOption::Fn(Arc::new(move |chunk| {
       let fn_js = value.clone();
       let chunk = chunk.clone();
       Box::pin(async move {
@@ -50,7 +52,7 @@ fn normalize_chunk_file_names_option(
   option
     .map(move |value| match value {
       Either::A(str) => Ok(ChunkFilenamesOutputOption::String(str)),
-      Either::B(func) => Ok(ChunkFilenamesOutputOption::Fn(Box::new(move |chunk| {
+      Either::B(func) => Ok(ChunkFilenamesOutputOption::Fn(Arc::new(move |chunk| {
         let func = func.clone();
         let chunk = chunk.clone();
         Box::pin(async move { func.invoke_async(chunk.into()).await.map_err(anyhow::Error::from) })
@@ -59,6 +61,62 @@ fn normalize_chunk_file_names_option(
     .transpose()
 }
 
+fn normalize_interop_option(
+  option: Option<crate::options::InteropOutputOption>,
+) -> napi::Result<Option<InteropOutputOption>> {
+  option
+    .map(move |value| match value {
+      Either::A(str) => InteropType::try_from(str.as_str())
+        .map(InteropOutputOption::Type)
+        .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err)),
+      Either::B(func) => Ok(InteropOutputOption::Fn(Arc::new(move |id| {
+        let func = func.clone();
+        let id = id.to_string();
+        Box::pin(async move {
+          let interop = func.invoke_async(id).await.map_err(anyhow::Error::from)?;
+          interop.map_or(Ok(InteropType::default()), |interop| {
+            InteropType::try_from(interop.as_str()).map_err(anyhow::Error::msg)
+          })
+        })
+      }))),
+    })
+    .transpose()
+}
+
+fn normalize_match_group_name(name: BindingMatchGroupName) -> MatchGroupName {
+  match name {
+    Either::A(name) => MatchGroupName::Static(name),
+    Either::B(func) => MatchGroupName::Fn(Arc::new(move |id, module_info| {
+      let func = func.clone();
+      let id = id.to_string();
+      Box::pin(async move {
+        func
+          .await_call((id, BindingModuleInfo::new(module_info)))
+          .await
+          .map_err(anyhow::Error::from)
+      })
+    })),
+  }
+}
+
+fn normalize_match_group_test(test: BindingMatchGroupTest) -> MatchGroupTest {
+  match test {
+    Either::A(regex) => {
+      MatchGroupTest::Regex(HybridRegex::new(&regex).expect("Invalid regex pass to test"))
+    }
+    Either::B(func) => MatchGroupTest::Fn(Arc::new(move |id, module_info| {
+      let func = func.clone();
+      let id = id.to_string();
+      Box::pin(async move {
+        func
+          .await_call((id, BindingModuleInfo::new(module_info)))
+          .await
+          .map_err(anyhow::Error::from)
+      })
+    })),
+  }
+}
+
 #[allow(clippy::too_many_lines)]
 pub fn normalize_binding_options(
   input_options: crate::options::BindingInputOptions,
@@ -150,6 +208,7 @@ pub fn normalize_binding_options(
     footer: normalize_addon_option(output_options.footer),
     intro: normalize_addon_option(output_options.intro),
     outro: normalize_addon_option(output_options.outro),
+    interop: normalize_interop_option(output_options.interop)?,
     sourcemap_ignore_list,
     sourcemap_path_transform,
     exports: output_options.exports.map(|format_str| match format_str.as_str() {
@@ -164,6 +223,8 @@ pub fn normalize_binding_options(
       "cjs" => OutputFormat::Cjs,
       "app" => OutputFormat::App,
       "iife" => OutputFormat::Iife,
+      "umd" => OutputFormat::Umd,
+      "amd" => OutputFormat::Amd,
       _ => panic!("Invalid format: {format_str}"),
     }),
     globals: output_options.globals,
@@ -172,7 +233,7 @@ pub fn normalize_binding_options(
       strict_execution_order: inner.strict_execution_order,
       disable_live_bindings: inner.disable_live_bindings,
     }),
-    minify: output_options.minify,
+    minify: output_options.minify.map(Into::into),
     css_entry_filenames: None,
     css_chunk_filenames: None,
     extend: output_options.extend,
@@ -182,26 +243,37 @@ pub fn normalize_binding_options(
       .map(|inner| inner.into_iter().map(normalize_binding_inject_import).collect()),
     external_live_bindings: output_options.external_live_bindings,
     inline_dynamic_imports: output_options.inline_dynamic_imports,
+    preserve_modules: output_options.preserve_modules,
+    preserve_modules_root: output_options.preserve_modules_root,
     advanced_chunks: output_options.advanced_chunks.map(|inner| AdvancedChunksOptions {
       min_size: inner.min_size,
+      max_size: inner.max_size,
       min_share_count: inner.min_share_count,
       groups: inner.groups.map(|inner| {
         inner
           .into_iter()
           .map(|item| MatchGroup {
-            name: item.name,
-            test: item
-              .test
-              .map(|inner| HybridRegex::new(&inner).expect("Invalid regex pass to test")),
+            name: normalize_match_group_name(item.name),
+            test: item.test.map(normalize_match_group_test),
             priority: item.priority,
             min_size: item.min_size,
+            max_size: item.max_size,
             min_share_count: item.min_share_count,
+            min_module_size: item.min_module_size,
           })
           .collect::<Vec<_>>()
       }),
     }),
     checks: None,
+    // Logs are filtered by `logLevel` and `onLog` on the JavaScript side.
+    log_level: Some(LogLevel::Debug),
+    on_log: None,
     profiler_names: input_options.profiler_names,
+    css_modules: None,
+    persistent_cache: None,
+    stats: None,
+    depfile: None,
+    watch: None,
   };
 
   #[cfg(not(target_family = "wasm"))]
@@ -0,0 +1,17 @@
+//! `syntheticNamedExports` is either a boolean or a string, so it crosses the boundary as JSON strings.
+
+use rolldown_common::SyntheticNamedExports;
+
+pub fn parse_synthetic_named_exports(
+  synthetic_named_exports: Option<String>,
+) -> anyhow::Result<Option<SyntheticNamedExports>> {
+  synthetic_named_exports
+    .map(|synthetic_named_exports| match serde_json::from_str(&synthetic_named_exports) {
+      Ok(serde_json::Value::Bool(value)) => Ok(SyntheticNamedExports::Bool(value)),
+      Ok(serde_json::Value::String(name)) => Ok(SyntheticNamedExports::Name(name)),
+      _ => Err(anyhow::anyhow!(
+        "`syntheticNamedExports` should be a boolean or a string, got {synthetic_named_exports}"
+      )),
+    })
+    .transpose()
+}
@@ -0,0 +1,17 @@
+use std::fs;
+use std::path::PathBuf;
+
+use rolldown_common::BundlerOptions;
+use serde_json::to_string_pretty;
+
+fn main() {
+  // The definition of `BundlerOptions` is tracked by cargo through the `rolldown_common` build dependency.
+  println!("cargo:rerun-if-changed=build.rs");
+  let schema = BundlerOptions::json_schema();
+  let schema_path =
+    PathBuf::from(&std::env::var("CARGO_MANIFEST_DIR").expect("Should have CARGO_MANIFEST_DIR"))
+      .join("bundler_options.schema.json");
+
+  fs::write(schema_path, to_string_pretty(&schema).expect("Should be valid JSON"))
+    .expect("Failed to write schema");
+}
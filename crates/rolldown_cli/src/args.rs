@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+/// Options passed by flags take precedence over the ones in the config file.
+#[derive(Debug, Default, Parser)]
+#[command(name = "rolldown", version, about = "Bundle JavaScript with rolldown")]
+pub struct Args {
+  /// Path to a `.json` or `.toml` config file, whose content is deserialized as the bundler options.
+  #[arg(short, long)]
+  pub config: Option<PathBuf>,
+  /// Entry module, could be repeated. Use `name=path` to specify the name of the entry chunk.
+  #[arg(short, long)]
+  pub input: Vec<String>,
+  /// Directory for the output files.
+  #[arg(short, long)]
+  pub dir: Option<String>,
+  /// Format of the output.
+  #[arg(short, long, value_parser = ["esm", "es", "cjs", "iife", "umd", "amd", "app"])]
+  pub format: Option<String>,
+  /// Generate sourcemaps. Defaults to `file` if the flag is given without a value.
+  #[arg(
+    short,
+    long,
+    num_args = 0..=1,
+    default_missing_value = "file",
+    value_parser = ["file", "inline", "hidden"],
+  )]
+  pub sourcemap: Option<String>,
+  /// Minify the output.
+  #[arg(short, long)]
+  pub minify: bool,
+  /// Module id to be treated as external, could be repeated. Replaces `external` of the config file.
+  #[arg(short, long)]
+  pub external: Vec<String>,
+  /// Replace a global identifier with a constant expression, in the form of `key=value`. Could be repeated.
+  #[arg(long, value_name = "KEY=VALUE")]
+  pub define: Vec<String>,
+  /// Rebuild when the files of the module graph are changed.
+  #[arg(short, long)]
+  pub watch: bool,
+}
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use anyhow::Context;
+use rolldown::{BundlerOptions, InputItem, IsExternal, MinifyOptions, OutputFormat, SourceMapType};
+use serde_json::Value;
+
+use crate::args::Args;
+
+/// Load the content of a `.json` or `.toml` file, which is validated as the bundler options later. Comments are
+/// allowed in JSON files.
+pub fn load_config(path: &Path) -> anyhow::Result<Value> {
+  let mut content = std::fs::read_to_string(path)
+    .with_context(|| format!("Failed to read config file {}", path.display()))?;
+  match path.extension().and_then(|ext| ext.to_str()) {
+    Some("json") => {
+      json_strip_comments::strip(&mut content)
+        .with_context(|| format!("Failed to strip comments of {}", path.display()))?;
+      serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse config file {}", path.display()))
+    }
+    Some("toml") => toml::from_str(&content)
+      .with_context(|| format!("Failed to parse config file {}", path.display())),
+    _ => anyhow::bail!(
+      "Unsupported config file {}, only `.json` and `.toml` files are supported",
+      path.display()
+    ),
+  }
+}
+
+/// Override the options with the flags given in the command line.
+pub fn apply_args(options: &mut BundlerOptions, args: &Args) -> anyhow::Result<()> {
+  if !args.input.is_empty() {
+    options.input = Some(args.input.iter().map(|input| parse_input(input)).collect());
+  }
+  if let Some(dir) = &args.dir {
+    options.dir = Some(dir.clone());
+  }
+  if let Some(format) = &args.format {
+    options.format = Some(match format.as_str() {
+      "esm" | "es" => OutputFormat::Esm,
+      "cjs" => OutputFormat::Cjs,
+      "iife" => OutputFormat::Iife,
+      "umd" => OutputFormat::Umd,
+      "amd" => OutputFormat::Amd,
+      "app" => OutputFormat::App,
+      _ => unreachable!("format is validated by clap"),
+    });
+  }
+  if let Some(sourcemap) = &args.sourcemap {
+    options.sourcemap = Some(SourceMapType::from(sourcemap.clone()));
+  }
+  if args.minify {
+    options.minify = Some(MinifyOptions::Boolean(true));
+  }
+  if !args.external.is_empty() {
+    options.external = Some(IsExternal::from_vec(args.external.clone()));
+  }
+  for define in &args.define {
+    let Some((key, value)) = define.split_once('=') else {
+      anyhow::bail!("Invalid define `{define}`, expected the form of `key=value`");
+    };
+    options.define.get_or_insert_with(Default::default).insert(key.to_string(), value.to_string());
+  }
+  Ok(())
+}
+
+fn parse_input(input: &str) -> InputItem {
+  match input.split_once('=') {
+    Some((name, import)) => InputItem { name: Some(name.to_string()), import: import.to_string() },
+    None => InputItem { name: None, import: input.to_string() },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_apply_args() {
+    let mut options: BundlerOptions = toml::from_str(
+      r#"
+      input = [{ import = "./main.js" }]
+      dir = "dist"
+      format = "cjs"
+      define = { "process.env.NODE_ENV" = "'development'" }
+      "#,
+    )
+    .unwrap();
+    let args = Args {
+      input: vec!["app=./app.js".to_string()],
+      format: Some("es".to_string()),
+      minify: true,
+      define: vec!["process.env.NODE_ENV='production'".to_string(), "DEBUG=false".to_string()],
+      ..Default::default()
+    };
+    apply_args(&mut options, &args).unwrap();
+
+    let input = options.input.unwrap();
+    assert_eq!(input.len(), 1);
+    assert_eq!(input[0].name.as_deref(), Some("app"));
+    assert_eq!(input[0].import, "./app.js");
+    assert_eq!(options.dir.as_deref(), Some("dist"));
+    assert!(matches!(options.format, Some(OutputFormat::Esm)));
+    assert!(matches!(options.minify, Some(MinifyOptions::Boolean(true))));
+    let define = options.define.unwrap();
+    assert_eq!(define["process.env.NODE_ENV"], "'production'");
+    assert_eq!(define["DEBUG"], "false");
+  }
+
+  #[test]
+  fn test_invalid_define() {
+    let mut options = BundlerOptions::default();
+    let args = Args { define: vec!["DEBUG".to_string()], ..Default::default() };
+    assert!(apply_args(&mut options, &args).is_err());
+  }
+}
@@ -0,0 +1,109 @@
+mod args;
+mod config;
+
+use std::{io::IsTerminal, path::PathBuf, process::ExitCode, time::Instant};
+
+use clap::Parser;
+use rolldown::{
+  check_bundler_options, deserialize_bundler_options, BundleOutput, Bundler, BundlerOptions,
+  WatcherEvent,
+};
+use rolldown_error::{BuildDiagnostic, DiagnosticOptions};
+
+use crate::args::Args;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let args = Args::parse();
+  match run(args).await {
+    Ok(code) => code,
+    Err(err) => {
+      eprintln!("{err:#}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+async fn run(args: Args) -> anyhow::Result<ExitCode> {
+  let mut reporter =
+    Reporter { cwd: std::env::current_dir()?, color: std::io::stderr().is_terminal() };
+  let mut options = match &args.config {
+    Some(path) => match deserialize_bundler_options(config::load_config(path)?) {
+      Ok(options) => options,
+      Err(err) => {
+        reporter.print(err);
+        return Ok(ExitCode::FAILURE);
+      }
+    },
+    None => BundlerOptions::default(),
+  };
+  config::apply_args(&mut options, &args)?;
+  if options.input.as_ref().map_or(true, Vec::is_empty) {
+    anyhow::bail!("No input is given, please specify it with `--input` or in the config file");
+  }
+  // Conflicts are checked after applying the flags, which may introduce them.
+  let errors = check_bundler_options(&options);
+  if !errors.is_empty() {
+    errors.into_iter().for_each(|err| reporter.print(err));
+    return Ok(ExitCode::FAILURE);
+  }
+
+  if let Some(cwd) = &options.cwd {
+    reporter.cwd = reporter.cwd.join(cwd);
+  }
+
+  let mut bundler = Bundler::new(options);
+  if args.watch {
+    return watch(bundler, &reporter).await;
+  }
+
+  let start = Instant::now();
+  let output = bundler.write().await;
+  bundler.close().await?;
+  if reporter.report(output?) {
+    return Ok(ExitCode::FAILURE);
+  }
+  eprintln!("Finished in {}ms", start.elapsed().as_millis());
+  Ok(ExitCode::SUCCESS)
+}
+
+/// Keep rebuilding until Ctrl-C is pressed. Build errors are reported without stopping the watcher.
+async fn watch(bundler: Bundler, reporter: &Reporter) -> anyhow::Result<ExitCode> {
+  let mut watcher = bundler.watch();
+  loop {
+    tokio::select! {
+      event = watcher.recv() => match event {
+        Some(WatcherEvent::BundleStart) => eprintln!("Bundling..."),
+        Some(WatcherEvent::BundleEnd { output, duration }) => {
+          if !reporter.report(output) {
+            eprintln!("Finished in {}ms, waiting for changes...", duration.as_millis());
+          }
+        }
+        Some(WatcherEvent::Error(err)) => eprintln!("{err:#}"),
+        None => break,
+      },
+      _ = tokio::signal::ctrl_c() => break,
+    }
+  }
+  watcher.close().await?;
+  Ok(ExitCode::SUCCESS)
+}
+
+struct Reporter {
+  cwd: PathBuf,
+  color: bool,
+}
+
+impl Reporter {
+  /// Print the warnings and errors of the build. Returns `true` if there are errors.
+  fn report(&self, output: BundleOutput) -> bool {
+    let has_errors = !output.errors.is_empty();
+    output.warnings.into_iter().chain(output.errors).for_each(|diagnostic| self.print(diagnostic));
+    has_errors
+  }
+
+  fn print(&self, diagnostic: BuildDiagnostic) {
+    let diagnostic = diagnostic.into_diagnostic_with(&DiagnosticOptions { cwd: self.cwd.clone() });
+    eprintln!("{}", diagnostic.convert_to_string(self.color));
+  }
+}
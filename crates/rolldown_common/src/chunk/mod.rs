@@ -22,6 +22,8 @@ pub struct Chunk {
   pub kind: ChunkKind,
   pub modules: Vec<ModuleIdx>,
   pub name: Option<ArcStr>,
+  /// The fixed file name of chunks emitted by plugins, which takes precedence over `entryFileNames`.
+  pub file_name: Option<ArcStr>,
   pub pre_rendered_chunk: Option<RollupPreRenderedChunk>,
   pub preliminary_filename: Option<PreliminaryFilename>,
   pub absolute_preliminary_filename: Option<String>,
@@ -38,6 +40,8 @@ pub struct Chunk {
   pub imports_from_external_modules: Vec<(ModuleIdx, Vec<NamedImport>)>,
   // meaningless if the chunk is an entrypoint
   pub exports_to_other_chunks: FxHashMap<SymbolRef, Rstr>,
+  /// Whether the wrapper of the chunk should capture `document.currentScript`, which lowered `import.meta.url` reads.
+  pub accesses_document_current_script: bool,
 }
 
 impl Chunk {
@@ -63,10 +67,14 @@ impl Chunk {
   }
 
   pub fn import_path_for(&self, importee: &Chunk) -> String {
+    self.relative_path_for(importee.absolute_preliminary_filename.as_ref().unwrap())
+  }
+
+  /// Returns the path of the output file relative to this chunk, which always starts with `./` or `../`.
+  pub fn relative_path_for(&self, absolute_filename: &str) -> String {
     let importer_dir =
       self.absolute_preliminary_filename.as_ref().unwrap().as_path().parent().unwrap();
-    let importee_filename = importee.absolute_preliminary_filename.as_ref().unwrap();
-    let import_path = importee_filename.relative(importer_dir).as_path().expect_to_slash();
+    let import_path = absolute_filename.relative(importer_dir).as_path().expect_to_slash();
 
     if import_path.starts_with('.') {
       import_path
@@ -80,7 +88,9 @@ impl Chunk {
     options: &'a NormalizedBundlerOptions,
     rollup_pre_rendered_chunk: &RollupPreRenderedChunk,
   ) -> anyhow::Result<FilenameTemplate> {
-    let ret = if matches!(self.kind, ChunkKind::EntryPoint { is_user_defined, .. } if is_user_defined)
+    // Every module is treated as an entry in `preserveModules` mode.
+    let ret = if options.preserve_modules
+      || matches!(self.kind, ChunkKind::EntryPoint { is_user_defined, .. } if is_user_defined)
     {
       options.entry_filenames.call(rollup_pre_rendered_chunk).await?
     } else {
@@ -95,7 +105,8 @@ impl Chunk {
     options: &'a NormalizedBundlerOptions,
     rollup_pre_rendered_chunk: &RollupPreRenderedChunk,
   ) -> anyhow::Result<FilenameTemplate> {
-    let ret = if matches!(self.kind, ChunkKind::EntryPoint { is_user_defined, .. } if is_user_defined)
+    let ret = if options.preserve_modules
+      || matches!(self.kind, ChunkKind::EntryPoint { is_user_defined, .. } if is_user_defined)
     {
       options.css_entry_filenames.call(rollup_pre_rendered_chunk).await?
     } else {
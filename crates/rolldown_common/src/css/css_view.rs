@@ -1,10 +1,15 @@
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
+use rustc_hash::FxHashMap;
 
 use crate::{ImportRecord, ImportRecordIdx};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CssView {
   pub source: ArcStr,
   pub import_records: IndexVec<ImportRecordIdx, ImportRecord>,
+  /// Maps specifiers of `url()`s to the file names of the emitted assets, including the `?query` and `#hash`.
+  pub url_assets: FxHashMap<String, String>,
+  /// Whether the file is compiled as a CSS Module, whose identifiers are scoped.
+  pub is_css_module: bool,
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arcstr::ArcStr;
 use bitflags::bitflags;
 use oxc::{index::IndexVec, span::Span};
@@ -9,7 +11,7 @@ use crate::{
   ImportRecordIdx, LocalExport, ModuleDefFormat, ModuleId, NamedImport, StmtInfos, SymbolRef,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EcmaView {
   pub source: ArcStr,
   pub ecma_ast_idx: Option<EcmaAstIdx>,
@@ -25,10 +27,16 @@ pub struct EcmaView {
   /// The key is the `Span` of `ImportDeclaration`, `ImportExpression`, `ExportNamedDeclaration`, `ExportAllDeclaration`
   /// and `CallExpression`(only when the callee is `require`).
   pub imports: FxHashMap<Span, ImportRecordIdx>,
+  /// The key is the `Span` of `new URL('./foo.png', import.meta.url)`, and the value is the file name of the emitted
+  /// asset it refers to.
+  pub new_url_assets: FxHashMap<Span, ArcStr>,
+  /// Spans of `import.meta` and `import.meta.<property>` and the accessed properties, in source order.
+  pub import_meta_references: Vec<(Span, Option<Rstr>)>,
   // [[StarExportEntries]] in https://tc39.es/ecma262/#sec-source-text-module-records
   pub star_exports: Vec<ImportRecordIdx>,
   pub exports_kind: ExportsKind,
-  pub scope: AstScopes,
+  /// Scopes are never mutated after the module is scanned, so they're shared between clones of the module.
+  pub scope: Arc<AstScopes>,
   pub default_export_ref: SymbolRef,
   pub sourcemap_chain: Vec<rolldown_sourcemap::SourceMap>,
   pub is_included: bool,
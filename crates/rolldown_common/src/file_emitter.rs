@@ -6,10 +6,10 @@ use rolldown_utils::sanitize_file_name::sanitize_file_name;
 use rolldown_utils::xxhash::xxhash_base64_url;
 use std::ffi::OsStr;
 use std::path::Path;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EmittedAsset {
   pub name: Option<String>,
   pub original_file_name: Option<String>,
@@ -17,6 +17,17 @@ pub struct EmittedAsset {
   pub source: AssetSource,
 }
 
+/// A chunk emitted by plugins, which is bundled as an additional user-defined entry.
+#[derive(Debug, Default)]
+pub struct EmittedChunk {
+  pub name: Option<String>,
+  /// If provided, the chunk is written to this file name instead of using `entryFileNames`.
+  pub file_name: Option<ArcStr>,
+  pub id: String,
+  /// Used to resolve `id` if provided.
+  pub importer: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct FileEmitter {
   source_hash_to_reference_id: DashMap<ArcStr, ArcStr>,
@@ -26,6 +37,20 @@ pub struct FileEmitter {
   options: Arc<NormalizedBundlerOptions>,
   /// Mark the files that have been emitted to bundle.
   emitted_files: DashSet<ArcStr>,
+  /// Files emitted in the current build. Files of the previous builds are kept, so cached modules could keep using
+  /// their reference ids and file names, but only the files emitted again are added to the bundle.
+  live_files: DashSet<ArcStr>,
+  /// Files emitted while loading each module in the current build and the previous build, which are emitted again
+  /// for the modules reused from the cache in watch mode.
+  module_files: DashMap<ArcStr, Vec<ArcStr>>,
+  previous_module_files: DashMap<ArcStr, Vec<ArcStr>>,
+  /// Emitted chunks in the order they are emitted.
+  chunks: Mutex<Vec<(ArcStr, Arc<EmittedChunk>)>>,
+  /// Ids of the entry modules of emitted chunks.
+  chunk_entry_modules: DashMap<ArcStr, ArcStr>,
+  chunk_file_names: DashMap<ArcStr, ArcStr>,
+  /// Chunks can't be emitted once all modules are loaded.
+  is_chunk_emission_closed: AtomicBool,
 }
 
 impl FileEmitter {
@@ -37,29 +62,137 @@ impl FileEmitter {
       base_reference_id: AtomicUsize::new(0),
       options,
       emitted_files: DashSet::default(),
+      live_files: DashSet::default(),
+      module_files: DashMap::default(),
+      previous_module_files: DashMap::default(),
+      chunks: Mutex::default(),
+      chunk_entry_modules: DashMap::default(),
+      chunk_file_names: DashMap::default(),
+      is_chunk_emission_closed: AtomicBool::new(false),
     }
   }
 
-  pub fn emit_file(&self, mut file: EmittedAsset) -> ArcStr {
+  pub fn emit_file(&self, file: EmittedAsset) -> ArcStr {
+    self.emit_file_for_module(file, None)
+  }
+
+  /// Emits the file while loading the module `module_id`, so it's emitted again if the module is reused from the
+  /// cache in watch mode.
+  pub fn emit_file_for_module(&self, mut file: EmittedAsset, module_id: Option<&str>) -> ArcStr {
     let hash: ArcStr = xxhash_base64_url(file.source.as_bytes()).into();
     // Deduplicate assets if an explicit fileName is not provided
-    if file.file_name.is_none() {
-      if let Some(reference_id) = self.source_hash_to_reference_id.get(&hash) {
-        return reference_id.value().clone();
+    let deduplicated = file
+      .file_name
+      .is_none()
+      .then(|| self.source_hash_to_reference_id.get(&hash).map(|id| id.value().clone()))
+      .flatten();
+    let reference_id = deduplicated.unwrap_or_else(|| {
+      let reference_id = self.assign_reference_id(file.file_name.clone());
+      if file.file_name.is_none() {
+        self.source_hash_to_reference_id.insert(hash.clone(), reference_id.clone());
       }
+      self.generate_file_name(&mut file, &hash);
+      self.files.insert(reference_id.clone(), file);
+      reference_id
+    });
+
+    self.live_files.insert(reference_id.clone());
+    if let Some(module_id) = module_id {
+      self.module_files.entry(module_id.into()).or_default().push(reference_id.clone());
     }
+    reference_id
+  }
 
-    let reference_id = self.assign_reference_id(file.file_name.clone());
-    if file.file_name.is_none() {
-      self.source_hash_to_reference_id.insert(hash.clone(), reference_id.clone());
+  /// Marks the file emitted while loading another module as emitted while loading the module `module_id` too, so
+  /// it's emitted again if any of the modules is reused from the cache in watch mode.
+  pub fn add_file_for_module(&self, reference_id: &ArcStr, module_id: &str) {
+    self.live_files.insert(reference_id.clone());
+    self.module_files.entry(module_id.into()).or_default().push(reference_id.clone());
+  }
+
+  /// Emits the files of the module again, which are emitted while loading it in the previous build.
+  pub fn reemit_module_files(&self, module_id: &str) {
+    if let Some((module_id, reference_ids)) = self.previous_module_files.remove(module_id) {
+      for reference_id in &reference_ids {
+        self.live_files.insert(reference_id.clone());
+      }
+      self.module_files.insert(module_id, reference_ids);
     }
+  }
 
-    self.generate_file_name(&mut file, &hash);
-    self.files.insert(reference_id.clone(), file);
-    reference_id
+  /// Forget the files emitted in the previous build, and only add the files emitted again to the bundle while
+  /// rebuilding.
+  pub fn reset_files(&self) {
+    self.previous_module_files.clear();
+    self.module_files.retain(|module_id, reference_ids| {
+      self.previous_module_files.insert(module_id.clone(), std::mem::take(reference_ids));
+      false
+    });
+    self.live_files.clear();
+    self.emitted_files.clear();
+  }
+
+  pub fn emit_chunk(&self, chunk: EmittedChunk) -> anyhow::Result<ArcStr> {
+    if self.is_chunk_emission_closed.load(Ordering::Relaxed) {
+      return Err(anyhow::format_err!(
+        "Unable to emit chunk {:?}, chunks can't be emitted after module loading has finished.",
+        chunk.id
+      ));
+    }
+    let reference_id = self.assign_reference_id(chunk.file_name.clone());
+    self.chunks.lock().expect("chunks lock poisoned").push((reference_id.clone(), Arc::new(chunk)));
+    Ok(reference_id)
+  }
+
+  /// Returns emitted chunks starting from the `start`th one, which should be added to the module graph as entries.
+  pub fn emitted_chunks_from(&self, start: usize) -> Vec<(ArcStr, Arc<EmittedChunk>)> {
+    let chunks = self.chunks.lock().expect("chunks lock poisoned");
+    chunks.get(start..).map(<[_]>::to_vec).unwrap_or_default()
+  }
+
+  pub fn set_chunk_entry_module(&self, reference_id: ArcStr, module_id: ArcStr) {
+    self.chunk_entry_modules.insert(reference_id, module_id);
+  }
+
+  pub fn close_chunk_emission(&self) {
+    self.is_chunk_emission_closed.store(true, Ordering::Relaxed);
+  }
+
+  /// Forget emitted chunks of the previous build, so they could be emitted again while rebuilding.
+  pub fn reset_chunks(&self) {
+    self.chunks.lock().expect("chunks lock poisoned").clear();
+    self.chunk_entry_modules.clear();
+    self.chunk_file_names.clear();
+    self.is_chunk_emission_closed.store(false, Ordering::Relaxed);
+  }
+
+  /// Records the final file names of emitted chunks, so they could be read through `get_file_name`.
+  pub fn set_chunk_file_names(&self, bundle: &[Output]) {
+    for output in bundle {
+      let Output::Chunk(chunk) = output else {
+        continue;
+      };
+      let Some(facade_module_id) = chunk.facade_module_id.as_ref().filter(|_| chunk.is_entry)
+      else {
+        continue;
+      };
+      for entry in &self.chunk_entry_modules {
+        if entry.value().as_str() == facade_module_id.as_str() {
+          self.chunk_file_names.insert(entry.key().clone(), chunk.filename.as_str().into());
+        }
+      }
+    }
   }
 
   pub fn try_get_file_name(&self, reference_id: &str) -> Result<ArcStr, String> {
+    if let Some(file_name) = self.chunk_file_names.get(reference_id) {
+      return Ok(file_name.value().clone());
+    }
+    if self.chunk_entry_modules.contains_key(reference_id) {
+      return Err(format!(
+        "Unable to get file name for emitted chunk {reference_id}. File names of chunks are only available after chunks are rendered."
+      ));
+    }
     let file = self
       .files
       .get(reference_id)
@@ -122,17 +255,22 @@ impl FileEmitter {
   }
 
   pub fn add_additional_files(&self, bundle: &mut Vec<Output>) {
-    self.files.iter_mut().for_each(|mut file| {
-      let (key, value) = file.pair_mut();
-      if self.emitted_files.contains(key) {
+    // Files that are not emitted again in this build are no longer used.
+    self.files.retain(|reference_id, _| self.live_files.contains(reference_id));
+    self
+      .source_hash_to_reference_id
+      .retain(|_, reference_id| self.files.contains_key(reference_id));
+    self.files.iter().for_each(|file| {
+      let (key, value) = file.pair();
+      if !self.emitted_files.insert(key.clone()) {
         return;
       }
-      self.emitted_files.insert(key.clone());
+      // The files are kept for rebuilding in watch mode, so the content is cloned.
       bundle.push(Output::Asset(Box::new(OutputAsset {
         filename: value.file_name.clone().expect("should have file name"),
-        source: std::mem::take(&mut value.source),
-        name: std::mem::take(&mut value.name),
-        original_file_name: std::mem::take(&mut value.original_file_name),
+        source: value.source.clone(),
+        name: value.name.clone(),
+        original_file_name: value.original_file_name.clone(),
       })));
     });
   }
@@ -2,8 +2,18 @@ use rolldown_utils::indexmap::FxIndexMap;
 use std::{collections::HashMap, fmt::Debug, path::PathBuf};
 use types::advanced_chunks_options::AdvancedChunksOptions;
 use types::checks_options::ChecksOptions;
+use types::css_modules_options::CssModulesOptions;
+use types::depfile_options::DepfileOptions;
 use types::inject_import::InjectImport;
+use types::log_level::LogLevel;
+use types::minify_options::MinifyOptions;
+use types::on_log::OnLog;
+use types::persistent_cache_options::PersistentCacheOptions;
+use types::stats_options::StatsOptions;
+use types::watch_option::WatchOption;
 
+#[cfg(feature = "deserialize_bundler_options")]
+use crate::InteropType;
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
@@ -19,7 +29,7 @@ use self::types::{
   platform::Platform, resolve_options::ResolveOptions, source_map_type::SourceMapType,
   sourcemap_path_transform::SourceMapPathTransform,
 };
-use crate::{ChunkFilenamesOutputOption, ModuleType, SourceMapIgnoreList};
+use crate::{ChunkFilenamesOutputOption, InteropOutputOption, ModuleType, SourceMapIgnoreList};
 
 pub mod types;
 
@@ -98,6 +108,12 @@ pub struct BundlerOptions {
     schemars(with = "Option<String>")
   )]
   pub outro: Option<AddonOutputOption>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_interop"),
+    schemars(with = "Option<InteropType>")
+  )]
+  pub interop: Option<InteropOutputOption>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, skip_deserializing),
@@ -121,7 +137,7 @@ pub struct BundlerOptions {
   )]
   pub treeshake: TreeshakeOptions,
   pub experimental: Option<ExperimentalOptions>,
-  pub minify: Option<bool>,
+  pub minify: Option<MinifyOptions>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     schemars(with = "Option<HashMap<String, String>>")
@@ -132,8 +148,48 @@ pub struct BundlerOptions {
   pub inject: Option<Vec<InjectImport>>,
   pub external_live_bindings: Option<bool>,
   pub inline_dynamic_imports: Option<bool>,
+  /// Emit one chunk per module instead of merging modules, so the output mirrors the structure of the source.
+  pub preserve_modules: Option<bool>,
+  /// The directory stripped from paths of modules in `preserveModules` mode. Defaults to the common directory of
+  /// all modules.
+  pub preserve_modules_root: Option<String>,
   pub advanced_chunks: Option<AdvancedChunksOptions>,
   pub checks: Option<ChecksOptions>,
+  /// Logs more verbose than this level are dropped from `BundleOutput::warnings`. Defaults to `info`.
+  pub log_level: Option<LogLevel>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub on_log: Option<OnLog>,
+  /// Options of CSS Modules, which are enabled for files ending with `.module.css`.
+  pub css_modules: Option<CssModulesOptions>,
+  /// Cache the loaded and transformed modules on the disk, so they could be reused across processes. Only enabled
+  /// while every plugin has a cache key.
+  pub persistent_cache: Option<PersistentCacheOptions>,
+  /// Emit a JSON asset describing the chunks, modules, imports and their sizes, in the format of esbuild's metafile.
+  pub stats: Option<StatsOptions>,
+  /// List the files the build depends on in a depfile next to the outputs, for build systems like Ninja and Bazel.
+  pub depfile: Option<DepfileOptions>,
+  // --- options for watch mode
+  pub watch: Option<WatchOption>,
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+impl BundlerOptions {
+  /// The JSON Schema of config files, which could be referenced by `$schema` to get completions and checks in editors.
+  pub fn json_schema() -> schemars::schema::RootSchema {
+    use schemars::schema::{InstanceType, SchemaObject};
+    let mut schema = schemars::schema_for!(BundlerOptions);
+    // Unknown fields are denied, but `$schema` is allowed for referencing the schema itself.
+    if let Some(object) = &mut schema.schema.object {
+      let string_schema =
+        SchemaObject { instance_type: Some(InstanceType::String.into()), ..Default::default() };
+      object.properties.insert("$schema".to_string(), string_schema.into());
+    }
+    schema
+  }
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -154,6 +210,15 @@ where
   Ok(deserialized.map(|s| AddonOutputOption::String(Some(s))))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_interop<'de, D>(deserializer: D) -> Result<Option<InteropOutputOption>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<InteropType>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_chunk_filenames<'de, D>(
   deserializer: D,
@@ -1,10 +1,14 @@
+use std::{fmt::Debug, future::Future, pin::Pin, sync::Arc};
+
 use rolldown_utils::js_regex::HybridRegex;
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
 use serde::{Deserialize, Deserializer};
 
-#[derive(Default, Debug)]
+use crate::ModuleInfo;
+
+#[derive(Default, Debug, Clone)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
   derive(Deserialize, JsonSchema),
@@ -13,31 +17,112 @@ use serde::{Deserialize, Deserializer};
 pub struct AdvancedChunksOptions {
   pub min_share_count: Option<u32>,
   pub min_size: Option<f64>,
+  pub max_size: Option<f64>,
   pub groups: Option<Vec<MatchGroup>>,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
   derive(Deserialize, JsonSchema),
   serde(rename_all = "camelCase", deny_unknown_fields)
 )]
 pub struct MatchGroup {
-  pub name: String,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(deserialize_with = "deserialize_name"),
+    schemars(with = "String")
+  )]
+  pub name: MatchGroupName,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(deserialize_with = "deserialize_test", default),
     schemars(with = "Option<String>")
   )]
-  pub test: Option<HybridRegex>,
+  pub test: Option<MatchGroupTest>,
   // pub share_count: Option<u32>,
   pub priority: Option<u32>,
   pub min_size: Option<f64>,
+  /// Groups larger than this are split into multiple chunks, each of which is at most `max_size` unless it only contains a
+  /// single module.
+  pub max_size: Option<f64>,
   pub min_share_count: Option<u32>,
+  /// Modules smaller than this are not captured by the group.
+  pub min_module_size: Option<f64>,
+}
+
+pub type MatchGroupTestFn = dyn Fn(
+    &str,
+    Arc<ModuleInfo>,
+  ) -> Pin<Box<(dyn Future<Output = anyhow::Result<bool>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// Decides whether a module is captured by a group.
+#[derive(Clone)]
+pub enum MatchGroupTest {
+  Regex(HybridRegex),
+  /// Called with the module id and its `ModuleInfo`, which allows matching by importers, package names and so on.
+  Fn(Arc<MatchGroupTestFn>),
+}
+
+impl Debug for MatchGroupTest {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Regex(regex) => write!(f, "MatchGroupTest::Regex({regex:?})"),
+      Self::Fn(_) => write!(f, "MatchGroupTest::Fn(...)"),
+    }
+  }
+}
+
+pub type MatchGroupNameFn = dyn Fn(
+    &str,
+    Arc<ModuleInfo>,
+  ) -> Pin<Box<(dyn Future<Output = anyhow::Result<Option<String>>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// The name of chunks created by a group.
+#[derive(Clone)]
+pub enum MatchGroupName {
+  Static(String),
+  /// Like rollup's `output.manualChunks`, the group is matched by modules for which a name is returned, and modules with
+  /// different names are put into different chunks.
+  Fn(Arc<MatchGroupNameFn>),
+}
+
+impl Default for MatchGroupName {
+  fn default() -> Self {
+    Self::Static(String::new())
+  }
+}
+
+impl Debug for MatchGroupName {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Static(name) => write!(f, "MatchGroupName::Static({name:?})"),
+      Self::Fn(_) => write!(f, "MatchGroupName::Fn(...)"),
+    }
+  }
+}
+
+impl From<String> for MatchGroupName {
+  fn from(value: String) -> Self {
+    Self::Static(value)
+  }
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_name<'de, D>(deserializer: D) -> Result<MatchGroupName, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = String::deserialize(deserializer)?;
+  Ok(MatchGroupName::Static(deserialized))
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
-fn deserialize_test<'de, D>(deserializer: D) -> Result<Option<HybridRegex>, D::Error>
+fn deserialize_test<'de, D>(deserializer: D) -> Result<Option<MatchGroupTest>, D::Error>
 where
   D: Deserializer<'de>,
 {
@@ -46,5 +131,5 @@ where
     .map(|inner| HybridRegex::new(&inner))
     .transpose()
     .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex")))?;
-  Ok(transformed)
+  Ok(transformed.map(MatchGroupTest::Regex))
 }
@@ -0,0 +1,16 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CssModulesOptions {
+  /// The pattern of scoped class names. `[hash]` is the hash of the file path relative to `cwd`, `[name]` is the
+  /// file name without the extension and `[local]` is the original class name. Defaults to `[hash]_[local]`.
+  pub pattern: Option<String>,
+}
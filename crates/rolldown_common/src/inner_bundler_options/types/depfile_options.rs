@@ -0,0 +1,18 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct DepfileOptions {
+  /// The file name of the Makefile-style depfile written by `Bundler::write`, relative to `dir`. Defaults to
+  /// `deps.d`.
+  pub filename: Option<String>,
+  /// Also write the dependencies as a JSON array to `<filename>.json`. Defaults to `false`.
+  pub json: Option<bool>,
+}
@@ -12,7 +12,7 @@ use serde::Deserialize;
 /// > export of this module corresponds to the `.default` property of the exported object.
 /// >
 /// > *From rollupjs.org*
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
   derive(Deserialize, JsonSchema),
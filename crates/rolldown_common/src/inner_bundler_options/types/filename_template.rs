@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FilenameTemplate {
   template: String,
 }
@@ -19,7 +19,7 @@ impl From<String> for FilenameTemplate {
   }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct FileNameRenderOptions<'me> {
   pub name: Option<&'me str>,
   pub hash: Option<&'me str>,
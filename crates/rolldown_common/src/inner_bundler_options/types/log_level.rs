@@ -0,0 +1,28 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Levels of the logs kept in `BundleOutput::warnings`, ordered from the least verbose to the most verbose.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
+#[cfg_attr(feature = "deserialize_bundler_options", serde(rename_all = "camelCase"))]
+pub enum LogLevel {
+  Warn,
+  #[default]
+  Info,
+  Debug,
+}
+
+impl TryFrom<&str> for LogLevel {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "warn" => Ok(Self::Warn),
+      "info" => Ok(Self::Info),
+      "debug" => Ok(Self::Debug),
+      _ => Err(format!("Unknown log level: {value:?}")),
+    }
+  }
+}
@@ -0,0 +1,110 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// `true` enables compressing, mangling and removing whitespace with the default options.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(untagged)
+)]
+pub enum MinifyOptions {
+  Boolean(bool),
+  Object(MinifyOptionsObject),
+}
+
+impl From<bool> for MinifyOptions {
+  fn from(value: bool) -> Self {
+    MinifyOptions::Boolean(value)
+  }
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MinifyOptionsObject {
+  /// Apply optimizations like dead code elimination and constant folding. Defaults to `true`.
+  pub compress: Option<bool>,
+  /// Rename local variables to shorter names. Defaults to `true`.
+  pub mangle: Option<bool>,
+  /// Remove unnecessary whitespace. Defaults to `true`.
+  pub whitespace: Option<bool>,
+  /// Keep the names of functions and classes, so `Function.prototype.name` still works. Defaults to `false`.
+  pub keep_names: Option<bool>,
+  /// Statements to remove, whether or not `compress` is enabled. Defaults to `[]`.
+  pub drop: Option<Vec<MinifyDrop>>,
+  /// How many times to run the compressor. More passes may produce smaller output. Defaults to `1`.
+  pub compress_passes: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase")
+)]
+pub enum MinifyDrop {
+  /// Calls of `console.*`.
+  Console,
+  /// `debugger` statements.
+  Debugger,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub struct NormalizedMinifyOptions {
+  pub compress: bool,
+  pub mangle: bool,
+  pub whitespace: bool,
+  pub keep_names: bool,
+  pub drop_console: bool,
+  pub drop_debugger: bool,
+  pub compress_passes: u32,
+}
+
+impl Default for NormalizedMinifyOptions {
+  fn default() -> Self {
+    Self {
+      compress: true,
+      mangle: true,
+      whitespace: true,
+      keep_names: false,
+      drop_console: false,
+      drop_debugger: false,
+      compress_passes: 1,
+    }
+  }
+}
+
+impl MinifyOptions {
+  /// Returns `None` if nothing is minified.
+  pub fn normalize(self) -> Option<NormalizedMinifyOptions> {
+    let normalized = match self {
+      MinifyOptions::Boolean(false) => return None,
+      MinifyOptions::Boolean(true) => NormalizedMinifyOptions::default(),
+      MinifyOptions::Object(object) => {
+        let drop = object.drop.unwrap_or_default();
+        NormalizedMinifyOptions {
+          compress: object.compress.unwrap_or(true),
+          mangle: object.mangle.unwrap_or(true),
+          whitespace: object.whitespace.unwrap_or(true),
+          keep_names: object.keep_names.unwrap_or(false),
+          drop_console: drop.contains(&MinifyDrop::Console),
+          drop_debugger: drop.contains(&MinifyDrop::Debugger),
+          compress_passes: object.compress_passes.unwrap_or(1).max(1),
+        }
+      }
+    };
+    (normalized.compress
+      || normalized.mangle
+      || normalized.whitespace
+      || normalized.drop_console
+      || normalized.drop_debugger)
+      .then_some(normalized)
+  }
+}
@@ -1,19 +1,27 @@
 pub mod advanced_chunks_options;
 pub mod checks_options;
+pub mod css_modules_options;
+pub mod depfile_options;
 pub mod es_module_flag;
 pub mod experimental_options;
 pub mod filename_template;
 pub mod inject_import;
 pub mod input_item;
 pub mod is_external;
+pub mod log_level;
+pub mod minify_options;
 pub mod module_type;
 pub mod normalized_bundler_options;
+pub mod on_log;
 pub mod output_exports;
 pub mod output_format;
 pub mod output_option;
+pub mod persistent_cache_options;
 pub mod platform;
 pub mod resolve_options;
 pub mod source_map_type;
 pub mod sourcemap_ignore_list;
 pub mod sourcemap_path_transform;
+pub mod stats_options;
 pub mod treeshake;
+pub mod watch_option;
@@ -4,14 +4,19 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use arcstr::ArcStr;
 use oxc::minifier::InjectGlobalVariablesConfig;
 use rustc_hash::FxHashMap;
 
 use super::advanced_chunks_options::AdvancedChunksOptions;
 use super::checks_options::ChecksOptions;
 use super::experimental_options::ExperimentalOptions;
-use super::output_option::ChunkFilenamesOutputOption;
+use super::log_level::LogLevel;
+use super::minify_options::NormalizedMinifyOptions;
+use super::on_log::OnLog;
+use super::output_option::{ChunkFilenamesOutputOption, InteropOutputOption};
 use super::treeshake::TreeshakeOptions;
+use super::watch_option::WatchOption;
 use super::{
   filename_template::FilenameTemplate, is_external::IsExternal, output_exports::OutputExports,
   output_format::OutputFormat, output_option::AddonOutputOption, platform::Platform,
@@ -21,7 +26,7 @@ use super::{
 use crate::{EsModuleFlag, InjectImport, InputItem, ModuleType};
 
 #[allow(clippy::struct_excessive_bools)] // Using raw booleans is more clear in this case
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NormalizedBundlerOptions {
   // --- Input
   pub input: Vec<InputItem>,
@@ -50,19 +55,40 @@ pub struct NormalizedBundlerOptions {
   pub footer: Option<AddonOutputOption>,
   pub intro: Option<AddonOutputOption>,
   pub outro: Option<AddonOutputOption>,
+  pub interop: InteropOutputOption,
   pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub experimental: ExperimentalOptions,
-  pub minify: bool,
+  /// `None` if minification is disabled.
+  pub minify: Option<NormalizedMinifyOptions>,
   pub extend: bool,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
   pub inject: Vec<InjectImport>,
   pub oxc_inject_global_variables_config: InjectGlobalVariablesConfig,
   pub external_live_bindings: bool,
   pub inline_dynamic_imports: bool,
+  pub preserve_modules: bool,
+  /// Absolute path of `preserveModulesRoot`.
+  pub preserve_modules_root: Option<PathBuf>,
   pub advanced_chunks: Option<AdvancedChunksOptions>,
   pub checks: ChecksOptions,
+  pub log_level: LogLevel,
+  pub on_log: Option<OnLog>,
   pub profiler_names: bool,
+  /// The pattern of scoped class names in CSS Modules.
+  pub css_modules_pattern: String,
+  /// `None` if the persistent cache is disabled.
+  pub persistent_cache_dir: Option<PathBuf>,
+  /// `None` if the stats asset shouldn't be emitted.
+  pub stats_filename: Option<String>,
+  /// `None` if the depfile shouldn't be written.
+  pub depfile_filename: Option<String>,
+  pub depfile_json: bool,
+  // --- Watch
+  pub watch: WatchOption,
+  // --- Worker
+  /// Ids of the workers enclosing the build, from the outermost one. Empty unless the build bundles a worker.
+  pub enclosing_workers: Vec<ArcStr>,
 }
 
 pub type SharedNormalizedBundlerOptions = Arc<NormalizedBundlerOptions>;
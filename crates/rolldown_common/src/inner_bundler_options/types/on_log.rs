@@ -0,0 +1,34 @@
+use std::{fmt::Debug, sync::Arc};
+
+use super::log_level::LogLevel;
+
+/// A log passed to `onLog`.
+#[derive(Debug, Clone, Copy)]
+pub struct Log<'a> {
+  pub level: LogLevel,
+  /// The kind of the log, such as `PLUGIN_WARNING`.
+  pub code: &'a str,
+  pub message: &'a str,
+}
+
+pub type OnLogFn = dyn Fn(Log<'_>) -> bool + Send + Sync;
+
+/// Called with every log that passes `logLevel`. Logs are dropped if it returns `false`.
+#[derive(Clone)]
+pub struct OnLog(Arc<OnLogFn>);
+
+impl Debug for OnLog {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "OnLog::Fn(...)")
+  }
+}
+
+impl OnLog {
+  pub fn new(f: Box<OnLogFn>) -> Self {
+    Self(f.into())
+  }
+
+  pub fn call(&self, log: Log<'_>) -> bool {
+    self.0(log)
+  }
+}
@@ -4,7 +4,7 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use std::fmt::Display;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
   derive(Deserialize, JsonSchema),
@@ -15,11 +15,13 @@ pub enum OutputFormat {
   Cjs,
   App,
   Iife,
+  Umd,
+  Amd,
 }
 
 impl OutputFormat {
   pub fn requires_scope_hoisting(&self) -> bool {
-    matches!(self, Self::Esm | Self::Cjs | Self::Iife)
+    matches!(self, Self::Esm | Self::Cjs | Self::Iife | Self::Umd | Self::Amd)
   }
 }
 
@@ -30,6 +32,8 @@ impl Display for OutputFormat {
       Self::Cjs => write!(f, "cjs"),
       Self::App => write!(f, "app"),
       Self::Iife => write!(f, "iife"),
+      Self::Umd => write!(f, "umd"),
+      Self::Amd => write!(f, "amd"),
     }
   }
 }
@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use crate::Interop;
+
+/// Controls how the `default` import and the namespace object of a CommonJS module are created when it's
+/// imported by ES modules. It is rollup-compatible, and you may find rollup explanation
+/// [here](https://rollupjs.org/configuration-options/#output-interop).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum InteropType {
+  /// `module.exports` is always the `default` export, and named imports are read from its properties.
+  /// This matches the behavior of Node.js when ES modules import CommonJS modules.
+  Default,
+  /// The module is assumed to be a transpiled ES module, so `module.exports` is used as the namespace object
+  /// directly and the `default` import is `module.exports.default`.
+  EsModule,
+  /// Use `module.exports.default` as the `default` export if `module.exports.__esModule` is set, otherwise
+  /// `module.exports` is the `default` export. This is the behavior of Babel and other bundlers.
+  #[default]
+  Auto,
+  /// Like `default`, but only the `default` import and namespace imports are allowed.
+  DefaultOnly,
+}
+
+impl InteropType {
+  /// Returns how the `__toESM` helper should be called for a module whose interop is inferred as `inferred`.
+  /// `None` means the module doesn't need the helper.
+  pub fn resolve(self, inferred: Option<Interop>) -> Option<Interop> {
+    let inferred = inferred?;
+    match self {
+      Self::Auto => Some(inferred),
+      Self::Default | Self::DefaultOnly => Some(Interop::Node),
+      Self::EsModule => None,
+    }
+  }
+}
+
+impl TryFrom<&str> for InteropType {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "default" => Ok(Self::Default),
+      "esModule" => Ok(Self::EsModule),
+      "auto" => Ok(Self::Auto),
+      "defaultOnly" => Ok(Self::DefaultOnly),
+      _ => Err(format!("Unknown interop: {value:?}")),
+    }
+  }
+}
+
+pub type InteropFunction = dyn Fn(&str) -> Pin<Box<(dyn Future<Output = anyhow::Result<InteropType>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+#[derive(Clone)]
+pub enum InteropOutputOption {
+  Type(InteropType),
+  /// Called with the id of each external module. Bundled CommonJS modules use `InteropType::Auto`.
+  Fn(Arc<InteropFunction>),
+}
+
+impl Debug for InteropOutputOption {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Type(value) => write!(f, "InteropOutputOption::Type({value:?})"),
+      Self::Fn(_) => write!(f, "InteropOutputOption::Fn(...)"),
+    }
+  }
+}
+
+impl Default for InteropOutputOption {
+  fn default() -> Self {
+    Self::Type(InteropType::default())
+  }
+}
+
+impl InteropOutputOption {
+  pub async fn call(&self, external_id: &str) -> anyhow::Result<InteropType> {
+    match self {
+      Self::Type(value) => Ok(*value),
+      Self::Fn(value) => value(external_id).await,
+    }
+  }
+
+  /// The interop of CommonJS modules that are bundled.
+  pub fn for_normal_modules(&self) -> InteropType {
+    match self {
+      Self::Type(value) => *value,
+      Self::Fn(_) => InteropType::Auto,
+    }
+  }
+}
+
+impl From<InteropType> for InteropOutputOption {
+  fn from(value: InteropType) -> Self {
+    Self::Type(value)
+  }
+}
@@ -1,5 +1,7 @@
 mod addon;
 mod chunk_filenames;
+mod interop;
 
 pub use addon::{AddonFunction, AddonOutputOption};
 pub use chunk_filenames::ChunkFilenamesOutputOption;
+pub use interop::{InteropFunction, InteropOutputOption, InteropType};
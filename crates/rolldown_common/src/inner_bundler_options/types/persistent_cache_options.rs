@@ -0,0 +1,15 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct PersistentCacheOptions {
+  /// The directory to store the cached modules in, resolved against `cwd`. Defaults to `node_modules/.rolldown`.
+  pub dir: Option<String>,
+}
@@ -1,11 +1,12 @@
 use std::fmt::Debug;
-use std::{future::Future, pin::Pin};
+use std::{future::Future, pin::Pin, sync::Arc};
 
 pub type SourceMapIgnoreListFn = dyn Fn(&str, &str) -> Pin<Box<(dyn Future<Output = anyhow::Result<bool>> + Send + 'static)>>
   + Send
   + Sync;
 
-pub struct SourceMapIgnoreList(Box<SourceMapIgnoreListFn>);
+#[derive(Clone)]
+pub struct SourceMapIgnoreList(Arc<SourceMapIgnoreListFn>);
 
 impl Debug for SourceMapIgnoreList {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -15,7 +16,7 @@ impl Debug for SourceMapIgnoreList {
 
 impl SourceMapIgnoreList {
   pub fn new(f: Box<SourceMapIgnoreListFn>) -> Self {
-    Self(f)
+    Self(f.into())
   }
 
   pub async fn call(&self, source: &str, sourcemap_path: &str) -> anyhow::Result<bool> {
@@ -1,11 +1,12 @@
 use std::fmt::Debug;
-use std::{future::Future, pin::Pin};
+use std::{future::Future, pin::Pin, sync::Arc};
 
 type SourceMapPathTransformFn = dyn Fn(&str, &str) -> Pin<Box<(dyn Future<Output = anyhow::Result<String>> + Send + 'static)>>
   + Send
   + Sync;
 
-pub struct SourceMapPathTransform(Box<SourceMapPathTransformFn>);
+#[derive(Clone)]
+pub struct SourceMapPathTransform(Arc<SourceMapPathTransformFn>);
 
 impl Debug for SourceMapPathTransform {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -15,7 +16,7 @@ impl Debug for SourceMapPathTransform {
 
 impl SourceMapPathTransform {
   pub fn new(f: Box<SourceMapPathTransformFn>) -> Self {
-    Self(f)
+    Self(f.into())
   }
 
   pub async fn call(&self, source: &str, sourcemap_path: &str) -> anyhow::Result<String> {
@@ -4,7 +4,7 @@ use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
 use serde::{Deserialize, Deserializer};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
   derive(Deserialize, JsonSchema),
@@ -22,7 +22,7 @@ impl Default for TreeshakeOptions {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ModuleSideEffects {
   Regex(HybridRegex),
   Boolean(bool),
@@ -43,7 +43,7 @@ impl TreeshakeOptions {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
   derive(Deserialize, JsonSchema),
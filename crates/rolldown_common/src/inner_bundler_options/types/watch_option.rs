@@ -0,0 +1,17 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct WatchOption {
+  /// Milliseconds to wait for further changes before triggering a rebuild. Defaults to `0`.
+  pub build_delay: Option<u32>,
+  /// Only generate the bundle without writing it to the disk. Defaults to `false`.
+  pub skip_write: Option<bool>,
+}
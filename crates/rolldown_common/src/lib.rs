@@ -13,24 +13,38 @@ mod types;
 pub mod bundler_options {
   pub use crate::inner_bundler_options::{
     types::{
-      advanced_chunks_options::{AdvancedChunksOptions, MatchGroup},
+      advanced_chunks_options::{
+        AdvancedChunksOptions, MatchGroup, MatchGroupName, MatchGroupNameFn, MatchGroupTest,
+        MatchGroupTestFn,
+      },
+      css_modules_options::CssModulesOptions,
+      depfile_options::DepfileOptions,
       es_module_flag::EsModuleFlag,
       experimental_options::ExperimentalOptions,
       filename_template::{FileNameRenderOptions, FilenameTemplate},
       inject_import::InjectImport,
       input_item::InputItem,
       is_external::IsExternal,
+      log_level::LogLevel,
+      minify_options::{MinifyDrop, MinifyOptions, MinifyOptionsObject, NormalizedMinifyOptions},
       module_type::ModuleType,
       normalized_bundler_options::{NormalizedBundlerOptions, SharedNormalizedBundlerOptions},
+      on_log::{Log, OnLog, OnLogFn},
       output_exports::OutputExports,
       output_format::OutputFormat,
-      output_option::{AddonFunction, AddonOutputOption, ChunkFilenamesOutputOption},
+      output_option::{
+        AddonFunction, AddonOutputOption, ChunkFilenamesOutputOption, InteropFunction,
+        InteropOutputOption, InteropType,
+      },
+      persistent_cache_options::PersistentCacheOptions,
       platform::Platform,
       resolve_options::ResolveOptions,
       source_map_type::SourceMapType,
       sourcemap_ignore_list::SourceMapIgnoreList,
       sourcemap_path_transform::SourceMapPathTransform,
+      stats_options::StatsOptions,
       treeshake::{InnerOptions, ModuleSideEffects, TreeshakeOptions},
+      watch_option::WatchOption,
     },
     BundlerOptions,
   };
@@ -51,7 +65,7 @@ pub use crate::{
     ecma_view::{EcmaModuleAstUsage, EcmaView},
     module_idx::ModuleIdx,
   },
-  file_emitter::{EmittedAsset, FileEmitter, SharedFileEmitter},
+  file_emitter::{EmittedAsset, EmittedChunk, FileEmitter, SharedFileEmitter},
   module::{external_module::ExternalModule, normal_module::NormalModule, Module},
   types::asset::Asset,
   types::asset_idx::AssetIdx,
@@ -75,7 +89,7 @@ pub use crate::{
   types::module_def_format::ModuleDefFormat,
   types::module_id::ModuleId,
   types::module_idx::LegacyModuleIdx,
-  types::module_info::ModuleInfo,
+  types::module_info::{ModuleInfo, ModuleMeta},
   types::module_table::{IndexExternalModules, IndexModules, ModuleTable},
   types::module_view::ModuleView,
   types::named_export::LocalExport,
@@ -93,6 +107,8 @@ pub use crate::{
   types::str_or_bytes::StrOrBytes,
   types::symbol_or_member_expr_ref::SymbolOrMemberExprRef,
   types::symbol_ref::SymbolRef,
+  types::synthetic_named_exports::SyntheticNamedExports,
+  types::watcher_change_kind::WatcherChangeKind,
   types::wrap_kind::WrapKind,
 };
 pub use bundler_options::*;
@@ -1,9 +1,12 @@
 use crate::side_effects::DeterminedSideEffects;
-use crate::{ImportRecord, ImportRecordIdx, ModuleIdx, SymbolRef};
+use crate::{
+  ImportRecord, ImportRecordIdx, Interop, InteropType, ModuleId, ModuleIdx, ModuleInfo, ModuleMeta,
+  SymbolRef,
+};
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ExternalModule {
   pub idx: ModuleIdx,
   pub exec_order: u32,
@@ -12,6 +15,11 @@ pub struct ExternalModule {
   pub name: ArcStr,
   pub import_records: IndexVec<ImportRecordIdx, ImportRecord>,
   pub side_effects: DeterminedSideEffects,
+  pub importers: Vec<ModuleId>,
+  pub dynamic_importers: Vec<ModuleId>,
+  pub meta: ModuleMeta,
+  /// Resolved from `output.interop` for this module.
+  pub interop: InteropType,
 }
 
 impl ExternalModule {
@@ -20,6 +28,7 @@ impl ExternalModule {
     module_id: ArcStr,
     side_effects: DeterminedSideEffects,
     symbol_ref: SymbolRef,
+    meta: ModuleMeta,
   ) -> Self {
     Self {
       idx,
@@ -28,6 +37,43 @@ impl ExternalModule {
       name: module_id,
       import_records: IndexVec::default(),
       side_effects,
+      importers: vec![],
+      dynamic_importers: vec![],
+      meta,
+      interop: InteropType::default(),
+    }
+  }
+
+  /// Returns how `__toESM` should wrap `require()` of this module. `None` means the required value is used as the
+  /// namespace object directly.
+  pub fn to_esm_interop(&self) -> Option<Interop> {
+    self.interop.resolve(Some(Interop::Babel))
+  }
+
+  pub fn to_module_info(&self) -> ModuleInfo {
+    ModuleInfo {
+      code: None,
+      id: ModuleId::new(ArcStr::clone(&self.name)),
+      is_entry: false,
+      is_external: true,
+      is_included: None,
+      importers: {
+        let mut value = self.importers.clone();
+        value.sort_unstable();
+        value
+      },
+      dynamic_importers: {
+        let mut value = self.dynamic_importers.clone();
+        value.sort_unstable();
+        value
+      },
+      imported_ids: vec![],
+      dynamically_imported_ids: vec![],
+      exports: vec![],
+      has_default_export: false,
+      module_type: None,
+      side_effects: self.side_effects,
+      meta: self.meta.clone(),
     }
   }
 }
@@ -8,7 +8,7 @@ use crate::{
   NormalModule,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Module {
   Normal(Box<NormalModule>),
   External(Box<ExternalModule>),
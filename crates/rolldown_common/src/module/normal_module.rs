@@ -4,14 +4,14 @@ use std::ops::{Deref, DerefMut};
 use crate::css::css_view::CssView;
 use crate::{
   DebugStmtInfoForTreeShaking, ExportsKind, ImportRecordIdx, ModuleId, ModuleIdx, ModuleInfo,
-  StmtInfo,
+  ModuleMeta, StmtInfo, SymbolRef,
 };
 use crate::{EcmaAstIdx, EcmaView, IndexModules, Interop, Module, ModuleType};
 
 use rolldown_rstr::Rstr;
 use rustc_hash::FxHashSet;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NormalModule {
   pub exec_order: u32,
   pub idx: ModuleIdx,
@@ -25,6 +25,9 @@ pub struct NormalModule {
   pub module_type: ModuleType,
   pub ecma_view: EcmaView,
   pub css_view: Option<CssView>,
+  pub meta: ModuleMeta,
+  /// The export that missing named imports of this module are resolved from. See `SyntheticNamedExports`.
+  pub synthetic_named_exports: Option<Rstr>,
 }
 
 impl NormalModule {
@@ -35,6 +38,30 @@ impl NormalModule {
     })
   }
 
+  /// Assigns the module to `idx` before it's linked, when only its own symbols are referenced.
+  pub fn set_idx(&mut self, idx: ModuleIdx) {
+    let from = std::mem::replace(&mut self.idx, idx);
+    let move_symbol = |symbol_ref: &mut SymbolRef| {
+      if symbol_ref.owner == from {
+        symbol_ref.owner = idx;
+      }
+    };
+    let view = &mut self.ecma_view;
+    move_symbol(&mut view.namespace_object_ref);
+    move_symbol(&mut view.default_export_ref);
+    view.named_imports = std::mem::take(&mut view.named_imports)
+      .into_iter()
+      .map(|(mut symbol_ref, mut named_import)| {
+        move_symbol(&mut symbol_ref);
+        move_symbol(&mut named_import.imported_as);
+        (symbol_ref, named_import)
+      })
+      .collect();
+    view.named_exports.values_mut().for_each(|export| move_symbol(&mut export.referenced));
+    view.import_records.iter_mut().for_each(|rec| move_symbol(&mut rec.namespace_ref));
+    view.stmt_infos.move_symbols(from, idx);
+  }
+
   pub fn to_debug_normal_module_for_tree_shaking(&self) -> DebugNormalModuleForTreeShaking {
     DebugNormalModuleForTreeShaking {
       id: self.repr_name.to_string(),
@@ -53,6 +80,8 @@ impl NormalModule {
       code: Some(self.ecma_view.source.clone()),
       id: self.id.clone(),
       is_entry: self.is_user_defined_entry,
+      is_external: false,
+      is_included: None,
       importers: {
         let mut value = self.ecma_view.importers.clone();
         value.sort_unstable();
@@ -65,9 +94,24 @@ impl NormalModule {
       },
       imported_ids: self.ecma_view.imported_ids.clone(),
       dynamically_imported_ids: self.ecma_view.dynamically_imported_ids.clone(),
+      exports: {
+        let mut value =
+          self.ecma_view.named_exports.keys().map(ToString::to_string).collect::<Vec<_>>();
+        value.sort_unstable();
+        value
+      },
+      has_default_export: self.ecma_view.named_exports.contains_key(&Rstr::new("default")),
+      module_type: Some(self.module_type.clone()),
+      side_effects: self.ecma_view.side_effects,
+      meta: self.meta.clone(),
     }
   }
 
+  /// Same as [`Self::to_module_info`], but with `is_included` filled, which should only be called after tree shaking.
+  pub fn to_linked_module_info(&self) -> ModuleInfo {
+    ModuleInfo { is_included: Some(self.ecma_view.is_included), ..self.to_module_info() }
+  }
+
   // The runtime module and module which path starts with `\0` shouldn't generate sourcemap. Ref see https://github.com/rollup/rollup/blob/master/src/Module.ts#L279.
   pub fn is_virtual(&self) -> bool {
     self.id.starts_with('\0') || self.id.starts_with("rolldown:")
@@ -4,6 +4,7 @@ use crate::EcmaAssetMeta;
 
 pub enum InstantiationKind {
   Ecma(Box<EcmaAssetMeta>),
+  Css,
   // Using Variant `None` instead of `Option<AssetMeta>` to make it friendly to use pattern matching.
   None,
 }
@@ -7,16 +7,20 @@ pub struct EntryPoint {
   pub name: Option<ArcStr>,
   pub id: ModuleIdx,
   pub kind: EntryPointKind,
+  /// The fixed file name of the chunk, only available for chunks emitted by plugins with a `file_name`.
+  pub file_name: Option<ArcStr>,
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum EntryPointKind {
   UserDefined,
+  /// Chunks emitted by plugins through `PluginContext::emit_chunk`.
+  EmittedUserDefined,
   DynamicImport,
 }
 
 impl EntryPointKind {
   pub fn is_user_defined(&self) -> bool {
-    matches!(self, EntryPointKind::UserDefined)
+    matches!(self, EntryPointKind::UserDefined | EntryPointKind::EmittedUserDefined)
   }
 }
@@ -45,7 +45,7 @@ impl Display for ImportKind {
 }
 
 /// See [ImportRecord] for more details.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RawImportRecord {
   // Module Request
   pub module_request: Rstr,
@@ -60,7 +60,7 @@ pub struct RawImportRecord {
 }
 
 bitflags::bitflags! {
-  #[derive(Debug)]
+  #[derive(Debug, Clone, Copy)]
   pub struct ImportRecordMeta: u8 {
     /// If it is `import * as ns from '...'` or `export * as ns from '...'`
     const CONTAINS_IMPORT_STAR = 1;
@@ -103,7 +103,7 @@ impl RawImportRecord {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ImportRecord {
   // Module Request
   pub module_request: Rstr,
@@ -36,4 +36,6 @@ pub mod stmt_info;
 pub mod str_or_bytes;
 pub mod symbol_or_member_expr_ref;
 pub mod symbol_ref;
+pub mod synthetic_named_exports;
+pub mod watcher_change_kind;
 pub mod wrap_kind;
@@ -1,14 +1,30 @@
 use arcstr::ArcStr;
 
-use crate::ModuleId;
+use crate::{side_effects::DeterminedSideEffects, ModuleId, ModuleType};
+
+/// Custom data attached to a module by plugins, which is `meta` in rollup.
+///
+/// Plugins should namespace their data by the plugin name. Meta returned by hooks is merged into the existing meta by
+/// top-level keys.
+pub type ModuleMeta = serde_json::Map<String, serde_json::Value>;
 
 #[derive(Debug)]
 pub struct ModuleInfo {
   pub code: Option<ArcStr>,
   pub id: ModuleId,
   pub is_entry: bool,
+  pub is_external: bool,
+  /// `None` until tree shaking is done. Always `None` for external modules.
+  pub is_included: Option<bool>,
   pub importers: Vec<ModuleId>,
   pub dynamic_importers: Vec<ModuleId>,
   pub imported_ids: Vec<ModuleId>,
   pub dynamically_imported_ids: Vec<ModuleId>,
+  /// Names exported by the module itself, sorted. `export * from` doesn't contribute to it.
+  pub exports: Vec<String>,
+  pub has_default_export: bool,
+  /// `None` for external modules.
+  pub module_type: Option<ModuleType>,
+  pub side_effects: DeterminedSideEffects,
+  pub meta: ModuleMeta,
 }
@@ -21,6 +21,10 @@ pub struct OutputChunk {
   pub imports: Vec<ModuleId>,
   pub dynamic_imports: Vec<ModuleId>,
   // OutputChunk
+  /// Sum of `original_length` of the rendered modules.
+  pub original_length: u32,
+  /// Byte length of `code`, excluding the `sourceMappingURL` comment.
+  pub rendered_length: u32,
   pub code: String,
   pub map: Option<SourceMap>,
   pub sourcemap_filename: Option<String>,
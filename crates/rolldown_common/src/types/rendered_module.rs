@@ -1,4 +1,11 @@
 #[derive(Debug, Clone)]
 pub struct RenderedModule {
   pub code: Option<String>,
+  /// Byte length of the module's code after `transform` hooks.
+  pub original_length: u32,
+  /// Byte length of the module's code in the chunk, after tree shaking.
+  pub rendered_length: u32,
+  pub rendered_exports: Vec<String>,
+  /// Exports that are removed by tree shaking. Always empty if tree shaking is disabled.
+  pub removed_exports: Vec<String>,
 }
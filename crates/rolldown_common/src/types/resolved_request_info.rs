@@ -2,11 +2,11 @@ use std::{path::Path, sync::Arc};
 
 use arcstr::ArcStr;
 
-use crate::{side_effects::HookSideEffects, ModuleDefFormat, PackageJson};
+use crate::{side_effects::HookSideEffects, ModuleDefFormat, ModuleMeta, PackageJson};
 
 use super::module_id::stabilize_module_id;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ResolvedId {
   pub id: ArcStr,
   // https://github.com/defunctzombie/package-browser-field-spec/blob/8c4869f6a5cb0de26d208de804ad0a62473f5a03/README.md?plain=1#L62-L77
@@ -15,6 +15,8 @@ pub struct ResolvedId {
   pub is_external: bool,
   pub package_json: Option<Arc<PackageJson>>,
   pub side_effects: Option<HookSideEffects>,
+  /// Meta returned by the `resolveId` hook.
+  pub meta: ModuleMeta,
 }
 
 impl ResolvedId {
@@ -1,9 +1,9 @@
 use oxc::index::IndexVec;
 use rustc_hash::FxHashMap;
 
-use crate::{ImportRecordIdx, SymbolOrMemberExprRef, SymbolRef};
+use crate::{ImportRecordIdx, ModuleIdx, SymbolOrMemberExprRef, SymbolRef};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct StmtInfos {
   pub infos: IndexVec<StmtInfoIdx, StmtInfo>,
   // only for top level symbols
@@ -43,6 +43,32 @@ impl StmtInfos {
     idx
   }
 
+  /// Moves the symbols owned by `from` to `to`, e.g. when a module is assigned another `ModuleIdx`.
+  pub fn move_symbols(&mut self, from: ModuleIdx, to: ModuleIdx) {
+    let move_symbol = |symbol_ref: &mut SymbolRef| {
+      if symbol_ref.owner == from {
+        symbol_ref.owner = to;
+      }
+    };
+    for info in &mut self.infos {
+      info.declared_symbols.iter_mut().for_each(move_symbol);
+      for referenced in &mut info.referenced_symbols {
+        match referenced {
+          SymbolOrMemberExprRef::Symbol(symbol_ref) => move_symbol(symbol_ref),
+          SymbolOrMemberExprRef::MemberExpr(expr) => move_symbol(&mut expr.object_ref),
+        }
+      }
+    }
+    self.symbol_ref_to_declared_stmt_idx =
+      std::mem::take(&mut self.symbol_ref_to_declared_stmt_idx)
+        .into_iter()
+        .map(|(mut symbol_ref, stmts)| {
+          move_symbol(&mut symbol_ref);
+          (symbol_ref, stmts)
+        })
+        .collect();
+  }
+
   pub fn declared_stmts_by_symbol(&self, symbol_ref: &SymbolRef) -> &[StmtInfoIdx] {
     self.symbol_ref_to_declared_stmt_idx.get(symbol_ref).map_or(&[], Vec::as_slice)
   }
@@ -66,7 +92,7 @@ oxc::index::define_index_type! {
   pub struct StmtInfoIdx = u32;
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct StmtInfo {
   /// The index of this statement in the module body.
   ///
@@ -0,0 +1,23 @@
+use rolldown_rstr::Rstr;
+
+/// Returned by `load` and `transform` hooks, which is `syntheticNamedExports` in rollup.
+///
+/// Named imports that can't be found in the module are resolved as properties of the fallback export instead, and
+/// the namespace object of the module includes the properties of the fallback export too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntheticNamedExports {
+  /// `true` uses the `default` export as the fallback export. `false` turns the feature off.
+  Bool(bool),
+  /// The name of the fallback export.
+  Name(String),
+}
+
+impl SyntheticNamedExports {
+  pub fn fallback_export_name(&self) -> Option<Rstr> {
+    match self {
+      SyntheticNamedExports::Bool(true) => Some("default".into()),
+      SyntheticNamedExports::Bool(false) => None,
+      SyntheticNamedExports::Name(name) => Some(name.as_str().into()),
+    }
+  }
+}
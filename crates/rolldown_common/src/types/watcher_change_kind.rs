@@ -0,0 +1,19 @@
+use std::fmt::Display;
+
+/// The kind of change of a watched file, which is passed to the `watch_change` hook.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum WatcherChangeKind {
+  Create,
+  Update,
+  Delete,
+}
+
+impl Display for WatcherChangeKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Create => write!(f, "create"),
+      Self::Update => write!(f, "update"),
+      Self::Delete => write!(f, "delete"),
+    }
+  }
+}
@@ -1,32 +1,394 @@
+use std::convert::Infallible;
+
 use lightningcss::{
-  stylesheet::{ParserOptions, StyleSheet},
-  traits::IntoOwned,
+  css_modules::{Config as LightningCssModulesConfig, CssModuleReference, Pattern},
+  rules::{import::ImportRule, CssRule, CssRuleList},
+  stylesheet::{MinifyOptions, ParserOptions, PrinterOptions, StyleSheet},
+  values::url::Url,
+  visit_types,
+  visitor::{Visit, VisitTypes, Visitor},
 };
-
-use crate::css_ast::CssAst;
+use rolldown_sourcemap::SourceMap;
 
 pub struct CssCompiler;
 
+/// Dependencies of a CSS file that need to be resolved by the bundler.
+#[derive(Debug, Default)]
+pub struct CssDependencies {
+  /// Specifiers of the `@import` rules that should be bundled, in the order they appear.
+  pub imports: Vec<String>,
+  /// Specifiers of the `url()`s, excluding the external ones.
+  pub urls: Vec<String>,
+  /// Original names and scoped names of the identifiers in a CSS Module, sorted by original names. Scoped names
+  /// include the names composed by `composes`, separated by spaces. Composing from other files is not supported yet.
+  pub css_module_exports: Vec<(String, String)>,
+}
+
+/// Options for compiling a file as a CSS Module. The same options must be used for scanning and rendering the file,
+/// so the scoped names are consistent.
+#[derive(Debug, Clone, Copy)]
+pub struct CssModulesConfig<'a> {
+  pub pattern: &'a str,
+  /// `[hash]` in the pattern is computed from the file path relative to it.
+  pub project_root: &'a str,
+}
+
+pub struct CssRenderOptions<'a> {
+  pub sourcemap: bool,
+  /// `Some` if the file is a CSS Module.
+  pub css_modules: Option<CssModulesConfig<'a>>,
+  /// Returns the new url for the given `url()` specifier, or `None` to keep it as-is.
+  pub rewrite_url: &'a dyn Fn(&str) -> Option<String>,
+}
+
+pub struct CssRenderOutput {
+  pub code: String,
+  pub map: Option<SourceMap>,
+  /// `@import` rules that are not bundled, which must be placed at the top of the output file.
+  pub hoisted_imports: String,
+}
+
+pub struct CssMinifyOptions {
+  /// Merge rules, remove redundant declarations and shorten values.
+  pub compress: bool,
+  pub whitespace: bool,
+  pub sourcemap: bool,
+}
+
+/// Parse errors of CSS. `offset` is the byte offset in the source where the error occurs, if it's known.
+#[derive(Debug)]
+pub struct CssParseError {
+  pub message: String,
+  pub offset: Option<usize>,
+}
+
 impl CssCompiler {
-  pub fn parse(source: &str, filename: String) -> anyhow::Result<CssAst> {
-    let options = ParserOptions { filename: filename.clone(), ..Default::default() };
-    let stylesheet =
-      StyleSheet::parse(source, options.clone()).map_err(lightningcss::error::Error::into_owned)?;
-
-    let stylesheet = StyleSheet::new(
-      stylesheet.sources,
-      stylesheet.rules.into_owned(),
-      ParserOptions { filename, ..Default::default() },
-    );
-    Ok(CssAst { stylesheet })
+  pub fn scan_dependencies(
+    source: &str,
+    filename: String,
+    css_modules: Option<CssModulesConfig>,
+  ) -> Result<CssDependencies, CssParseError> {
+    let parser_options = parser_options(filename, css_modules)
+      .map_err(|message| CssParseError { message, offset: None })?;
+    let mut stylesheet =
+      StyleSheet::parse(source, parser_options).map_err(|err| CssParseError {
+        message: err.kind.to_string(),
+        offset: err.loc.map(|loc| line_column_to_offset(source, loc.line, loc.column)),
+      })?;
+
+    let imports = stylesheet
+      .rules
+      .0
+      .iter()
+      .filter_map(|rule| match rule {
+        CssRule::Import(rule) if is_bundled_import(rule) => Some(rule.url.to_string()),
+        _ => None,
+      })
+      .collect();
+
+    let mut urls = vec![];
+    visit_urls(&mut stylesheet, |url| {
+      if !is_external_url(&url.url) {
+        urls.push(url.url.to_string());
+      }
+    });
+
+    // Scoped names are generated while printing.
+    let mut css_module_exports = vec![];
+    if let Some(css_modules) = css_modules {
+      let ret = stylesheet
+        .to_css(PrinterOptions {
+          project_root: Some(css_modules.project_root),
+          ..Default::default()
+        })
+        .map_err(|err| CssParseError {
+          message: err.kind.to_string(),
+          offset: err.loc.map(|loc| line_column_to_offset(source, loc.line, loc.column)),
+        })?;
+      css_module_exports = ret
+        .exports
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, export)| {
+          let mut value = export.name;
+          for reference in export.composes {
+            if let CssModuleReference::Local { name } | CssModuleReference::Global { name } =
+              reference
+            {
+              value.push(' ');
+              value.push_str(&name);
+            }
+          }
+          (name, value)
+        })
+        .collect();
+      css_module_exports.sort_unstable();
+    }
+
+    Ok(CssDependencies { imports, urls, css_module_exports })
+  }
+
+  /// Print the CSS file for the output. Bundled `@import`s are removed, since the imported files are placed before
+  /// the importer in the output.
+  pub fn render(
+    source: &str,
+    filename: &str,
+    options: &CssRenderOptions,
+  ) -> anyhow::Result<CssRenderOutput> {
+    let parser_options =
+      parser_options(filename.to_string(), options.css_modules).map_err(anyhow::Error::msg)?;
+    let mut stylesheet =
+      StyleSheet::parse(source, parser_options).map_err(lightningcss::error::Error::into_owned)?;
+
+    let mut hoisted_rules = vec![];
+    stylesheet.rules.0.retain(|rule| match rule {
+      CssRule::Import(import) => {
+        if !is_bundled_import(import) {
+          hoisted_rules.push(rule.clone());
+        }
+        false
+      }
+      _ => true,
+    });
+
+    visit_urls(&mut stylesheet, |url| {
+      if let Some(rewritten) = (options.rewrite_url)(&url.url) {
+        url.url = rewritten.into();
+      }
+    });
+
+    let (code, map) = print(
+      &stylesheet,
+      source,
+      filename,
+      options.sourcemap,
+      false,
+      options.css_modules.map(|css_modules| css_modules.project_root),
+    )?;
+
+    let hoisted_imports = if hoisted_rules.is_empty() {
+      String::new()
+    } else {
+      StyleSheet::new(
+        vec![filename.to_string()],
+        CssRuleList(hoisted_rules),
+        ParserOptions::default(),
+      )
+      .to_css(PrinterOptions::default())
+      .map_err(|err| anyhow::format_err!("Failed to print {filename}: {err}"))?
+      .code
+    };
+
+    Ok(CssRenderOutput { code, map, hoisted_imports })
+  }
+
+  pub fn minify(
+    source: &str,
+    filename: &str,
+    options: &CssMinifyOptions,
+  ) -> anyhow::Result<(String, Option<SourceMap>)> {
+    let mut stylesheet = StyleSheet::parse(
+      source,
+      ParserOptions { filename: filename.to_string(), ..Default::default() },
+    )
+    .map_err(lightningcss::error::Error::into_owned)?;
+    if options.compress {
+      stylesheet
+        .minify(MinifyOptions::default())
+        .map_err(|err| anyhow::format_err!("Failed to minify {filename}: {err}"))?;
+    }
+    print(&stylesheet, source, filename, options.sourcemap, options.whitespace, None)
   }
 }
 
 #[test]
 fn basic_test() {
-  use lightningcss::printer::PrinterOptions;
-  let ast = CssCompiler::parse(".bar { color: green; }", "Noop".to_string()).unwrap();
-  let res = ast.stylesheet.to_css(PrinterOptions::default()).unwrap();
+  let res = CssCompiler::render(
+    ".bar { color: green; }",
+    "Noop",
+    &CssRenderOptions { sourcemap: false, css_modules: None, rewrite_url: &|_| None },
+  )
+  .unwrap();
 
   assert_eq!(res.code, ".bar {\n  color: green;\n}\n");
 }
+
+fn print(
+  stylesheet: &StyleSheet,
+  source: &str,
+  filename: &str,
+  sourcemap: bool,
+  minify_whitespace: bool,
+  project_root: Option<&str>,
+) -> anyhow::Result<(String, Option<SourceMap>)> {
+  let mut source_map = if sourcemap {
+    let mut source_map = parcel_sourcemap::SourceMap::new("/");
+    source_map.add_source(filename);
+    source_map
+      .set_source_content(0, source)
+      .map_err(|err| anyhow::format_err!("Failed to generate sourcemap for {filename}: {err:?}"))?;
+    Some(source_map)
+  } else {
+    None
+  };
+
+  let ret = stylesheet
+    .to_css(PrinterOptions {
+      minify: minify_whitespace,
+      source_map: source_map.as_mut(),
+      project_root,
+      ..Default::default()
+    })
+    .map_err(|err| anyhow::format_err!("Failed to print {filename}: {err}"))?;
+
+  let map = source_map
+    .map(|mut source_map| {
+      let json = source_map.to_json(None).map_err(|err| {
+        anyhow::format_err!("Failed to generate sourcemap for {filename}: {err:?}")
+      })?;
+      SourceMap::from_json_string(&json)
+        .map_err(|err| anyhow::format_err!("Failed to generate sourcemap for {filename}: {err:?}"))
+    })
+    .transpose()?;
+
+  Ok((ret.code, map))
+}
+
+fn parser_options<'i>(
+  filename: String,
+  css_modules: Option<CssModulesConfig<'_>>,
+) -> Result<ParserOptions<'_, 'i>, String> {
+  let css_modules = css_modules
+    .map(|css_modules| {
+      Pattern::parse(css_modules.pattern)
+        .map(|pattern| LightningCssModulesConfig { pattern, ..Default::default() })
+        .map_err(|err| format!("Invalid CSS Modules pattern {:?}: {err}", css_modules.pattern))
+    })
+    .transpose()?;
+  Ok(ParserOptions { filename, css_modules, ..Default::default() })
+}
+
+/// Urls with a scheme (`https:`, `data:`), protocol-relative urls, root-relative urls and fragment references
+/// (`url(#gradient)`) are not handled by the bundler.
+pub fn is_external_url(url: &str) -> bool {
+  let has_scheme = url.split_once(':').map_or(false, |(scheme, _)| {
+    scheme.len() > 1 && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+  });
+  url.is_empty() || has_scheme || url.starts_with('/') || url.starts_with('#')
+}
+
+/// `@import`s with conditions (`media`, `supports` or `layer`) can't be simply inlined, so they are kept.
+fn is_bundled_import(rule: &ImportRule) -> bool {
+  rule.media.media_queries.is_empty()
+    && rule.supports.is_none()
+    && rule.layer.is_none()
+    && !is_external_url(&rule.url)
+}
+
+fn visit_urls<'i>(stylesheet: &mut StyleSheet<'i, '_>, f: impl FnMut(&mut Url<'i>)) {
+  struct UrlVisitor<F>(F);
+
+  impl<'i, F: FnMut(&mut Url<'i>)> Visitor<'i> for UrlVisitor<F> {
+    type Error = Infallible;
+
+    fn visit_types(&self) -> VisitTypes {
+      visit_types!(URLS)
+    }
+
+    fn visit_url(&mut self, url: &mut Url<'i>) -> Result<(), Self::Error> {
+      (self.0)(url);
+      Ok(())
+    }
+  }
+
+  stylesheet.visit(&mut UrlVisitor(f)).unwrap_or_else(|never| match never {});
+}
+
+/// `line` is 0-based and `column` is 1-based in locations reported by lightningcss.
+fn line_column_to_offset(source: &str, line: u32, column: u32) -> usize {
+  let line_start = source.split_inclusive('\n').take(line as usize).map(str::len).sum::<usize>();
+  let line_len = source[line_start..].find('\n').unwrap_or(source.len() - line_start);
+  line_start + (column.saturating_sub(1) as usize).min(line_len)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_scan_dependencies() {
+    let deps = CssCompiler::scan_dependencies(
+      "@import './a.css';\n@import 'b.css' screen;\n@import 'https://example.com/c.css';\n.foo { background: url(./foo.png); mask: url(#mask); }",
+      "test.css".to_string(),
+      None,
+    )
+    .unwrap();
+    assert_eq!(deps.imports, vec!["./a.css"]);
+    assert_eq!(deps.urls, vec!["./foo.png"]);
+  }
+
+  #[test]
+  fn test_render() {
+    let ret = CssCompiler::render(
+      "@import './a.css';\n@import 'b.css' screen;\n.foo { background: url(./foo.png); }",
+      "test.css",
+      &CssRenderOptions {
+        sourcemap: false,
+        css_modules: None,
+        rewrite_url: &|url| (url == "./foo.png").then(|| "assets/foo.png".to_string()),
+      },
+    )
+    .unwrap();
+    assert_eq!(ret.code, ".foo {\n  background: url(\"assets/foo.png\");\n}\n");
+    assert_eq!(ret.hoisted_imports, "@import \"b.css\" screen;\n");
+  }
+
+  #[test]
+  fn test_css_modules() {
+    let css_modules = CssModulesConfig { pattern: "[name]_[local]", project_root: "/" };
+    let source = ".foo { color: red; }\n.bar { composes: foo; color: blue; }";
+    let deps =
+      CssCompiler::scan_dependencies(source, "/a.css".to_string(), Some(css_modules)).unwrap();
+    assert_eq!(
+      deps.css_module_exports,
+      vec![
+        ("bar".to_string(), "a_bar a_foo".to_string()),
+        ("foo".to_string(), "a_foo".to_string())
+      ]
+    );
+
+    let ret = CssCompiler::render(
+      source,
+      "/a.css",
+      &CssRenderOptions {
+        sourcemap: false,
+        css_modules: Some(css_modules),
+        rewrite_url: &|_| None,
+      },
+    )
+    .unwrap();
+    assert_eq!(ret.code, ".a_foo {\n  color: red;\n}\n\n.a_bar {\n  color: #00f;\n}\n");
+  }
+
+  #[test]
+  fn test_minify() {
+    let source = ".foo { color: red; }\n.bar { color: red; }";
+    let options = CssMinifyOptions { compress: true, whitespace: true, sourcemap: false };
+    let (code, _) = CssCompiler::minify(source, "test.css", &options).unwrap();
+    assert_eq!(code, ".foo,.bar{color:red}");
+
+    let options = CssMinifyOptions { compress: false, whitespace: true, sourcemap: false };
+    let (code, _) = CssCompiler::minify(source, "test.css", &options).unwrap();
+    assert_eq!(code, ".foo{color:red}.bar{color:red}");
+  }
+
+  #[test]
+  fn test_is_external_url() {
+    assert!(is_external_url("https://example.com/a.png"));
+    assert!(is_external_url("data:image/png;base64,AAAA"));
+    assert!(is_external_url("//example.com/a.png"));
+    assert!(is_external_url("#gradient"));
+    assert!(!is_external_url("./a.png"));
+    assert!(!is_external_url("a.png"));
+  }
+}
@@ -1,4 +1,10 @@
 mod css_ast;
 mod css_compiler;
 
-pub use crate::{css_ast::CssAst, css_compiler::CssCompiler};
+pub use crate::{
+  css_ast::CssAst,
+  css_compiler::{
+    is_external_url, CssCompiler, CssDependencies, CssMinifyOptions, CssModulesConfig,
+    CssParseError, CssRenderOptions, CssRenderOutput,
+  },
+};
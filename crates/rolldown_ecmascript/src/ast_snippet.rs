@@ -635,6 +635,137 @@ impl<'ast> AstSnippet<'ast> {
     )
   }
 
+  /// `self.location.href`
+  pub fn worker_location_url_expr(&self) -> Expression<'ast> {
+    self.builder.expression_member(self.builder.member_expression_static(
+      SPAN,
+      self.literal_prop_access_member_expr_expr("self", "location"),
+      self.id_name("href", SPAN),
+      false,
+    ))
+  }
+
+  /// `new URL([file_name], document.baseURI).href`
+  ///
+  /// `file_name` is the path of the chunk relative to the output directory.
+  pub fn document_base_url_expr(&self, file_name: &str) -> Expression<'ast> {
+    let url = self.builder.expression_new(
+      SPAN,
+      self.id_ref_expr("URL", SPAN),
+      self.builder.vec_from_iter([
+        self.builder.argument_expression(self.builder.expression_string_literal(SPAN, file_name)),
+        self
+          .builder
+          .argument_expression(self.literal_prop_access_member_expr_expr("document", "baseURI")),
+      ]),
+      NONE,
+    );
+    self.builder.expression_member(self.builder.member_expression_static(
+      SPAN,
+      url,
+      self.id_name("href", SPAN),
+      false,
+    ))
+  }
+
+  /// `[current_script] && [current_script].tagName.toUpperCase() === 'SCRIPT' && [current_script].src || [document_base_url_expr]`
+  ///
+  /// `current_script` is the binding of `document.currentScript` captured while the script is evaluated, since it's
+  /// `null` in callbacks. The chunk is resolved against `document.baseURI` if it's not loaded by a `<script>` tag.
+  pub fn document_script_url_expr(
+    &self,
+    current_script: &str,
+    file_name: &str,
+  ) -> Expression<'ast> {
+    let upper_tag_name = self.builder.expression_call(
+      SPAN,
+      self.builder.expression_member(self.builder.member_expression_static(
+        SPAN,
+        self.literal_prop_access_member_expr_expr(current_script, "tagName"),
+        self.id_name("toUpperCase", SPAN),
+        false,
+      )),
+      NONE,
+      self.builder.vec(),
+      false,
+    );
+    let is_script = self.builder.expression_binary(
+      SPAN,
+      upper_tag_name,
+      ast::BinaryOperator::StrictEquality,
+      self.builder.expression_string_literal(SPAN, "SCRIPT"),
+    );
+    let script_src = self.builder.expression_logical(
+      SPAN,
+      self.builder.expression_logical(
+        SPAN,
+        self.id_ref_expr(current_script, SPAN),
+        ast::LogicalOperator::And,
+        is_script,
+      ),
+      ast::LogicalOperator::And,
+      self.literal_prop_access_member_expr_expr(current_script, "src"),
+    );
+    self.builder.expression_logical(
+      SPAN,
+      script_src,
+      ast::LogicalOperator::Or,
+      self.document_base_url_expr(file_name),
+    )
+  }
+
+  /// `typeof document === 'undefined' ? require('u' + 'rl').pathToFileURL(__filename).href : [document_script_url_expr]`
+  ///
+  /// The module name is split, so bundlers that consume the output don't try to bundle `url` for browsers.
+  pub fn cjs_file_url_expr(&self, current_script: &str, file_name: &str) -> Expression<'ast> {
+    let require_url = self.builder.expression_call(
+      SPAN,
+      self.id_ref_expr("require", SPAN),
+      NONE,
+      self.builder.vec1(self.builder.argument_expression(self.builder.expression_binary(
+        SPAN,
+        self.builder.expression_string_literal(SPAN, "u"),
+        ast::BinaryOperator::Addition,
+        self.builder.expression_string_literal(SPAN, "rl"),
+      ))),
+      false,
+    );
+    let path_to_file_url = self.builder.expression_call(
+      SPAN,
+      self.builder.expression_member(self.builder.member_expression_static(
+        SPAN,
+        require_url,
+        self.id_name("pathToFileURL", SPAN),
+        false,
+      )),
+      NONE,
+      self.builder.vec1(self.builder.argument_expression(self.id_ref_expr("__filename", SPAN))),
+      false,
+    );
+    let node_file_url = self.builder.expression_member(self.builder.member_expression_static(
+      SPAN,
+      path_to_file_url,
+      self.id_name("href", SPAN),
+      false,
+    ));
+    let is_node = self.builder.expression_binary(
+      SPAN,
+      self.builder.expression_unary(
+        SPAN,
+        ast::UnaryOperator::Typeof,
+        self.id_ref_expr("document", SPAN),
+      ),
+      ast::BinaryOperator::StrictEquality,
+      self.builder.expression_string_literal(SPAN, "undefined"),
+    );
+    self.builder.expression_conditional(
+      SPAN,
+      is_node,
+      node_file_url,
+      self.document_script_url_expr(current_script, file_name),
+    )
+  }
+
   /// var [assignee] = require([source]);
   pub fn variable_declarator_require_call_stmt(
     &self,
@@ -708,6 +839,87 @@ impl<'ast> AstSnippet<'ast> {
     )
   }
 
+  /// `new Promise((resolve, reject) => require([source], (m) => resolve(__toESM(m)), reject))`
+  ///
+  /// If `to_esm` is `None`, the module is passed to `resolve` as it is. `require` is the local one passed to the
+  /// factory function of `define`, so `source` is resolved relative to the chunk.
+  pub fn amd_dynamic_require_expr(
+    &self,
+    span: Span,
+    source: PassedStr,
+    to_esm: Option<(PassedStr, Interop)>,
+  ) -> ast::Expression<'ast> {
+    let on_load = match to_esm {
+      Some((to_esm_fn_name, interop)) => self.arrow_expr(
+        &["m"],
+        self.call_expr_with_arg_expr_expr(
+          "resolve",
+          self.to_esm_call_with_interop(to_esm_fn_name, self.id_ref_expr("m", SPAN), Some(interop)),
+        ),
+      ),
+      None => self.id_ref_expr("resolve", SPAN),
+    };
+    let require_call = self.builder.expression_call(
+      SPAN,
+      self.id_ref_expr("require", SPAN),
+      NONE,
+      self.builder.vec_from_iter([
+        Argument::from(self.builder.expression_array(
+          SPAN,
+          self.builder.vec1(self.builder.array_expression_element_expression(
+            self.builder.expression_string_literal(SPAN, source),
+          )),
+          None,
+        )),
+        Argument::from(on_load),
+        Argument::from(self.id_ref_expr("reject", SPAN)),
+      ]),
+      false,
+    );
+    self.builder.expression_new(
+      span,
+      self.id_ref_expr("Promise", SPAN),
+      self.builder.vec1(Argument::from(self.arrow_expr(&["resolve", "reject"], require_call))),
+      NONE,
+    )
+  }
+
+  /// `(params) => expr`
+  fn arrow_expr(&self, params: &[PassedStr], expr: ast::Expression<'ast>) -> ast::Expression<'ast> {
+    let params = self.builder.vec_from_iter(params.iter().map(|name| {
+      self.builder.formal_parameter(
+        SPAN,
+        self.builder.vec(),
+        self.builder.binding_pattern(
+          self.builder.binding_pattern_kind_binding_identifier(SPAN, *name),
+          NONE,
+          false,
+        ),
+        None,
+        false,
+        false,
+      )
+    }));
+    self.builder.expression_arrow_function(
+      SPAN,
+      true,
+      false,
+      NONE,
+      self.builder.formal_parameters(
+        SPAN,
+        ast::FormalParameterKind::ArrowFormalParameters,
+        params,
+        NONE,
+      ),
+      NONE,
+      self.builder.function_body(
+        SPAN,
+        self.builder.vec(),
+        self.builder.vec1(self.builder.statement_expression(SPAN, expr)),
+      ),
+    )
+  }
+
   // return xxx
   pub fn return_stmt(&self, argument: ast::Expression<'ast>) -> ast::Statement<'ast> {
     ast::Statement::ReturnStatement(
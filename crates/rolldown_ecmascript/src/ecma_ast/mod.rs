@@ -2,10 +2,11 @@ use std::fmt::Debug;
 
 use crate::EcmaCompiler;
 use arcstr::ArcStr;
+use oxc::allocator::CloneIn;
 use oxc::ast::Trivias;
 use oxc::{allocator::Allocator, ast::ast::Program, span::SourceType};
 
-use self::program_cell::ProgramCell;
+use self::program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner};
 
 mod gen;
 mod helpers;
@@ -33,6 +34,23 @@ impl EcmaAst {
   pub fn program(&self) -> &Program {
     &self.program.borrow_dependent().program
   }
+
+  /// Deeply clone the AST into a new allocator. The semantic ids (symbol ids, reference ids and scope ids)
+  /// stored in the AST are kept, so the cloned AST is still valid for the `SymbolTable` and `ScopeTree`
+  /// created from the original one.
+  #[must_use]
+  pub fn clone_with_another_arena(&self) -> Self {
+    let owner = ProgramCellOwner { source: self.source().clone(), allocator: Allocator::default() };
+    let program = ProgramCell::new(owner, |owner| ProgramCellDependent {
+      program: self.program().clone_in(&owner.allocator),
+    });
+    Self {
+      program,
+      trivias: self.trivias.clone(),
+      source_type: self.source_type,
+      contains_use_strict: self.contains_use_strict,
+    }
+  }
 }
 
 impl Debug for EcmaAst {
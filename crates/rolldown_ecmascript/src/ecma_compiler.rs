@@ -1,17 +1,23 @@
 use arcstr::ArcStr;
 use oxc::{
   allocator::Allocator,
+  ast::VisitMut,
   codegen::{CodeGenerator, Codegen, CodegenOptions, CodegenReturn},
-  minifier::{Minifier, MinifierOptions},
+  mangler::Mangler,
+  minifier::{CompressOptions, Compressor},
   parser::{ParseOptions, Parser},
   sourcemap::SourceMap,
   span::SourceType,
 };
+use rolldown_common::NormalizedMinifyOptions;
 use rolldown_error::{BuildDiagnostic, DiagnosableResult};
 
-use crate::ecma_ast::{
-  program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner},
-  EcmaAst,
+use crate::{
+  ecma_ast::{
+    program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner},
+    EcmaAst,
+  },
+  minify_helpers::{mangle_keeping_names, DropStatements},
 };
 pub struct EcmaCompiler;
 
@@ -76,15 +82,36 @@ impl EcmaCompiler {
     source_text: &str,
     enable_sourcemap: bool,
     filename: &str,
+    options: &NormalizedMinifyOptions,
   ) -> anyhow::Result<(String, Option<SourceMap>)> {
     let allocator = Allocator::default();
     let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
     let program = allocator.alloc(program);
-    let options = MinifierOptions { mangle: true, ..MinifierOptions::default() };
-    let ret = Minifier::new(options).build(&allocator, program);
+    if options.compress {
+      let compress = CompressOptions {
+        drop_console: options.drop_console,
+        drop_debugger: options.drop_debugger,
+        ..CompressOptions::default()
+      };
+      for _ in 0..options.compress_passes {
+        Compressor::new(&allocator, compress).build(program);
+      }
+    } else if options.drop_console || options.drop_debugger {
+      DropStatements { drop_console: options.drop_console, drop_debugger: options.drop_debugger }
+        .visit_program(program);
+    }
+    let mangler = if options.mangle && options.keep_names {
+      // The names are applied to the AST directly, so the codegen doesn't need the mangler.
+      mangle_keeping_names(&allocator, program);
+      None
+    } else if options.mangle {
+      Some(Mangler::new().build(program))
+    } else {
+      None
+    };
     let codegen = Codegen::new()
-      .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
-      .with_mangler(ret.mangler);
+      .with_options(CodegenOptions { minify: options.whitespace, ..CodegenOptions::default() })
+      .with_mangler(mangler);
     let codegen =
       if enable_sourcemap { codegen.enable_source_map(filename, source_text) } else { codegen };
 
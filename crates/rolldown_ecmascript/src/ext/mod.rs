@@ -258,6 +258,8 @@ pub trait ExpressionExt<'ast> {
 
   fn as_identifier(&self) -> Option<&ast::IdentifierReference<'ast>>;
   fn as_identifier_mut(&mut self) -> Option<&mut ast::IdentifierReference<'ast>>;
+
+  fn is_import_meta(&self) -> bool;
 }
 
 impl<'ast> ExpressionExt<'ast> for ast::Expression<'ast> {
@@ -292,4 +294,8 @@ impl<'ast> ExpressionExt<'ast> for ast::Expression<'ast> {
       None
     }
   }
+
+  fn is_import_meta(&self) -> bool {
+    matches!(self, ast::Expression::MetaProperty(meta) if meta.meta.name == "import" && meta.property.name == "meta")
+  }
 }
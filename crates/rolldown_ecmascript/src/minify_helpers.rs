@@ -0,0 +1,156 @@
+use oxc::{
+  allocator::{Allocator, Vec},
+  ast::{
+    ast::{
+      BindingIdentifier, BindingPatternKind, Class, Expression, Function, IdentifierReference,
+      Program, Statement, VariableDeclarator,
+    },
+    visit::{walk, walk_mut},
+    Visit, VisitMut,
+  },
+  mangler::Mangler,
+  semantic::{ScopeFlags, SemanticBuilder, SymbolId, SymbolTable},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Removes `console.*()` calls and `debugger` statements, like the compressor does with `drop_console` and
+/// `drop_debugger`. Used while the compressor is disabled.
+pub struct DropStatements {
+  pub drop_console: bool,
+  pub drop_debugger: bool,
+}
+
+impl DropStatements {
+  fn should_drop(&self, stmt: &Statement) -> bool {
+    match stmt {
+      Statement::DebuggerStatement(_) => self.drop_debugger,
+      Statement::ExpressionStatement(stmt) => {
+        self.drop_console && is_console_call(&stmt.expression)
+      }
+      _ => false,
+    }
+  }
+}
+
+fn is_console_call(expr: &Expression) -> bool {
+  let Expression::CallExpression(call_expr) = expr else {
+    return false;
+  };
+  call_expr.callee.as_member_expression().is_some_and(|member_expr| {
+    member_expr.object().get_identifier_reference().is_some_and(|ident| ident.name == "console")
+  })
+}
+
+impl<'a> VisitMut<'a> for DropStatements {
+  fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+    stmts.retain(|stmt| !self.should_drop(stmt));
+    walk_mut::walk_statements(self, stmts);
+  }
+}
+
+/// Renames the symbols of `program` to the names assigned by the mangler, except the names of functions and classes,
+/// and of variables initialized with anonymous ones, so `Function.prototype.name` still works.
+///
+/// Symbols mangled to a kept name are renamed to unused names instead, so they don't shadow or get shadowed by the
+/// kept ones.
+pub fn mangle_keeping_names<'a>(allocator: &'a Allocator, program: &mut Program<'a>) {
+  let semantic = SemanticBuilder::new("").build(program).semantic;
+  let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+  // The mangler builds the semantic of the same program again, so the `SymbolId`s are the same.
+  let mangler = Mangler::new().build(program);
+
+  let mut collector = NamedSymbolsCollector { kept: FxHashSet::default() };
+  collector.visit_program(program);
+  let kept_names =
+    collector.kept.iter().map(|symbol_id| symbols.get_name(*symbol_id)).collect::<FxHashSet<_>>();
+
+  let mut used_names = symbols
+    .symbol_ids()
+    .map(|symbol_id| mangler.get_symbol_name(symbol_id).to_string())
+    .chain(kept_names.iter().map(ToString::to_string))
+    .chain(scopes.root_unresolved_references().keys().map(ToString::to_string))
+    .collect::<FxHashSet<_>>();
+  let names = symbols
+    .symbol_ids()
+    .map(|symbol_id| {
+      let name = if collector.kept.contains(&symbol_id) {
+        symbols.get_name(symbol_id)
+      } else {
+        mangler.get_symbol_name(symbol_id)
+      };
+      let name = if !collector.kept.contains(&symbol_id) && kept_names.contains(name) {
+        let mut i = 1;
+        while used_names.contains(&format!("{name}${i}")) {
+          i += 1;
+        }
+        let candidate = format!("{name}${i}");
+        used_names.insert(candidate.clone());
+        candidate
+      } else {
+        name.to_string()
+      };
+      (symbol_id, &*allocator.alloc_str(&name))
+    })
+    .collect::<FxHashMap<_, _>>();
+
+  MangledNamesApplier { symbols: &symbols, names }.visit_program(program);
+}
+
+/// Collects the symbols whose names are observable through `Function.prototype.name`.
+struct NamedSymbolsCollector {
+  kept: FxHashSet<SymbolId>,
+}
+
+impl<'a> Visit<'a> for NamedSymbolsCollector {
+  fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+    if let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) {
+      self.kept.insert(symbol_id);
+    }
+    walk::walk_function(self, func, flags);
+  }
+
+  fn visit_class(&mut self, class: &Class<'a>) {
+    if let Some(symbol_id) = class.id.as_ref().and_then(|id| id.symbol_id.get()) {
+      self.kept.insert(symbol_id);
+    }
+    walk::walk_class(self, class);
+  }
+
+  fn visit_variable_declarator(&mut self, decl: &VariableDeclarator<'a>) {
+    let is_anonymous = match &decl.init {
+      Some(Expression::ArrowFunctionExpression(_)) => true,
+      Some(Expression::FunctionExpression(func)) => func.id.is_none(),
+      Some(Expression::ClassExpression(class)) => class.id.is_none(),
+      _ => false,
+    };
+    if let (true, BindingPatternKind::BindingIdentifier(id)) = (is_anonymous, &decl.id.kind) {
+      if let Some(symbol_id) = id.symbol_id.get() {
+        self.kept.insert(symbol_id);
+      }
+    }
+    walk::walk_variable_declarator(self, decl);
+  }
+}
+
+struct MangledNamesApplier<'a, 'me> {
+  symbols: &'me SymbolTable,
+  names: FxHashMap<SymbolId, &'a str>,
+}
+
+impl<'a, 'me> VisitMut<'a> for MangledNamesApplier<'a, 'me> {
+  fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+    if let Some(name) = ident.symbol_id.get().and_then(|symbol_id| self.names.get(&symbol_id)) {
+      ident.name = (*name).into();
+    }
+  }
+
+  fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+    let symbol_id = ident
+      .reference_id
+      .get()
+      .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id());
+    if let Some(name) = symbol_id.and_then(|symbol_id| self.names.get(&symbol_id)) {
+      ident.name = (*name).into();
+    }
+  }
+}
@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use super::BuildDiagnostic;
+use super::{severity::Severity, BuildDiagnostic};
 use arcstr::ArcStr;
 use oxc::{diagnostics::LabeledSpan, span::Span};
 use rolldown_resolver::ResolveError;
@@ -10,6 +10,7 @@ use crate::events::illegal_identifier_as_name::IllegalIdentifierAsName;
 use crate::events::invalid_option::{InvalidOption, InvalidOptionTypes};
 use crate::events::missing_global_name::MissingGlobalName;
 use crate::events::missing_name_option_for_iife_export::MissingNameOptionForIifeExport;
+use crate::events::missing_name_option_for_umd_export::MissingNameOptionForUmdExport;
 use crate::events::resolve_error::DiagnosableResolveError;
 use crate::events::unloadable_dependency::{UnloadableDependency, UnloadableDependencyContext};
 use crate::events::{
@@ -23,10 +24,14 @@ use crate::events::{
   missing_export::MissingExport,
   mixed_export::MixedExport,
   parse_error::ParseError,
+  plugin_log::{PluginLog, PluginLogLocation},
   sourcemap_error::SourceMapError,
+  synthetic_named_exports_need_namespace_export::SyntheticNamedExportsNeedNamespaceExport,
+  unexpected_named_import::UnexpectedNamedImport,
   unresolved_entry::UnresolvedEntry,
   unresolved_import::UnresolvedImport,
   unresolved_import_treated_as_external::UnresolvedImportTreatedAsExternal,
+  unsupported_import_meta_property::UnsupportedImportMetaProperty,
   NapiError,
 };
 
@@ -117,6 +122,28 @@ impl BuildDiagnostic {
     })
   }
 
+  pub fn synthetic_named_exports_need_namespace_export(
+    stable_id: String,
+    fallback_export_name: String,
+  ) -> Self {
+    Self::new_inner(SyntheticNamedExportsNeedNamespaceExport { stable_id, fallback_export_name })
+  }
+
+  pub fn unexpected_named_import(module_name: ArcStr, imported: ArcStr) -> Self {
+    Self::new_inner(UnexpectedNamedImport { module_name, imported })
+  }
+
+  pub fn plugin_log(
+    severity: Severity,
+    plugin: String,
+    message: String,
+    id: Option<String>,
+    location: Option<(ArcStr, Span)>,
+  ) -> Self {
+    let location = location.map(|(source, span)| PluginLogLocation { source, span });
+    Self::new_inner(PluginLog { severity, plugin, message, id, location }).with_severity(severity)
+  }
+
   pub fn mixed_export(module_name: ArcStr, entry_module: ArcStr, export_keys: Vec<ArcStr>) -> Self {
     Self::new_inner(MixedExport { module_name, entry_module, export_keys })
   }
@@ -129,6 +156,10 @@ impl BuildDiagnostic {
     Self::new_inner(MissingNameOptionForIifeExport {})
   }
 
+  pub fn missing_name_option_for_umd_export() -> Self {
+    Self::new_inner(MissingNameOptionForUmdExport {})
+  }
+
   pub fn illegal_identifier_as_name(identifier_name: ArcStr) -> Self {
     Self::new_inner(IllegalIdentifierAsName { identifier_name })
   }
@@ -197,4 +228,14 @@ impl BuildDiagnostic {
   ) -> Self {
     Self::new_inner(ExportUndefinedVariable { filename, source, span, name })
   }
+
+  pub fn unsupported_import_meta_property(
+    filename: String,
+    source: ArcStr,
+    span: Span,
+    property: ArcStr,
+    format: ArcStr,
+  ) -> Self {
+    Self::new_inner(UnsupportedImportMetaProperty { filename, source, span, property, format })
+  }
 }
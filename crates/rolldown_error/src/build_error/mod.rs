@@ -26,6 +26,13 @@ impl Display for BuildDiagnostic {
   }
 }
 
+/// Allows `BuildDiagnostic`s to be passed through `anyhow::Error`, such as the ones created by plugins.
+impl std::error::Error for BuildDiagnostic {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    self.source.as_deref().map(|source| source as &(dyn std::error::Error + 'static))
+  }
+}
+
 impl BuildDiagnostic {
   pub fn kind(&self) -> crate::event_kind::EventKind {
     self.inner.kind()
@@ -46,6 +53,20 @@ impl BuildDiagnostic {
     self
   }
 
+  #[must_use]
+  pub fn with_severity(mut self, severity: Severity) -> Self {
+    self.severity = severity;
+    self
+  }
+
+  pub fn severity(&self) -> Severity {
+    self.severity
+  }
+
+  pub fn to_message_with(&self, opts: &DiagnosticOptions) -> String {
+    self.inner.message(opts)
+  }
+
   pub fn into_diagnostic(self) -> Diagnostic {
     self.into_diagnostic_with(&DiagnosticOptions::default())
   }
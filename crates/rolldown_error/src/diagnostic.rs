@@ -1,4 +1,4 @@
-use crate::build_error::severity::Severity;
+use crate::{build_error::severity::Severity, structured_diagnostic::StructuredDiagnostic};
 use arcstr::ArcStr;
 use ariadne::{sources, Config, Label, Report, ReportBuilder, ReportKind};
 use std::{fmt::Display, ops::Range};
@@ -6,12 +6,19 @@ use std::{fmt::Display, ops::Range};
 #[derive(Debug, Clone)]
 pub struct DiagnosticFileId(ArcStr);
 
+#[derive(Debug, Clone)]
+pub(crate) struct DiagnosticLabel {
+  pub(crate) file: ArcStr,
+  pub(crate) range: Range<usize>,
+  pub(crate) message: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct Diagnostic {
   pub(crate) kind: String,
   pub(crate) title: String,
   pub(crate) files: Vec<(/* filename */ ArcStr, /* file content */ ArcStr)>,
-  pub(crate) labels: Vec<Label<(/* filename */ ArcStr, Range<usize>)>>,
+  pub(crate) labels: Vec<DiagnosticLabel>,
   pub(crate) help: Option<String>,
   pub(crate) severity: Severity,
 }
@@ -56,8 +63,7 @@ impl Diagnostic {
   ) -> &mut Self {
     let range = range.into();
     let range = range.start as usize..range.end as usize;
-    let label = Label::new((file_id.0.clone(), range)).with_message(message);
-    self.labels.push(label);
+    self.labels.push(DiagnosticLabel { file: file_id.0.clone(), range, message });
     self
   }
 
@@ -66,6 +72,7 @@ impl Diagnostic {
       match self.severity {
         Severity::Error => ReportKind::Error,
         Severity::Warning => ReportKind::Warning,
+        Severity::Info | Severity::Debug => ReportKind::Advice,
       },
       "",
       0,
@@ -74,7 +81,8 @@ impl Diagnostic {
     .with_message(self.title.clone());
 
     for label in self.labels.clone() {
-      builder = builder.with_label(label);
+      builder =
+        builder.with_label(Label::new((label.file, label.range)).with_message(label.message));
     }
 
     if let Some(help) = &self.help {
@@ -98,6 +106,12 @@ impl Diagnostic {
   pub fn to_color_string(&self) -> String {
     self.convert_to_string(true)
   }
+
+  /// Returns a machine-readable view of this diagnostic, which could be serialized to JSON, converted to a LSP
+  /// `Diagnostic` or printed as a GitHub Actions annotation.
+  pub fn to_structured(&self) -> StructuredDiagnostic {
+    StructuredDiagnostic::from_diagnostic(self)
+  }
 }
 
 impl Display for Diagnostic {
@@ -17,10 +17,16 @@ pub enum EventKind {
   SourcemapError,
   MissingExport,
   InvalidExportOption,
+  SyntheticNamedExportsNeedNamespaceExport,
+  UnexpectedNamedImport,
+  PluginError,
+  PluginWarning,
+  PluginLog,
   // --- These kinds are rolldown specific
   IllegalReassignment,
   UnloadableDependency,
   DiagnosableResolveError,
+  UnsupportedImportMetaProperty,
   // !! Only add new kind if it's not covered by the kinds from rollup !!
 
   // TODO remove following kinds
@@ -51,12 +57,20 @@ impl Display for EventKind {
       EventKind::MissingExport => write!(f, "MISSING_EXPORT"),
       EventKind::InvalidExportOption => write!(f, "INVALID_EXPORT_OPTION"),
       EventKind::InvalidOption => write!(f, "INVALID_OPTION"),
+      EventKind::SyntheticNamedExportsNeedNamespaceExport => {
+        write!(f, "SYNTHETIC_NAMED_EXPORTS_NEED_NAMESPACE_EXPORT")
+      }
+      EventKind::UnexpectedNamedImport => write!(f, "UNEXPECTED_NAMED_IMPORT"),
+      EventKind::PluginError => write!(f, "PLUGIN_ERROR"),
+      EventKind::PluginWarning => write!(f, "PLUGIN_WARNING"),
+      EventKind::PluginLog => write!(f, "PLUGIN_LOG"),
       // --- Rolldown specific
       EventKind::NapiError => write!(f, "NAPI_ERROR"),
       EventKind::IoError => write!(f, "IO_ERROR"),
       EventKind::CommonJsVariableInEsm => write!(f, "COMMONJS_VARIABLE_IN_ESM"),
       EventKind::ExportUndefinedVariable => write!(f, "EXPORT_UNDEFINED_VARIABLE"),
       EventKind::DiagnosableResolveError => write!(f, "DIAGNOSABLE_RESOLVE_ERROR"),
+      EventKind::UnsupportedImportMetaProperty => write!(f, "UNSUPPORTED_IMPORT_META_PROPERTY"),
     }
   }
 }
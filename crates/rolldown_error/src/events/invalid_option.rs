@@ -4,11 +4,19 @@ use crate::{DiagnosticOptions, EventKind};
 #[derive(Debug)]
 pub enum InvalidOptionTypes {
   UnsupportedCodeSplittingFormat,
+  UnsupportedWithPreserveModules,
+  /// The value can't be deserialized, with the reason reported by serde.
+  InvalidValue(String),
+  /// IIFE and UMD formats are used with multiple inputs, which requires code splitting.
+  UnsupportedMultipleInputsFormat(String),
+  UnsupportedMultipleInputsWithInlineDynamicImports,
+  UnsupportedWithInlineDynamicImports,
 }
 
 #[derive(Debug)]
 pub struct InvalidOption {
   pub invalid_option_types: InvalidOptionTypes,
+  /// The name of the option, or the JSON pointer to it for options validated from config files, e.g. `/input/0`.
   pub option: String,
 }
 
@@ -22,6 +30,24 @@ impl BuildEvent for InvalidOption {
       InvalidOptionTypes::UnsupportedCodeSplittingFormat => {
         format!("Invalid value \"{}\" for option \"format\". UMD and IIFE are not supported for code splitting. You may set `output.inlineDynamicImports` to `true` when using dynamic imports.", self.option)
       }
+      InvalidOptionTypes::UnsupportedWithPreserveModules => {
+        format!("Invalid value for option \"output.{}\" - this option is not supported for \"output.preserveModules\".", self.option)
+      }
+      InvalidOptionTypes::InvalidValue(reason) => {
+        format!("Invalid value for option \"{}\": {reason}.", self.option)
+      }
+      InvalidOptionTypes::UnsupportedMultipleInputsFormat(format) => {
+        format!("Invalid value \"{format}\" for option \"{}\" - multiple inputs are not supported for the IIFE and UMD formats.", self.option)
+      }
+      InvalidOptionTypes::UnsupportedMultipleInputsWithInlineDynamicImports => {
+        format!(
+          "Invalid value for option \"{}\" - this option is not supported for multiple inputs.",
+          self.option
+        )
+      }
+      InvalidOptionTypes::UnsupportedWithInlineDynamicImports => {
+        format!("Invalid value for option \"{}\" - this option is not supported for \"inlineDynamicImports\".", self.option)
+      }
     }
   }
 }
@@ -17,14 +17,19 @@ pub mod invalid_option;
 pub mod missing_export;
 pub mod missing_global_name;
 pub mod missing_name_option_for_iife_export;
+pub mod missing_name_option_for_umd_export;
 pub mod mixed_export;
 pub mod parse_error;
+pub mod plugin_log;
 pub mod resolve_error;
 pub mod sourcemap_error;
+pub mod synthetic_named_exports_need_namespace_export;
+pub mod unexpected_named_import;
 pub mod unloadable_dependency;
 pub mod unresolved_entry;
 pub mod unresolved_import;
 pub mod unresolved_import_treated_as_external;
+pub mod unsupported_import_meta_property;
 
 pub trait BuildEvent: Debug + Sync + Send {
   fn kind(&self) -> EventKind;
@@ -0,0 +1,51 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_error::severity::Severity, diagnostic::Diagnostic, event_kind::EventKind,
+  types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct PluginLogLocation {
+  pub source: ArcStr,
+  pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct PluginLog {
+  pub severity: Severity,
+  pub plugin: String,
+  pub message: String,
+  pub id: Option<String>,
+  pub location: Option<PluginLogLocation>,
+}
+
+impl BuildEvent for PluginLog {
+  fn kind(&self) -> EventKind {
+    match self.severity {
+      Severity::Error => EventKind::PluginError,
+      Severity::Warning => EventKind::PluginWarning,
+      Severity::Info | Severity::Debug => EventKind::PluginLog,
+    }
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    match (&self.id, &self.location) {
+      (Some(id), None) => {
+        format!("[plugin {}] {}: {}", self.plugin, opts.stabilize_path(id), self.message)
+      }
+      _ => format!("[plugin {}] {}", self.plugin, self.message),
+    }
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let (Some(id), Some(location)) = (&self.id, &self.location) else {
+      return;
+    };
+    let file_id = diagnostic.add_file(opts.stabilize_path(id), location.source.clone());
+    diagnostic.add_label(&file_id, location.span.start..location.span.end, String::new());
+  }
+}
@@ -0,0 +1,27 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct SyntheticNamedExportsNeedNamespaceExport {
+  pub stable_id: String,
+  pub fallback_export_name: String,
+}
+
+impl BuildEvent for SyntheticNamedExportsNeedNamespaceExport {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    EventKind::SyntheticNamedExportsNeedNamespaceExport
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    let needed_export = if self.fallback_export_name == "default" {
+      "a default export".to_string()
+    } else {
+      format!(r#"an explicit export named "{}""#, self.fallback_export_name)
+    };
+    format!(
+      r#"Module "{}" that is marked with `syntheticNamedExports` needs {needed_export} that does not reexport an unresolved named export of the same module."#,
+      self.stable_id
+    )
+  }
+}
@@ -0,0 +1,22 @@
+use crate::events::BuildEvent;
+use crate::{DiagnosticOptions, EventKind};
+use arcstr::ArcStr;
+
+#[derive(Debug)]
+pub struct UnexpectedNamedImport {
+  pub module_name: ArcStr,
+  pub imported: ArcStr,
+}
+
+impl BuildEvent for UnexpectedNamedImport {
+  fn kind(&self) -> EventKind {
+    EventKind::UnexpectedNamedImport
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"The named export "{}" was imported from the external module "{}" even though its interop type is "defaultOnly". Please switch its interop type to "default" or remove the import."#,
+      &self.imported, &self.module_name
+    )
+  }
+}
@@ -0,0 +1,47 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnsupportedImportMetaProperty {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub property: ArcStr,
+  pub format: ArcStr,
+}
+
+impl BuildEvent for UnsupportedImportMetaProperty {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::UnsupportedImportMetaProperty
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "`import.meta.{}` is not supported with the `{}` output format and will be replaced with `undefined` in '{}'.",
+      self.property,
+      self.format,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    diagnostic.title = format!(
+      "`import.meta.{}` is not supported with the `{}` output format.",
+      self.property, self.format
+    );
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "This will be replaced with `undefined`.".to_string(),
+    );
+  }
+}
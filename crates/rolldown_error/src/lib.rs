@@ -2,16 +2,21 @@ mod build_error;
 mod diagnostic;
 mod event_kind;
 mod events;
+mod structured_diagnostic;
 mod type_aliases;
 mod types;
 
 pub use crate::{
-  build_error::{BuildDiagnostic, BuildResult},
+  build_error::{severity::Severity, BuildDiagnostic, BuildResult},
   event_kind::EventKind,
   events::ambiguous_external_namespace::AmbiguousExternalNamespaceModule,
   events::commonjs_variable_in_esm::CjsExportSpan,
   events::invalid_option::InvalidOptionTypes,
   events::unloadable_dependency::UnloadableDependencyContext,
+  structured_diagnostic::{
+    DiagnosticPosition, DiagnosticRange, LspDiagnostic, LspLocation, LspPosition, LspRange,
+    LspRelatedInformation, StructuredDiagnostic, StructuredLabel,
+  },
   type_aliases::{DiagnosableResult, UnhandleableResult},
   types::diagnostic_options::DiagnosticOptions,
 };
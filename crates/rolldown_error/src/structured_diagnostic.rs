@@ -0,0 +1,306 @@
+//! Machine-readable views of [`Diagnostic`], for tools that can't consume the pretty-printed reports, such as IDE
+//! integrations and CI annotators.
+
+use std::{fmt::Write as _, ops::Range, path::Path};
+
+use serde::Serialize;
+use sugar_path::SugarPath;
+
+use crate::{build_error::severity::Severity, diagnostic::Diagnostic};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredDiagnostic {
+  /// The kind of the diagnostic, such as `UNRESOLVED_IMPORT`.
+  pub code: String,
+  pub severity: Severity,
+  pub message: String,
+  /// The file the diagnostic belongs to, which is the file of the first label if there is any.
+  pub file: Option<String>,
+  /// The range of the first label.
+  pub range: Option<DiagnosticRange>,
+  pub help: Option<String>,
+  pub labels: Vec<StructuredLabel>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredLabel {
+  pub file: String,
+  pub range: DiagnosticRange,
+  pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticRange {
+  pub start: DiagnosticPosition,
+  pub end: DiagnosticPosition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticPosition {
+  /// Byte offset from the start of the file.
+  pub offset: usize,
+  /// 0-based line.
+  pub line: usize,
+  /// 0-based column, counted in UTF-16 code units like LSP and JavaScript source maps do.
+  pub column: usize,
+}
+
+impl DiagnosticPosition {
+  fn new(source: &str, offset: usize) -> Self {
+    let offset = offset.min(source.len());
+    let before = source.get(..offset).unwrap_or_else(|| {
+      // Fallback to the closest char boundary, spans should never point into the middle of a char.
+      let boundary = (0..offset).rev().find(|i| source.is_char_boundary(*i)).unwrap_or(0);
+      &source[..boundary]
+    });
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count();
+    let column = before[line_start..].encode_utf16().count();
+    Self { offset, line, column }
+  }
+}
+
+impl DiagnosticRange {
+  fn new(source: &str, range: &Range<usize>) -> Self {
+    Self {
+      start: DiagnosticPosition::new(source, range.start),
+      end: DiagnosticPosition::new(source, range.end),
+    }
+  }
+}
+
+impl StructuredDiagnostic {
+  pub(crate) fn from_diagnostic(diagnostic: &Diagnostic) -> Self {
+    let labels = diagnostic
+      .labels
+      .iter()
+      .map(|label| {
+        let source = diagnostic
+          .files
+          .iter()
+          .find(|(filename, _)| filename == &label.file)
+          .map_or("", |(_, content)| content.as_str());
+        StructuredLabel {
+          file: label.file.to_string(),
+          range: DiagnosticRange::new(source, &label.range),
+          message: label.message.clone(),
+        }
+      })
+      .collect::<Vec<_>>();
+
+    let file = labels
+      .first()
+      .map(|label| label.file.clone())
+      .or_else(|| diagnostic.files.first().map(|(filename, _)| filename.to_string()));
+
+    Self {
+      code: diagnostic.kind.clone(),
+      severity: diagnostic.severity,
+      message: diagnostic.title.clone(),
+      file,
+      range: labels.first().map(|label| label.range),
+      help: diagnostic.help.clone(),
+      labels,
+    }
+  }
+
+  pub fn to_json(&self) -> String {
+    serde_json::to_string(self).expect("StructuredDiagnostic should be serializable")
+  }
+
+  /// The `file://` uri of the file this diagnostic belongs to. LSP servers publish diagnostics per document, so
+  /// this is needed to know where [`Self::to_lsp`] should be sent to.
+  pub fn file_uri(&self, cwd: &Path) -> Option<String> {
+    self.file.as_deref().map(|file| path_to_file_uri(cwd, file))
+  }
+
+  /// Converts to a LSP `Diagnostic`. Relative paths of labels are resolved against `cwd`.
+  pub fn to_lsp(&self, cwd: &Path) -> LspDiagnostic {
+    let message = match &self.help {
+      Some(help) => format!("{}\nHelp: {help}", self.message),
+      None => self.message.clone(),
+    };
+    LspDiagnostic {
+      range: self.range.map(LspRange::from).unwrap_or_default(),
+      severity: match self.severity {
+        Severity::Error => 1,
+        Severity::Warning => 2,
+        Severity::Info => 3,
+        Severity::Debug => 4,
+      },
+      code: self.code.clone(),
+      source: "rolldown",
+      message,
+      related_information: self
+        .labels
+        .iter()
+        .filter(|label| !label.message.is_empty())
+        .map(|label| LspRelatedInformation {
+          location: LspLocation {
+            uri: path_to_file_uri(cwd, &label.file),
+            range: label.range.into(),
+          },
+          message: label.message.clone(),
+        })
+        .collect(),
+    }
+  }
+
+  /// Formats as a GitHub Actions workflow command, which shows up as an annotation on the file.
+  ///
+  /// See https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions.
+  pub fn to_github_annotation(&self) -> String {
+    let command = match self.severity {
+      Severity::Error => "error",
+      Severity::Warning => "warning",
+      Severity::Info | Severity::Debug => "notice",
+    };
+    let mut properties = vec![];
+    if let Some(file) = &self.file {
+      properties.push(format!("file={}", escape_github_property(file)));
+    }
+    if let Some(range) = &self.range {
+      // GitHub uses 1-based lines and columns.
+      properties.push(format!("line={}", range.start.line + 1));
+      properties.push(format!("endLine={}", range.end.line + 1));
+      properties.push(format!("col={}", range.start.column + 1));
+      properties.push(format!("endColumn={}", range.end.column + 1));
+    }
+    properties.push(format!("title={}", escape_github_property(&self.code)));
+
+    let mut message = self.message.clone();
+    if let Some(help) = &self.help {
+      write!(message, "\nHelp: {help}").unwrap();
+    }
+
+    format!("::{command} {}::{}", properties.join(","), escape_github_data(&message))
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LspDiagnostic {
+  pub range: LspRange,
+  /// `1` for errors, `2` for warnings, `3` for infos and `4` for debug logs.
+  pub severity: u8,
+  pub code: String,
+  pub source: &'static str,
+  pub message: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub related_information: Vec<LspRelatedInformation>,
+}
+
+impl LspDiagnostic {
+  pub fn to_json(&self) -> String {
+    serde_json::to_string(self).expect("LspDiagnostic should be serializable")
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LspRange {
+  pub start: LspPosition,
+  pub end: LspPosition,
+}
+
+impl From<DiagnosticRange> for LspRange {
+  fn from(range: DiagnosticRange) -> Self {
+    Self {
+      start: LspPosition { line: range.start.line, character: range.start.column },
+      end: LspPosition { line: range.end.line, character: range.end.column },
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LspPosition {
+  pub line: usize,
+  pub character: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LspRelatedInformation {
+  pub location: LspLocation,
+  pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LspLocation {
+  pub uri: String,
+  pub range: LspRange,
+}
+
+fn path_to_file_uri(cwd: &Path, file: &str) -> String {
+  let path = cwd.join(file).normalize();
+  let path = path.to_slash_lossy();
+  let mut uri = String::from("file://");
+  if !path.starts_with('/') {
+    // Windows paths like `C:/foo` need to be `file:///C:/foo`.
+    uri.push('/');
+  }
+  for byte in path.bytes() {
+    if byte.is_ascii_alphanumeric() || b"-._~/:".contains(&byte) {
+      uri.push(char::from(byte));
+    } else {
+      write!(uri, "%{byte:02X}").unwrap();
+    }
+  }
+  uri
+}
+
+fn escape_github_data(value: &str) -> String {
+  value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_github_property(value: &str) -> String {
+  escape_github_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use crate::{build_error::severity::Severity, diagnostic::Diagnostic};
+
+  fn create_diagnostic() -> Diagnostic {
+    let mut diagnostic =
+      Diagnostic::new("MISSING_EXPORT".to_string(), "Missing export".to_string(), Severity::Error);
+    let file_id = diagnostic.add_file("src/main.js", "const a = 1;\nimport { 😀, b } from './foo'");
+    diagnostic.add_label(&file_id, 28..29, "Missing export".to_string());
+    diagnostic.add_help("Did you mean `a`?".to_string());
+    diagnostic
+  }
+
+  #[test]
+  fn computes_lines_and_utf16_columns() {
+    let structured = create_diagnostic().to_structured();
+    let range = structured.range.unwrap();
+    assert_eq!(structured.file.as_deref(), Some("src/main.js"));
+    assert_eq!((range.start.offset, range.start.line, range.start.column), (28, 1, 13));
+    assert_eq!((range.end.offset, range.end.line, range.end.column), (29, 1, 14));
+  }
+
+  #[test]
+  fn serializes_to_lsp() {
+    let lsp = create_diagnostic().to_structured().to_lsp(Path::new("/project"));
+    assert_eq!(
+      lsp.to_json(),
+      r#"{"range":{"start":{"line":1,"character":13},"end":{"line":1,"character":14}},"severity":1,"code":"MISSING_EXPORT","source":"rolldown","message":"Missing export\nHelp: Did you mean `a`?","relatedInformation":[{"location":{"uri":"file:///project/src/main.js","range":{"start":{"line":1,"character":13},"end":{"line":1,"character":14}}},"message":"Missing export"}]}"#
+    );
+  }
+
+  #[test]
+  fn formats_github_annotation() {
+    assert_eq!(
+      create_diagnostic().to_structured().to_github_annotation(),
+      "::error file=src/main.js,line=2,endLine=2,col=14,endColumn=15,title=MISSING_EXPORT::Missing export%0AHelp: Did you mean `a`?"
+    );
+  }
+}
@@ -1,4 +1,4 @@
-use std::{io, path::Path};
+use std::{io, path::Path, time::SystemTime};
 
 use oxc_resolver::FileSystem as OxcResolverFileSystem;
 
@@ -27,4 +27,11 @@ pub trait FileSystem: Send + Sync + OxcResolverFileSystem {
   ///
   /// * See [std::fs::read]
   fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+  /// Returns the last modification time and the size of the file or the directory.
+  ///
+  /// # Errors
+  ///
+  /// * See [std::fs::metadata]
+  fn modified_and_len(&self, path: &Path) -> io::Result<(SystemTime, u64)>;
 }
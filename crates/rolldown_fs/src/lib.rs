@@ -5,6 +5,8 @@ mod memory;
 pub use memory::MemoryFileSystem;
 #[cfg(feature = "os")]
 mod os;
+mod shared;
 pub use crate::file_system::FileSystem;
 #[cfg(feature = "os")]
 pub use os::OsFileSystem;
+pub use shared::SharedFileSystem;
@@ -2,6 +2,7 @@ use std::{
   io,
   path::{Path, PathBuf},
   sync::Arc,
+  time::SystemTime,
 };
 
 use oxc_resolver::{FileMetadata, FileSystem as OxcResolverFileSystem};
@@ -56,10 +57,17 @@ impl FileSystem for MemoryFileSystem {
   }
 
   fn create_dir_all(&self, path: &Path) -> io::Result<()> {
-    self
-      .fs
-      .create_dir(&path.to_string_lossy())
-      .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+    // Like `std::fs::create_dir_all`, missing parent directories are created and existing ones are skipped.
+    for path in path.ancestors().collect::<Vec<_>>().iter().rev() {
+      let path = path.to_string_lossy();
+      if !self.fs.exists(path.as_ref()).unwrap_or(false) {
+        self
+          .fs
+          .create_dir(path.as_ref())
+          .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+      }
+    }
+    Ok(())
   }
 
   fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
@@ -73,7 +81,7 @@ impl FileSystem for MemoryFileSystem {
   }
 
   fn exists(&self, path: &Path) -> bool {
-    self.fs.exists(path.to_string_lossy().as_ref()).is_ok()
+    self.fs.exists(path.to_string_lossy().as_ref()).unwrap_or(false)
   }
 
   fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
@@ -85,6 +93,15 @@ impl FileSystem for MemoryFileSystem {
       .read_to_end(&mut buf)?;
     Ok(buf)
   }
+
+  fn modified_and_len(&self, path: &Path) -> io::Result<(SystemTime, u64)> {
+    let metadata = self
+      .fs
+      .metadata(path.to_string_lossy().as_ref())
+      .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err))?;
+    // Directories don't track the modified time in memory.
+    Ok((metadata.modified.unwrap_or(SystemTime::UNIX_EPOCH), metadata.len))
+  }
 }
 
 impl OxcResolverFileSystem for MemoryFileSystem {
@@ -114,8 +131,13 @@ impl OxcResolverFileSystem for MemoryFileSystem {
     })
   }
 
-  fn canonicalize(&self, _path: &Path) -> io::Result<PathBuf> {
-    Err(io::Error::new(io::ErrorKind::NotFound, "not a symlink"))
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    // There are no symlinks in the memory file system, so existing paths are already canonical.
+    if FileSystem::exists(self, path) {
+      Ok(path.to_path_buf())
+    } else {
+      Err(io::Error::new(io::ErrorKind::NotFound, format!("{} does not exist", path.display())))
+    }
   }
 }
 
@@ -146,11 +168,11 @@ mod tests {
       fs.read_to_string(Path::new("/module_1.js")).map_err(|err| err.to_string())?
     );
 
-    let ret = fs.create_dir_all(Path::new("/module_2/utils")).map_err(|err| err.kind());
-    assert_eq!(Err(std::io::ErrorKind::Other), ret);
-
-    fs.create_dir_all(Path::new("/module_2")).map_err(|err| err.to_string())?;
+    assert!(!fs.exists(Path::new("/module_2")));
     fs.create_dir_all(Path::new("/module_2/utils")).map_err(|err| err.to_string())?;
+    assert!(fs.exists(Path::new("/module_2/utils")));
+    // Existing directories are skipped.
+    fs.create_dir_all(Path::new("/module_2")).map_err(|err| err.to_string())?;
 
     let utils_content = b"export const name = \"utils\"";
     fs.write(Path::new("/module_2/utils/index.js"), utils_content)
@@ -3,6 +3,7 @@ use oxc_resolver::{FileMetadata, FileSystem as OxcResolverFileSystem};
 use std::{
   io,
   path::{Path, PathBuf},
+  time::SystemTime,
 };
 
 use crate::file_system::FileSystem;
@@ -31,6 +32,11 @@ impl FileSystem for OsFileSystem {
   fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
     std::fs::read(path)
   }
+
+  fn modified_and_len(&self, path: &Path) -> io::Result<(SystemTime, u64)> {
+    let metadata = std::fs::metadata(path)?;
+    Ok((metadata.modified()?, metadata.len()))
+  }
 }
 
 impl OxcResolverFileSystem for OsFileSystem {
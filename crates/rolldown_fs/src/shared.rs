@@ -0,0 +1,80 @@
+use std::{
+  fmt::Debug,
+  io,
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::SystemTime,
+};
+
+use oxc_resolver::{FileMetadata, FileSystem as OxcResolverFileSystem};
+
+use crate::file_system::FileSystem;
+
+/// A type-erased [FileSystem] that is cheap to clone.
+///
+/// It allows the bundler and the resolver to run on any file system without being generic over it.
+#[derive(Clone)]
+pub struct SharedFileSystem(Arc<dyn FileSystem>);
+
+impl SharedFileSystem {
+  pub fn new<T: FileSystem + 'static>(fs: T) -> Self {
+    Self(Arc::new(fs))
+  }
+}
+
+#[cfg(feature = "os")]
+impl Default for SharedFileSystem {
+  fn default() -> Self {
+    Self::new(crate::OsFileSystem)
+  }
+}
+
+impl Debug for SharedFileSystem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "SharedFileSystem(...)")
+  }
+}
+
+impl FileSystem for SharedFileSystem {
+  fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.0.remove_dir_all(path)
+  }
+
+  fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.0.create_dir_all(path)
+  }
+
+  fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+    self.0.write(path, content)
+  }
+
+  fn exists(&self, path: &Path) -> bool {
+    self.0.exists(path)
+  }
+
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    self.0.read(path)
+  }
+
+  fn modified_and_len(&self, path: &Path) -> io::Result<(SystemTime, u64)> {
+    self.0.modified_and_len(path)
+  }
+}
+
+impl OxcResolverFileSystem for SharedFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    self.0.read_to_string(path)
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.0.metadata(path)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.0.symlink_metadata(path)
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    self.0.canonicalize(path)
+  }
+}
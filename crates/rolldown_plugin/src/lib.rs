@@ -20,11 +20,11 @@ pub mod __inner {
 pub use crate::{
   plugin::{
     HookAugmentChunkHashReturn, HookInjectionOutputReturn, HookLoadReturn, HookNoopReturn,
-    HookRenderChunkReturn, HookResolveIdReturn, HookTransformAstReturn, HookTransformReturn,
-    Plugin,
+    HookRenderChunkReturn, HookResolveIdReturn, HookResolveImportMetaReturn,
+    HookTransformAstReturn, HookTransformReturn, Plugin,
   },
   plugin_context::PluginContext,
-  plugin_driver::{PluginDriver, SharedPluginDriver},
+  plugin_driver::{PluginDriver, PluginWatchFiles, SharedPluginDriver},
   plugin_hook_meta::{PluginHookMeta, PluginOrder},
   transform_plugin_context::TransformPluginContext,
   types::hook_addon_args::HookAddonArgs,
@@ -39,10 +39,12 @@ pub use crate::{
   types::hook_render_error::HookRenderErrorArgs,
   types::hook_resolve_id_args::HookResolveIdArgs,
   types::hook_resolve_id_output::HookResolveIdOutput,
+  types::hook_resolve_import_meta_args::HookResolveImportMetaArgs,
   types::hook_transform_args::HookTransformArgs,
   types::hook_transform_ast_args::HookTransformAstArgs,
   types::hook_transform_output::HookTransformOutput,
   types::plugin_context_resolve_options::PluginContextResolveOptions,
+  types::plugin_log::PluginLog,
 };
 
 pub use typedmap;
@@ -11,10 +11,11 @@ use crate::{
     hook_transform_output::HookTransformOutput,
   },
   HookAddonArgs, HookBuildEndArgs, HookLoadArgs, HookLoadOutput, HookRenderChunkArgs,
-  HookRenderChunkOutput, HookResolveIdArgs, HookResolveIdOutput, HookTransformArgs,
+  HookRenderChunkOutput, HookResolveIdArgs, HookResolveIdOutput, HookResolveImportMetaArgs,
+  HookTransformArgs,
 };
 use anyhow::Result;
-use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk};
+use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk, WatcherChangeKind};
 use rolldown_ecmascript::EcmaAst;
 
 pub type HookResolveIdReturn = Result<Option<HookResolveIdOutput>>;
@@ -25,10 +26,20 @@ pub type HookNoopReturn = Result<()>;
 pub type HookRenderChunkReturn = Result<Option<HookRenderChunkOutput>>;
 pub type HookAugmentChunkHashReturn = Result<Option<String>>;
 pub type HookInjectionOutputReturn = Result<Option<String>>;
+pub type HookResolveImportMetaReturn = Result<Option<String>>;
 
 pub trait Plugin: Any + Debug + Send + Sync + 'static {
   fn name(&self) -> Cow<'static, str>;
 
+  /// Identifies what affects the results of the hooks besides the name, e.g. the version and the options of the
+  /// plugin. Entries of the persistent cache are only reused while it's unchanged.
+  ///
+  /// Defaults to `None`, which means the results of the plugin can't be cached, so the persistent cache is disabled
+  /// while the plugin is used.
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    None
+  }
+
   // The `option` hook consider call at node side.
 
   // --- Build hooks ---
@@ -132,6 +143,20 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Replaces `import.meta` or `import.meta.<property>` with the returned code. The first plugin returning `Some`
+  /// wins. Without a replacement, `import.meta` is lowered according to `output.format`.
+  fn resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveImportMetaArgs<'_>,
+  ) -> impl std::future::Future<Output = HookResolveImportMetaReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn resolve_import_meta_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn banner(
     &self,
     _ctx: &PluginContext,
@@ -252,6 +277,32 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  // --- Watch hooks ---
+
+  fn watch_change(
+    &self,
+    _ctx: &PluginContext,
+    _path: &str,
+    _event: WatcherChangeKind,
+  ) -> impl std::future::Future<Output = HookNoopReturn> + Send {
+    async { Ok(()) }
+  }
+
+  fn watch_change_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  fn close_watcher(
+    &self,
+    _ctx: &PluginContext,
+  ) -> impl std::future::Future<Output = HookNoopReturn> + Send {
+    async { Ok(()) }
+  }
+
+  fn close_watcher_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   // --- experimental hooks ---
 
   fn transform_ast(
@@ -1,17 +1,22 @@
 use std::{
   ops::Deref,
-  path::PathBuf,
-  sync::{Arc, OnceLock, Weak},
+  path::{Path, PathBuf},
+  sync::{Arc, RwLock, Weak},
 };
 
 use arcstr::ArcStr;
-use rolldown_common::{ModuleTable, ResolvedId, SharedFileEmitter, SharedNormalizedBundlerOptions};
+use rolldown_common::{
+  Module, ModuleInfo, ModuleTable, ResolvedId, SharedFileEmitter, SharedNormalizedBundlerOptions,
+};
+use rolldown_error::{BuildDiagnostic, Severity};
 use rolldown_resolver::{ResolveError, Resolver};
+use sugar_path::SugarPath;
 
 use crate::{
   types::{
     hook_resolve_id_skipped::HookResolveIdSkipped,
     plugin_context_resolve_options::PluginContextResolveOptions, plugin_idx::PluginIdx,
+    plugin_log::PluginLog,
   },
   utils::resolve_id_with_plugins::resolve_id_check_external,
   PluginDriver,
@@ -26,15 +31,29 @@ impl PluginContext {
     &self,
     skipped_resolve_calls: Vec<Arc<HookResolveIdSkipped>>,
   ) -> Self {
+    Self(Arc::new(PluginContextImpl { skipped_resolve_calls, ..self.clone_inner() }))
+  }
+
+  /// Returns the context for the `load` and `transform` hooks of the module `module_id`.
+  #[must_use]
+  pub(crate) fn new_shared_with_current_module(&self, module_id: &str) -> Self {
     Self(Arc::new(PluginContextImpl {
-      skipped_resolve_calls,
+      current_module: Some(module_id.into()),
+      ..self.clone_inner()
+    }))
+  }
+
+  fn clone_inner(&self) -> PluginContextImpl {
+    PluginContextImpl {
+      skipped_resolve_calls: self.skipped_resolve_calls.clone(),
+      current_module: self.current_module.clone(),
       plugin_idx: self.plugin_idx,
       plugin_driver: Weak::clone(&self.plugin_driver),
       resolver: Arc::clone(&self.resolver),
       file_emitter: Arc::clone(&self.file_emitter),
-      module_table: self.module_table.clone(),
+      module_table: RwLock::new(*self.module_table.read().expect("module_table lock poisoned")),
       options: Arc::clone(&self.options),
-    }))
+    }
   }
 }
 
@@ -49,12 +68,14 @@ impl Deref for PluginContext {
 #[derive(Debug)]
 pub struct PluginContextImpl {
   pub(crate) skipped_resolve_calls: Vec<Arc<HookResolveIdSkipped>>,
+  /// The module whose `load` or `transform` hooks are running with this context.
+  pub(crate) current_module: Option<ArcStr>,
   pub(crate) plugin_idx: PluginIdx,
   pub(crate) resolver: Arc<Resolver>,
   pub(crate) plugin_driver: Weak<PluginDriver>,
   pub(crate) file_emitter: SharedFileEmitter,
   #[allow(clippy::redundant_allocation)]
-  pub(crate) module_table: OnceLock<&'static ModuleTable>,
+  pub(crate) module_table: RwLock<Option<&'static ModuleTable>>,
   pub(crate) options: SharedNormalizedBundlerOptions,
 }
 
@@ -106,10 +127,43 @@ impl PluginContextImpl {
     .await
   }
 
+  /// Loads, transforms and parses the module, and returns its info once the `module_parsed` hooks are called.
+  ///
+  /// It's safe to await in `load` and `transform` hooks, since other modules keep loading in the meantime. Loading the
+  /// current module fails, but it never resolves if the awaited module awaits the current module in its own hooks.
+  pub async fn load(&self, resolved_id: ResolvedId) -> anyhow::Result<Arc<ModuleInfo>> {
+    if self.current_module.as_ref().is_some_and(|id| *id == resolved_id.id) {
+      return Err(anyhow::format_err!(
+        "Unable to load module {:?} in its own hooks, since it can't be parsed before they finish.",
+        resolved_id.id
+      ));
+    }
+    let plugin_driver = self
+      .plugin_driver
+      .upgrade()
+      .ok_or_else(|| anyhow::format_err!("Plugin driver is already dropped."))?;
+    plugin_driver.load_module(resolved_id).await
+  }
+
   pub fn emit_file(&self, file: rolldown_common::EmittedAsset) -> ArcStr {
     self.file_emitter.emit_file(file)
   }
 
+  /// Like `emit_file`, but the file is emitted again if the module `module_id` is reused from the cache in watch mode,
+  /// which is needed if the file is emitted while loading the module.
+  pub fn emit_file_for_module(
+    &self,
+    file: rolldown_common::EmittedAsset,
+    module_id: Option<&str>,
+  ) -> ArcStr {
+    self.file_emitter.emit_file_for_module(file, module_id)
+  }
+
+  /// Emits a chunk with `chunk.id` as its entry module, which could only be called before all modules are loaded.
+  pub fn emit_chunk(&self, chunk: rolldown_common::EmittedChunk) -> anyhow::Result<ArcStr> {
+    self.file_emitter.emit_chunk(chunk)
+  }
+
   pub fn try_get_file_name(&self, reference_id: &str) -> Result<ArcStr, String> {
     self.file_emitter.try_get_file_name(reference_id)
   }
@@ -118,34 +172,109 @@ impl PluginContextImpl {
     self.file_emitter.get_file_name(reference_id)
   }
 
+  /// Modules are only available after linking, so `is_included` of normal modules is always filled.
   pub fn get_module_info(&self, module_id: &str) -> Option<rolldown_common::ModuleInfo> {
-    self.module_table.get().as_ref().and_then(|module_table| {
-      for normal_module in &module_table.modules {
-        if let Some(ecma_module) = normal_module.as_normal() {
-          if ecma_module.id.as_str() == module_id {
-            return Some(ecma_module.to_module_info());
+    self.module_table.read().expect("module_table lock poisoned").as_ref().and_then(
+      |module_table| {
+        module_table.modules.iter().find(|module| module.id() == module_id).map(|module| {
+          match module {
+            Module::Normal(normal_module) => normal_module.to_linked_module_info(),
+            Module::External(external_module) => external_module.to_module_info(),
           }
-        }
-      }
-      // TODO external module
-      None
-    })
+        })
+      },
+    )
   }
 
   pub fn get_module_ids(&self) -> Option<Vec<String>> {
-    if let Some(module_table) = self.module_table.get() {
-      let mut ids = Vec::with_capacity(module_table.modules.len());
-      for normal_module in &module_table.modules {
-        ids.push(normal_module.id().to_string());
-      }
-      // TODO external module
-      Some(ids)
-    } else {
-      None
-    }
+    self.module_table.read().expect("module_table lock poisoned").as_ref().map(|module_table| {
+      module_table.modules.iter().map(|module| module.id().to_string()).collect()
+    })
   }
 
   pub fn cwd(&self) -> &PathBuf {
     self.resolver.cwd()
   }
+
+  /// Adds a file that isn't part of the module graph to the dependencies of the build, so it's watched in watch
+  /// mode and reported in `BundleOutput::watch_files`. Relative paths are resolved against `cwd`.
+  ///
+  /// Changes of the file invalidate all cached modules in watch mode, use `TransformPluginContext::add_watch_file`
+  /// to only invalidate the module being transformed.
+  pub fn add_watch_file(&self, file: &str) {
+    self.add_watch_file_with_importer(file, None);
+  }
+
+  /// Returns the files added through `add_watch_file` in the current build.
+  pub fn get_watch_files(&self) -> Vec<ArcStr> {
+    self
+      .plugin_driver
+      .upgrade()
+      .map(|plugin_driver| plugin_driver.watch_files())
+      .unwrap_or_default()
+  }
+
+  pub(crate) fn add_watch_file_with_importer(&self, file: &str, importer: Option<&str>) {
+    if let Some(plugin_driver) = self.plugin_driver.upgrade() {
+      let file =
+        Path::new(file).absolutize_with(self.cwd().as_path()).to_string_lossy().into_owned();
+      plugin_driver.add_watch_file(file.into(), importer);
+    }
+  }
+
+  /// Reports a warning, which ends up in `BundleOutput::warnings`.
+  pub fn warn(&self, log: impl Into<PluginLog>) {
+    self.add_log(Severity::Warning, log.into(), None);
+  }
+
+  pub fn info(&self, log: impl Into<PluginLog>) {
+    self.add_log(Severity::Info, log.into(), None);
+  }
+
+  pub fn debug(&self, log: impl Into<PluginLog>) {
+    self.add_log(Severity::Debug, log.into(), None);
+  }
+
+  /// Creates an error to be returned from hooks. It aborts the build like other errors returned by hooks, but it's
+  /// reported in `BundleOutput::errors` with the plugin name and the position of the module, instead of failing the
+  /// call to `generate` or `write`.
+  pub fn error(&self, log: impl Into<PluginLog>) -> anyhow::Error {
+    self.create_diagnostic(Severity::Error, log.into(), None).into()
+  }
+
+  pub(crate) fn add_log(&self, severity: Severity, log: PluginLog, source: Option<ArcStr>) {
+    let diagnostic = self.create_diagnostic(severity, log, source);
+    if let Some(plugin_driver) = self.plugin_driver.upgrade() {
+      plugin_driver.add_log(diagnostic);
+    }
+  }
+
+  /// The span of `log` is only shown if the source of the module is known, which is either passed as `source` or
+  /// looked up in the module table.
+  pub(crate) fn create_diagnostic(
+    &self,
+    severity: Severity,
+    log: PluginLog,
+    source: Option<ArcStr>,
+  ) -> BuildDiagnostic {
+    let plugin = self.plugin_driver.upgrade().map_or_else(
+      || "unknown".to_string(),
+      |plugin_driver| plugin_driver.plugin_name(self.plugin_idx).into_owned(),
+    );
+    let source = source.or_else(|| log.id.as_deref().and_then(|id| self.module_source(id)));
+    let location = log.span.zip(source).map(|(span, source)| (source, span));
+    BuildDiagnostic::plugin_log(severity, plugin, log.message, log.id, location)
+  }
+
+  fn module_source(&self, module_id: &str) -> Option<ArcStr> {
+    let module_table = self.module_table.read().expect("module_table lock poisoned");
+    module_table.as_ref().and_then(|module_table| {
+      module_table.modules.iter().find_map(|module| match module {
+        Module::Normal(normal_module) if normal_module.id.as_str() == module_id => {
+          Some(normal_module.source.clone())
+        }
+        _ => None,
+      })
+    })
+  }
 }
@@ -1,7 +1,8 @@
-use std::sync::Arc;
+use std::sync::{atomic::AtomicBool, Arc};
 
 use crate::{
   pluginable::HookTransformAstReturn,
+  transform_plugin_context::CombinedSourcemapCache,
   types::{
     hook_resolve_id_skipped::HookResolveIdSkipped, hook_transform_ast_args::HookTransformAstArgs,
     plugin_idx::PluginIdx,
@@ -10,7 +11,9 @@ use crate::{
   HookResolveIdReturn, HookTransformArgs, PluginContext, PluginDriver, TransformPluginContext,
 };
 use anyhow::Result;
-use rolldown_common::{side_effects::HookSideEffects, ModuleInfo, ModuleType};
+use rolldown_common::{
+  side_effects::HookSideEffects, ModuleInfo, ModuleMeta, ModuleType, SyntheticNamedExports,
+};
 use rolldown_sourcemap::SourceMap;
 use rolldown_utils::futures::block_on_spawn_all;
 
@@ -153,22 +156,29 @@ impl PluginDriver {
       if filter_load(filter_option, args.id, ctx.cwd()) == Some(false) {
         continue;
       }
-      if let Some(r) = plugin.call_load(ctx, args).await? {
+      let ctx = ctx.new_shared_with_current_module(args.id);
+      if let Some(r) = plugin.call_load(&ctx, args).await? {
         return Ok(Some(r));
       }
     }
     Ok(None)
   }
 
+  #[allow(clippy::too_many_arguments)]
   pub async fn transform(
     &self,
     args: &HookTransformArgs<'_>,
     sourcemap_chain: &mut Vec<SourceMap>,
     side_effects: &mut Option<HookSideEffects>,
+    meta: &mut ModuleMeta,
+    synthetic_named_exports: &mut Option<SyntheticNamedExports>,
     original_code: &str,
     module_type: &mut ModuleType,
+    has_side_outputs: &mut bool,
   ) -> Result<String> {
     let mut code = args.code.to_string();
+    let combined_sourcemap_cache = CombinedSourcemapCache::default();
+    let side_outputs = AtomicBool::new(false);
     for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_transform_meta)
     {
@@ -178,7 +188,15 @@ impl PluginDriver {
       }
       if let Some(r) = plugin
         .call_transform(
-          &TransformPluginContext::new(ctx.clone(), sourcemap_chain, original_code, args.id),
+          &TransformPluginContext::new(
+            ctx.new_shared_with_current_module(args.id),
+            sourcemap_chain,
+            &combined_sourcemap_cache,
+            original_code,
+            args.id,
+            &code,
+            &side_outputs,
+          ),
           &HookTransformArgs { id: args.id, code: &code, module_type: &*module_type },
         )
         .await?
@@ -197,6 +215,12 @@ impl PluginDriver {
         if let Some(v) = r.side_effects {
           *side_effects = Some(v);
         }
+        if let Some(v) = r.meta {
+          meta.extend(v);
+        }
+        if let Some(v) = r.synthetic_named_exports {
+          *synthetic_named_exports = Some(v);
+        }
         if let Some(v) = r.code {
           code = v;
         }
@@ -205,6 +229,7 @@ impl PluginDriver {
         }
       }
     }
+    *has_side_outputs = side_outputs.into_inner();
     Ok(code)
   }
 
@@ -226,6 +251,7 @@ impl PluginDriver {
     {
       plugin.call_module_parsed(ctx, Arc::clone(&module_info)).await?;
     }
+    self.set_module_loaded(module_info);
     Ok(())
   }
 
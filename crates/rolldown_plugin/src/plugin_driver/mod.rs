@@ -1,11 +1,16 @@
 use std::{
+  borrow::Cow,
   ops::Deref,
-  sync::{Arc, OnceLock, Weak},
+  sync::{Arc, Mutex, RwLock, Weak},
   vec,
 };
 
+use arcstr::ArcStr;
 use rolldown_common::{ModuleTable, SharedFileEmitter, SharedNormalizedBundlerOptions};
+use rolldown_error::BuildDiagnostic;
 use rolldown_resolver::Resolver;
+use rolldown_utils::indexmap::FxIndexMap;
+use rustc_hash::FxHashSet;
 
 use crate::{
   __inner::SharedPluginable,
@@ -17,15 +22,26 @@ use crate::{
 
 mod build_hooks;
 mod hook_filter;
+mod module_loading;
 mod output_hooks;
+mod watch_hooks;
+
+use module_loading::ModuleLoadingState;
 
 pub type SharedPluginDriver = Arc<PluginDriver>;
 
+/// Files added by plugins through `add_watch_file`, with the modules whose `transform` hooks depend on them.
+/// `None` means the file is added by other hooks, so it can't be attributed to specific modules.
+pub type PluginWatchFiles = FxIndexMap<ArcStr, Option<FxHashSet<ArcStr>>>;
+
 pub struct PluginDriver {
   plugins: IndexPluginable,
   contexts: IndexPluginContext,
   order_indicates: HookOrderIndicates,
   index_plugin_filters: IndexPluginFilter,
+  module_loading: ModuleLoadingState,
+  logs: Mutex<Vec<BuildDiagnostic>>,
+  watch_files: Mutex<PluginWatchFiles>,
 }
 
 impl PluginDriver {
@@ -51,11 +67,12 @@ impl PluginDriver {
         index_contexts.push(
           PluginContextImpl {
             skipped_resolve_calls: vec![],
+            current_module: None,
             plugin_idx,
             plugin_driver: Weak::clone(plugin_driver),
             resolver: Arc::clone(resolver),
             file_emitter: Arc::clone(file_emitter),
-            module_table: OnceLock::default(),
+            module_table: RwLock::default(),
             options: Arc::clone(options),
           }
           .into(),
@@ -67,16 +84,77 @@ impl PluginDriver {
         plugins: index_plugins,
         contexts: index_contexts,
         index_plugin_filters,
+        module_loading: ModuleLoadingState::default(),
+        logs: Mutex::default(),
+        watch_files: Mutex::default(),
       }
     })
   }
 
   pub fn set_module_table(&self, module_table: &'static ModuleTable) {
     self.contexts.iter().for_each(|ctx| {
-      ctx.module_table.set(module_table).expect("module_table is already set before");
+      *ctx.module_table.write().expect("module_table lock poisoned") = Some(module_table);
+    });
+  }
+
+  /// Forget the `module_table` of the previous build, so plugins can't access it while rebuilding.
+  pub fn clear_module_table(&self) {
+    self.contexts.iter().for_each(|ctx| {
+      *ctx.module_table.write().expect("module_table lock poisoned") = None;
     });
   }
 
+  /// Names and cache keys of the plugins in the order they are registered. Returns `None` if any plugin doesn't have
+  /// a cache key.
+  pub fn plugin_cache_keys(&self) -> Option<Vec<(Cow<'static, str>, Cow<'static, str>)>> {
+    self.plugins.iter().map(|plugin| Some((plugin.call_name(), plugin.call_cache_key()?))).collect()
+  }
+
+  pub(crate) fn add_log(&self, log: BuildDiagnostic) {
+    self.logs.lock().expect("logs lock poisoned").push(log);
+  }
+
+  /// The plugins of this driver. Builds of web workers are run with the same plugins.
+  pub fn plugins(&self) -> Vec<SharedPluginable> {
+    self.plugins.iter().cloned().collect()
+  }
+
+  /// Takes the logs created by plugins through `PluginContext::warn`, `info` and `debug`.
+  pub fn take_logs(&self) -> Vec<BuildDiagnostic> {
+    std::mem::take(&mut *self.logs.lock().expect("logs lock poisoned"))
+  }
+
+  /// Records a file the build depends on. Files with an importer only invalidate the importer in watch mode.
+  pub fn add_watch_file(&self, file: ArcStr, importer: Option<&str>) {
+    let mut watch_files = self.watch_files.lock().expect("watch_files lock poisoned");
+    match importer {
+      Some(importer) => {
+        if let Some(importers) =
+          watch_files.entry(file).or_insert_with(|| Some(FxHashSet::default()))
+        {
+          importers.insert(importer.into());
+        }
+      }
+      None => {
+        watch_files.insert(file, None);
+      }
+    }
+  }
+
+  /// Files added through `add_watch_file` in the current build.
+  pub fn watch_files(&self) -> Vec<ArcStr> {
+    self.watch_files.lock().expect("watch_files lock poisoned").keys().cloned().collect()
+  }
+
+  /// Takes the files added through `add_watch_file`, which also resets them for the next build.
+  pub fn take_watch_files(&self) -> PluginWatchFiles {
+    std::mem::take(&mut *self.watch_files.lock().expect("watch_files lock poisoned"))
+  }
+
+  pub(crate) fn plugin_name(&self, plugin_idx: PluginIdx) -> Cow<'static, str> {
+    self.plugins[plugin_idx].call_name()
+  }
+
   pub fn iter_plugin_with_context_by_order<'me>(
     &'me self,
     ordered_plugins: &'me [PluginIdx],
@@ -107,6 +185,7 @@ pub struct HookOrderIndicates {
   pub order_by_module_parsed_meta: Vec<PluginIdx>,
   pub order_by_build_end_meta: Vec<PluginIdx>,
   pub order_by_render_start_meta: Vec<PluginIdx>,
+  pub order_by_resolve_import_meta_meta: Vec<PluginIdx>,
   pub order_by_banner_meta: Vec<PluginIdx>,
   pub order_by_footer_meta: Vec<PluginIdx>,
   pub order_by_intro_meta: Vec<PluginIdx>,
@@ -117,6 +196,8 @@ pub struct HookOrderIndicates {
   pub order_by_generate_bundle_meta: Vec<PluginIdx>,
   pub order_by_write_bundle_meta: Vec<PluginIdx>,
   pub order_by_close_bundle_meta: Vec<PluginIdx>,
+  pub order_by_watch_change_meta: Vec<PluginIdx>,
+  pub order_by_close_watcher_meta: Vec<PluginIdx>,
   pub order_by_transform_ast_meta: Vec<PluginIdx>,
 }
 
@@ -145,6 +226,9 @@ impl HookOrderIndicates {
       order_by_render_start_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_render_start_meta()
       }),
+      order_by_resolve_import_meta_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_resolve_import_meta_meta()
+      }),
       order_by_banner_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_banner_meta()
       }),
@@ -171,6 +255,12 @@ impl HookOrderIndicates {
       order_by_close_bundle_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_close_bundle_meta()
       }),
+      order_by_watch_change_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_watch_change_meta()
+      }),
+      order_by_close_watcher_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_close_watcher_meta()
+      }),
       order_by_transform_ast_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_transform_ast_meta()
       }),
@@ -0,0 +1,87 @@
+use std::sync::{Arc, Mutex};
+
+use arcstr::ArcStr;
+use dashmap::DashMap;
+use rolldown_common::{ModuleInfo, ResolvedId};
+use tokio::sync::{mpsc, Notify};
+
+use super::PluginDriver;
+
+/// Lets `PluginContext::load` request modules from the `ModuleLoader` and wait for them to be parsed.
+#[derive(Debug, Default)]
+pub(crate) struct ModuleLoadingState {
+  /// `None` if the `ModuleLoader` is not running.
+  tx: Mutex<Option<mpsc::UnboundedSender<ResolvedId>>>,
+  /// `None` for modules that failed to load.
+  results: DashMap<ArcStr, Option<Arc<ModuleInfo>>>,
+  notify: Notify,
+}
+
+impl PluginDriver {
+  /// Called by the `ModuleLoader` before loading modules. Modules requested by `PluginContext::load` are
+  /// received from the returned receiver, which should be spawned as non-entry modules.
+  pub fn start_module_loading(&self) -> mpsc::UnboundedReceiver<ResolvedId> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    *self.module_loading.tx.lock().expect("module loading lock poisoned") = Some(tx);
+    self.module_loading.results.clear();
+    rx
+  }
+
+  /// Called by the `ModuleLoader` once it stops loading modules. Pending `PluginContext::load` calls will fail.
+  pub fn finish_module_loading(&self) {
+    *self.module_loading.tx.lock().expect("module loading lock poisoned") = None;
+    self.module_loading.notify.notify_waiters();
+  }
+
+  /// Marks the module as parsed, which resolves `PluginContext::load` calls waiting for it.
+  pub fn set_module_loaded(&self, module_info: Arc<ModuleInfo>) {
+    self.module_loading.results.insert(module_info.id.as_str().into(), Some(module_info));
+    self.module_loading.notify.notify_waiters();
+  }
+
+  pub fn set_module_load_failed(&self, id: &ArcStr) {
+    self.module_loading.results.insert(ArcStr::clone(id), None);
+    self.module_loading.notify.notify_waiters();
+  }
+
+  pub(crate) async fn load_module(
+    &self,
+    resolved_id: ResolvedId,
+  ) -> anyhow::Result<Arc<ModuleInfo>> {
+    if resolved_id.is_external {
+      return Err(anyhow::format_err!("Unable to load external module {:?}.", resolved_id.id));
+    }
+    let id = ArcStr::clone(&resolved_id.id);
+    let mut resolved_id = Some(resolved_id);
+    loop {
+      // Register the waiter before checking results, so notifications in between are not missed.
+      let mut notified = std::pin::pin!(self.module_loading.notify.notified());
+      notified.as_mut().enable();
+
+      if let Some(result) = self.module_loading.results.get(&id) {
+        return result.value().clone().ok_or_else(|| {
+          anyhow::format_err!("Unable to load module {id:?}, since it failed to load or parse.")
+        });
+      }
+
+      {
+        let tx = self.module_loading.tx.lock().expect("module loading lock poisoned");
+        let Some(tx) = tx.as_ref() else {
+          return Err(anyhow::format_err!(
+            "Unable to load module {id:?}, modules can't be loaded after module loading has finished."
+          ));
+        };
+        // The `ModuleLoader` ignores modules that are already loading, so it's safe to request them.
+        if let Some(resolved_id) = resolved_id.take() {
+          tx.send(resolved_id).map_err(|_| {
+            anyhow::format_err!(
+              "Unable to load module {id:?}, the module loader is already dropped."
+            )
+          })?;
+        }
+      }
+
+      notified.await;
+    }
+  }
+}
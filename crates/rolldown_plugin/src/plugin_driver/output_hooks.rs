@@ -1,5 +1,5 @@
 use crate::types::hook_render_error::HookRenderErrorArgs;
-use crate::{HookAddonArgs, PluginDriver};
+use crate::{HookAddonArgs, HookResolveImportMetaArgs, PluginDriver};
 use crate::{HookAugmentChunkHashReturn, HookNoopReturn, HookRenderChunkArgs};
 use anyhow::{Ok, Result};
 use rolldown_common::{Output, RollupRenderedChunk};
@@ -14,6 +14,20 @@ impl PluginDriver {
     Ok(())
   }
 
+  pub async fn resolve_import_meta(
+    &self,
+    args: &HookResolveImportMetaArgs<'_>,
+  ) -> Result<Option<String>> {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_resolve_import_meta_meta)
+    {
+      if let Some(r) = plugin.call_resolve_import_meta(ctx, args).await? {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
   pub async fn banner(
     &self,
     args: HookAddonArgs<'_>,
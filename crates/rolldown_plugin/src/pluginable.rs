@@ -10,16 +10,17 @@ use crate::{
     hook_transform_ast_args::HookTransformAstArgs,
   },
   HookAddonArgs, HookBuildEndArgs, HookInjectionOutputReturn, HookLoadArgs, HookRenderChunkArgs,
-  HookResolveIdArgs, HookTransformArgs, Plugin,
+  HookResolveIdArgs, HookResolveImportMetaArgs, HookTransformArgs, Plugin,
 };
 use anyhow::Ok;
-use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk};
+use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk, WatcherChangeKind};
 
 pub use crate::plugin::HookAugmentChunkHashReturn;
 pub use crate::plugin::HookLoadReturn;
 pub use crate::plugin::HookNoopReturn;
 pub use crate::plugin::HookRenderChunkReturn;
 pub use crate::plugin::HookResolveIdReturn;
+pub use crate::plugin::HookResolveImportMetaReturn;
 pub use crate::plugin::HookTransformAstReturn;
 pub use crate::plugin::HookTransformReturn;
 
@@ -35,6 +36,8 @@ pub type SharedPluginable = Arc<dyn Pluginable>;
 pub trait Pluginable: Any + Debug + Send + Sync + 'static {
   fn call_name(&self) -> Cow<'static, str>;
 
+  fn call_cache_key(&self) -> Option<Cow<'static, str>>;
+
   // The `option` hook consider call at node side.
 
   // --- Build hooks ---
@@ -104,6 +107,14 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_render_start_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveImportMetaArgs,
+  ) -> HookResolveImportMetaReturn;
+
+  fn call_resolve_import_meta_meta(&self) -> Option<PluginHookMeta>;
+
   async fn call_banner(
     &self,
     _ctx: &PluginContext,
@@ -181,6 +192,21 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_close_bundle_meta(&self) -> Option<PluginHookMeta>;
 
+  // --- Watch hooks ---
+
+  async fn call_watch_change(
+    &self,
+    _ctx: &PluginContext,
+    _path: &str,
+    _event: WatcherChangeKind,
+  ) -> HookNoopReturn;
+
+  fn call_watch_change_meta(&self) -> Option<PluginHookMeta>;
+
+  async fn call_close_watcher(&self, _ctx: &PluginContext) -> HookNoopReturn;
+
+  fn call_close_watcher_meta(&self) -> Option<PluginHookMeta>;
+
   fn call_transform_filter(&self) -> anyhow::Result<Option<TransformHookFilter>> {
     Ok(None)
   }
@@ -200,6 +226,10 @@ impl<T: Plugin> Pluginable for T {
     Plugin::name(self)
   }
 
+  fn call_cache_key(&self) -> Option<Cow<'static, str>> {
+    Plugin::cache_key(self)
+  }
+
   async fn call_build_start(&self, ctx: &PluginContext) -> HookNoopReturn {
     Plugin::build_start(self, ctx).await
   }
@@ -285,6 +315,18 @@ impl<T: Plugin> Pluginable for T {
     Plugin::render_start_meta(self)
   }
 
+  async fn call_resolve_import_meta(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveImportMetaArgs,
+  ) -> HookResolveImportMetaReturn {
+    Plugin::resolve_import_meta(self, ctx, args).await
+  }
+
+  fn call_resolve_import_meta_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::resolve_import_meta_meta(self)
+  }
+
   async fn call_banner(
     &self,
     ctx: &PluginContext,
@@ -402,6 +444,27 @@ impl<T: Plugin> Pluginable for T {
     Plugin::close_bundle_meta(self)
   }
 
+  async fn call_watch_change(
+    &self,
+    ctx: &PluginContext,
+    path: &str,
+    event: WatcherChangeKind,
+  ) -> HookNoopReturn {
+    Plugin::watch_change(self, ctx, path, event).await
+  }
+
+  fn call_watch_change_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::watch_change_meta(self)
+  }
+
+  async fn call_close_watcher(&self, ctx: &PluginContext) -> HookNoopReturn {
+    Plugin::close_watcher(self, ctx).await
+  }
+
+  fn call_close_watcher_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::close_watcher_meta(self)
+  }
+
   fn call_transform_ast(
     &self,
     ctx: &PluginContext,
@@ -1,44 +1,171 @@
-use crate::PluginContext;
-use rolldown_sourcemap::SourceMap;
+use std::{
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+};
 
-#[allow(unused)]
+use arcstr::ArcStr;
+use rolldown_common::{ModuleInfo, ResolvedId};
+use rolldown_error::Severity;
+use rolldown_resolver::ResolveError;
+
+use crate::{PluginContext, PluginContextResolveOptions, PluginLog};
+use rolldown_sourcemap::{collapse_sourcemaps, create_identity_sourcemap, SourceMap};
+
+/// Caches the collapsed sourcemap of a prefix of the sourcemap chain of a module, so calling `get_combined_sourcemap`
+/// in multiple `transform` hooks only remaps sourcemaps added since the last call.
+#[derive(Debug, Default)]
+pub(crate) struct CombinedSourcemapCache(Mutex<Option<(usize, SourceMap)>>);
+
+/// The context of `transform` hooks. Methods producing outputs besides the transformed result, e.g. emitting files,
+/// mark the result as not persistently cacheable, so the underlying `PluginContext` isn't exposed.
 #[derive(Debug)]
 pub struct TransformPluginContext<'a> {
-  pub inner: PluginContext,
+  inner: PluginContext,
   sourcemap_chain: &'a Vec<SourceMap>,
+  combined_sourcemap_cache: &'a CombinedSourcemapCache,
   original_code: &'a str,
   id: &'a str,
+  code: &'a str,
+  has_side_outputs: &'a AtomicBool,
 }
 
 impl<'a> TransformPluginContext<'a> {
-  pub fn new(
+  pub(crate) fn new(
     inner: PluginContext,
     sourcemap_chain: &'a Vec<SourceMap>,
+    combined_sourcemap_cache: &'a CombinedSourcemapCache,
     original_code: &'a str,
     id: &'a str,
+    code: &'a str,
+    has_side_outputs: &'a AtomicBool,
   ) -> Self {
-    Self { inner, sourcemap_chain, original_code, id }
-  }
-
-  // pub fn get_combined_sourcemap(&self) -> SourceMap {
-  //   if self.sourcemap_chain.is_empty() {
-  //     self.create_sourcemap()
-  //   } else if self.sourcemap_chain.len() == 1 {
-  //     // TODO (fix): clone is not necessary
-  //     self.sourcemap_chain.first().expect("should have one sourcemap").clone()
-  //   } else {
-  //     let sourcemap_chain = self.sourcemap_chain.iter().collect::<Vec<_>>();
-  //     // TODO Here could be cache result for pervious sourcemap_chain, only remapping new sourcemap chain
-  //     collapse_sourcemaps(sourcemap_chain).unwrap_or_else(|| self.create_sourcemap())
-  //   }
-  // }
-
-  // fn create_sourcemap(&self) -> SourceMap {
-  //   let magic_string = MagicString::new(self.original_code);
-  //   magic_string.source_map(SourceMapOptions {
-  //     hires: true,
-  //     include_content: true,
-  //     source: self.id.into(),
-  //   })
-  // }
+    Self {
+      inner,
+      sourcemap_chain,
+      combined_sourcemap_cache,
+      original_code,
+      id,
+      code,
+      has_side_outputs,
+    }
+  }
+
+  /// Marks that the hook produces outputs besides the transformed result, so the result isn't stored in the persistent
+  /// cache, which can't replay them. Methods of this context call it on their own.
+  pub fn mark_side_output(&self) {
+    self.has_side_outputs.store(true, Ordering::Relaxed);
+  }
+
+  /// Like `PluginContext::add_watch_file`, but changes of the file only invalidate the module being transformed in
+  /// watch mode.
+  pub fn add_watch_file(&self, file: &str) {
+    self.mark_side_output();
+    self.inner.add_watch_file_with_importer(file, Some(self.id));
+  }
+
+  /// Like `PluginContext::emit_file`, but the file is emitted again if the module being transformed is reused from the
+  /// cache in watch mode.
+  pub fn emit_file(&self, file: rolldown_common::EmittedAsset) -> ArcStr {
+    self.mark_side_output();
+    self.inner.emit_file_for_module(file, Some(self.id))
+  }
+
+  pub fn emit_chunk(&self, chunk: rolldown_common::EmittedChunk) -> anyhow::Result<ArcStr> {
+    self.mark_side_output();
+    self.inner.emit_chunk(chunk)
+  }
+
+  pub fn get_watch_files(&self) -> Vec<ArcStr> {
+    self.inner.get_watch_files()
+  }
+
+  pub async fn resolve(
+    &self,
+    specifier: &str,
+    importer: Option<&str>,
+    extra_options: Option<PluginContextResolveOptions>,
+  ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
+    self.inner.resolve(specifier, importer, extra_options).await
+  }
+
+  pub async fn load(&self, resolved_id: ResolvedId) -> anyhow::Result<Arc<ModuleInfo>> {
+    self.inner.load(resolved_id).await
+  }
+
+  pub fn try_get_file_name(&self, reference_id: &str) -> Result<ArcStr, String> {
+    self.inner.try_get_file_name(reference_id)
+  }
+
+  pub fn get_file_name(&self, reference_id: &str) -> ArcStr {
+    self.inner.get_file_name(reference_id)
+  }
+
+  pub fn get_module_info(&self, module_id: &str) -> Option<ModuleInfo> {
+    self.inner.get_module_info(module_id)
+  }
+
+  pub fn get_module_ids(&self) -> Option<Vec<String>> {
+    self.inner.get_module_ids()
+  }
+
+  pub fn cwd(&self) -> &PathBuf {
+    self.inner.cwd()
+  }
+
+  /// The underlying context, which doesn't mark side outputs. Only for bindings that mark them on their own.
+  #[doc(hidden)]
+  pub fn unmarked_inner(&self) -> &PluginContext {
+    &self.inner
+  }
+
+  /// Like `PluginContext::warn`, but the log is about the module being transformed if `id` is not set, and the span
+  /// is in the code passed to the current `transform` hook.
+  pub fn warn(&self, log: impl Into<PluginLog>) {
+    let (log, source) = self.with_current_module(log.into());
+    self.inner.add_log(Severity::Warning, log, source);
+  }
+
+  pub fn info(&self, log: impl Into<PluginLog>) {
+    let (log, source) = self.with_current_module(log.into());
+    self.inner.add_log(Severity::Info, log, source);
+  }
+
+  pub fn debug(&self, log: impl Into<PluginLog>) {
+    let (log, source) = self.with_current_module(log.into());
+    self.inner.add_log(Severity::Debug, log, source);
+  }
+
+  pub fn error(&self, log: impl Into<PluginLog>) -> anyhow::Error {
+    let (log, source) = self.with_current_module(log.into());
+    self.inner.create_diagnostic(Severity::Error, log, source).into()
+  }
+
+  fn with_current_module(&self, mut log: PluginLog) -> (PluginLog, Option<ArcStr>) {
+    self.mark_side_output();
+    let is_current_module = log.id.get_or_insert_with(|| self.id.to_string()) == self.id;
+    (log, is_current_module.then(|| self.code.into()))
+  }
+
+  /// Returns the sourcemap from the code passed to the current `transform` hook to the original source.
+  pub fn get_combined_sourcemap(&self) -> SourceMap {
+    match self.sourcemap_chain.as_slice() {
+      [] => create_identity_sourcemap(self.id, self.original_code),
+      [sourcemap] => sourcemap.clone(),
+      sourcemap_chain => {
+        let mut cache = self.combined_sourcemap_cache.0.lock().expect("cache lock poisoned");
+        let combined = match cache.take() {
+          Some((len, combined)) if len == sourcemap_chain.len() => combined,
+          Some((len, combined)) if len < sourcemap_chain.len() => {
+            collapse_sourcemaps(std::iter::once(&combined).chain(&sourcemap_chain[len..]).collect())
+          }
+          _ => collapse_sourcemaps(sourcemap_chain.iter().collect()),
+        };
+        *cache = Some((sourcemap_chain.len(), combined.clone()));
+        combined
+      }
+    }
+  }
 }
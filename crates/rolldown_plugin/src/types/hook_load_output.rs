@@ -1,4 +1,6 @@
-use rolldown_common::{side_effects::HookSideEffects, ModuleType};
+use rolldown_common::{
+  side_effects::HookSideEffects, ModuleMeta, ModuleType, SyntheticNamedExports,
+};
 use rolldown_sourcemap::SourceMap;
 
 #[derive(Debug, Default)]
@@ -6,5 +8,7 @@ pub struct HookLoadOutput {
   pub code: String,
   pub map: Option<SourceMap>,
   pub side_effects: Option<HookSideEffects>,
+  pub meta: Option<ModuleMeta>,
   pub module_type: Option<ModuleType>,
+  pub synthetic_named_exports: Option<SyntheticNamedExports>,
 }
@@ -1,8 +1,9 @@
-use rolldown_common::side_effects::HookSideEffects;
+use rolldown_common::{side_effects::HookSideEffects, ModuleMeta};
 
 #[derive(Debug, Default)]
 pub struct HookResolveIdOutput {
   pub id: String,
   pub external: Option<bool>,
   pub side_effects: Option<HookSideEffects>,
+  pub meta: Option<ModuleMeta>,
 }
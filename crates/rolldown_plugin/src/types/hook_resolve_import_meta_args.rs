@@ -0,0 +1,11 @@
+use rolldown_common::OutputFormat;
+
+#[derive(Debug)]
+pub struct HookResolveImportMetaArgs<'a> {
+  /// The accessed property, e.g. `url` for `import.meta.url`. `None` if `import.meta` is used directly.
+  pub property: Option<&'a str>,
+  /// The preliminary filename of the chunk containing the module.
+  pub chunk_id: &'a str,
+  pub module_id: &'a str,
+  pub format: &'a OutputFormat,
+}
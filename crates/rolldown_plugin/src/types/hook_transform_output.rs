@@ -1,5 +1,5 @@
 use rolldown_common::side_effects::HookSideEffects;
-use rolldown_common::ModuleType;
+use rolldown_common::{ModuleMeta, ModuleType, SyntheticNamedExports};
 use rolldown_sourcemap::SourceMap;
 
 #[derive(Debug, Default)]
@@ -7,5 +7,7 @@ pub struct HookTransformOutput {
   pub code: Option<String>,
   pub map: Option<SourceMap>,
   pub side_effects: Option<HookSideEffects>,
+  pub meta: Option<ModuleMeta>,
   pub module_type: Option<ModuleType>,
+  pub synthetic_named_exports: Option<SyntheticNamedExports>,
 }
@@ -9,8 +9,10 @@ pub mod hook_render_error;
 pub mod hook_resolve_id_args;
 pub mod hook_resolve_id_output;
 pub mod hook_resolve_id_skipped;
+pub mod hook_resolve_import_meta_args;
 pub mod hook_transform_args;
 pub mod hook_transform_ast_args;
 pub mod hook_transform_output;
 pub mod plugin_context_resolve_options;
 pub mod plugin_idx;
+pub mod plugin_log;
@@ -0,0 +1,23 @@
+use oxc::span::Span;
+
+/// The argument of `PluginContext::warn`, `info`, `debug` and `error`.
+#[derive(Debug, Default, Clone)]
+pub struct PluginLog {
+  pub message: String,
+  /// The id of the module this log is about.
+  pub id: Option<String>,
+  /// The byte range in the code of the module with `id`.
+  pub span: Option<Span>,
+}
+
+impl From<String> for PluginLog {
+  fn from(message: String) -> Self {
+    Self { message, ..Default::default() }
+  }
+}
+
+impl From<&str> for PluginLog {
+  fn from(message: &str) -> Self {
+    message.to_string().into()
+  }
+}
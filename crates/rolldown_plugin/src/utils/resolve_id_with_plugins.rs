@@ -1,7 +1,9 @@
 use crate::{
   types::hook_resolve_id_skipped::HookResolveIdSkipped, HookResolveIdArgs, PluginDriver,
 };
-use rolldown_common::{ImportKind, ModuleDefFormat, ResolvedId, SharedNormalizedBundlerOptions};
+use rolldown_common::{
+  ImportKind, ModuleDefFormat, ModuleMeta, ResolvedId, SharedNormalizedBundlerOptions,
+};
 use rolldown_resolver::{ResolveError, Resolver};
 use std::{path::Path, sync::Arc};
 use typedmap::TypedDashMap;
@@ -37,6 +39,7 @@ pub async fn resolve_id_check_external(
         is_external: true,
         package_json: None,
         side_effects: None,
+        meta: ModuleMeta::default(),
       }));
     }
   }
@@ -101,6 +104,7 @@ pub async fn resolve_id_with_plugins(
         is_external: matches!(r.external, Some(true)),
         package_json: None,
         side_effects: r.side_effects,
+        meta: r.meta.unwrap_or_default(),
       }));
     }
   }
@@ -125,6 +129,7 @@ pub async fn resolve_id_with_plugins(
       is_external: matches!(r.external, Some(true)),
       package_json: None,
       side_effects: r.side_effects,
+      meta: r.meta.unwrap_or_default(),
     }));
   }
 
@@ -137,6 +142,7 @@ pub async fn resolve_id_with_plugins(
       is_external: true,
       package_json: None,
       side_effects: None,
+      meta: ModuleMeta::default(),
     }));
   }
 
@@ -162,6 +168,7 @@ fn resolve_id(
         module_def_format: ModuleDefFormat::Unknown,
         package_json: None,
         side_effects: None,
+        meta: ModuleMeta::default(),
       })),
       ResolveError::Ignored(p) => Ok(Ok(ResolvedId {
         //(hyf0) TODO: This `p` doesn't seem to contains `query` or `fragment` of the input. We need to make sure this is ok
@@ -171,6 +178,7 @@ fn resolve_id(
         module_def_format: ModuleDefFormat::Unknown,
         package_json: None,
         side_effects: None,
+        meta: ModuleMeta::default(),
       })),
       _ => Ok(Err(err)),
     }
@@ -182,6 +190,7 @@ fn resolve_id(
       is_external: false,
       package_json: resolved.package_json,
       side_effects: None,
+      meta: ModuleMeta::default(),
     }))
   }
 }
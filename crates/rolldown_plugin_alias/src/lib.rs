@@ -40,6 +40,10 @@ impl Plugin for AliasPlugin {
     Cow::Borrowed("builtin:alias")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(format!("{self:?}").into())
+  }
+
   async fn resolve_id(
     &self,
     ctx: &PluginContext,
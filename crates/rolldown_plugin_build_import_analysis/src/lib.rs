@@ -46,6 +46,10 @@ impl Plugin for BuildImportAnalysisPlugin {
     Cow::Borrowed("builtin:build-import-analysis")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(format!("{self:?}").into())
+  }
+
   async fn resolve_id(
     &self,
     _ctx: &PluginContext,
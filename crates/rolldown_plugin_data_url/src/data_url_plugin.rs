@@ -26,6 +26,10 @@ impl Plugin for DataUrlPlugin {
     "rolldown:data-url".into()
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(Cow::Borrowed(""))
+  }
+
   fn resolve_id(
     &self,
     _ctx: &PluginContext,
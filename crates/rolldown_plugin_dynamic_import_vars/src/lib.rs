@@ -27,6 +27,10 @@ impl Plugin for DynamicImportVarsPlugin {
     Cow::Borrowed("builtin:dynamic_import_vars")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(Cow::Borrowed(""))
+  }
+
   async fn resolve_id(
     &self,
     _ctx: &PluginContext,
@@ -37,9 +37,13 @@ impl Plugin for ImportGlobPlugin {
     Cow::Borrowed("builtin:import-glob-plugin")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(format!("{self:?}").into())
+  }
+
   fn transform_ast(
     &self,
-    _ctx: &PluginContext,
+    ctx: &PluginContext,
     mut args: HookTransformAstArgs,
   ) -> HookTransformAstReturn {
     args.ast.program.with_mut(|fields| {
@@ -48,6 +52,7 @@ impl Plugin for ImportGlobPlugin {
       let normalized_id = normalized_path.to_slash_lossy();
       let root = self.config.root.as_ref().map(PathBuf::from);
       let mut visitor = GlobImportVisit {
+        ctx,
         root: root.as_ref().unwrap_or(args.cwd),
         import_decls: ast_builder.vec(),
         ast_builder,
@@ -72,6 +77,7 @@ pub struct ImportGlobOptions {
 }
 
 pub struct GlobImportVisit<'ast, 'a> {
+  ctx: &'a PluginContext,
   root: &'a PathBuf,
   ast_builder: AstBuilder<'ast>,
   import_decls: Vec<'ast, Statement<'ast>>,
@@ -230,9 +236,17 @@ impl<'ast, 'a> GlobImportVisit<'ast, 'a> {
       if dir == "" {
         dir = Cow::Borrowed(root);
       }
+      // Files added to or removed from the directories change the result of the glob.
+      if !absolute_glob.starts_with('!') {
+        self.ctx.add_watch_file(glob_base(&absolute_glob));
+      }
       // TODO handle error
       for file in glob(&absolute_glob).unwrap() {
-        let file = file.unwrap().as_path().relative(dir.as_ref()).to_slash_lossy().to_string();
+        let file = file.unwrap();
+        if let Some(parent) = file.parent() {
+          self.ctx.add_watch_file(&parent.to_string_lossy());
+        }
+        let file = file.as_path().relative(dir.as_ref()).to_slash_lossy().to_string();
         files.push(format!("./{file}"));
       }
     }
@@ -427,6 +441,12 @@ fn preprocess_glob_expr(glob_expr: &str) -> String {
   new_glob_expr
 }
 
+/// Returns the leading directories of the glob that don't contain any patterns.
+fn glob_base(glob: &str) -> &str {
+  let pattern_start = glob.find(['*', '?', '[', '{']).unwrap_or(glob.len());
+  glob[..pattern_start].rfind('/').map_or(glob, |slash| &glob[..slash.max(1)])
+}
+
 fn to_absolute_glob<'a>(
   mut glob: &'a str,
   root: &'a str,
@@ -14,6 +14,10 @@ impl Plugin for JsonPlugin {
     Cow::Borrowed("builtin:json")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(format!("{self:?}").into())
+  }
+
   async fn transform(
     &self,
     _ctx: &rolldown_plugin::TransformPluginContext<'_>,
@@ -15,6 +15,10 @@ impl Plugin for LoadFallbackPlugin {
     Cow::Borrowed("builtin:load-fallback")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(Cow::Borrowed(""))
+  }
+
   async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
     if DATA_URL_RE.is_match(args.id) {
       return Ok(None);
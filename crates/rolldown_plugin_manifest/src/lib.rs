@@ -30,6 +30,10 @@ impl Plugin for ManifestPlugin {
     Cow::Borrowed("builtin:manifest-plugin")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(Cow::Borrowed(""))
+  }
+
   #[allow(clippy::case_sensitive_file_extension_comparisons)]
   async fn generate_bundle(
     &self,
@@ -24,6 +24,10 @@ impl Plugin for ModulePreloadPolyfillPlugin {
     Cow::Borrowed("builtin:module-preload-polyfill")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(format!("{self:?}").into())
+  }
+
   async fn resolve_id(
     &self,
     _ctx: &PluginContext,
@@ -116,6 +116,12 @@ impl Plugin for ReplacePlugin {
     "builtin:replace".into()
   }
 
+  fn cache_key(&self) -> Option<std::borrow::Cow<'static, str>> {
+    let mut values = self.values.iter().collect::<Vec<_>>();
+    values.sort_unstable();
+    Some(format!("{}:{}:{values:?}", self.matcher.as_str(), self.prevent_assignment).into())
+  }
+
   async fn transform(
     &self,
     _ctx: &rolldown_plugin::TransformPluginContext<'_>,
@@ -30,6 +30,10 @@ impl Plugin for TransformPlugin {
     Cow::Borrowed("builtin:transform")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(format!("{self:?}").into())
+  }
+
   async fn transform(
     &self,
     ctx: &rolldown_plugin::TransformPluginContext<'_>,
@@ -126,7 +130,7 @@ impl TransformPlugin {
     if self.include.is_empty() && self.exclude.is_empty() {
       return matches!(module_type, ModuleType::Jsx | ModuleType::Tsx | ModuleType::Ts);
     }
-    let normalized_path = Path::new(id).relative(ctx.inner.cwd());
+    let normalized_path = Path::new(id).relative(ctx.cwd());
     let normalized_id = normalized_path.to_string_lossy();
     let cleaned_id = rolldown_utils::path_ext::clean_url(&normalized_id);
     if cleaned_id == normalized_id {
@@ -10,6 +10,10 @@ impl Plugin for WasmFallbackPlugin {
     Cow::Borrowed("builtin:wasm-fallback-plugin")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(Cow::Borrowed(""))
+  }
+
   #[allow(clippy::case_sensitive_file_extension_comparisons)]
   async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
     if args.id.ends_with(".wasm") {
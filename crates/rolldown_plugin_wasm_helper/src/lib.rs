@@ -16,6 +16,10 @@ impl Plugin for WasmHelperPlugin {
     Cow::Borrowed("builtin:wasm-helper")
   }
 
+  fn cache_key(&self) -> Option<Cow<'static, str>> {
+    Some(Cow::Borrowed(""))
+  }
+
   async fn resolve_id(
     &self,
     _ctx: &PluginContext,
@@ -39,12 +43,16 @@ impl Plugin for WasmHelperPlugin {
     if args.id.ends_with(".wasm?init") {
       let id = args.id.replace("?init", "");
       let file_path = Path::new(&id);
-      let reference_id = ctx.emit_file(EmittedAsset {
-        name: file_path.file_name().map(|x| x.to_string_lossy().to_string()),
-        original_file_name: None,
-        source: AssetSource::Buffer(fs::read(file_path)?),
-        file_name: None,
-      });
+      ctx.add_watch_file(&id);
+      let reference_id = ctx.emit_file_for_module(
+        EmittedAsset {
+          name: file_path.file_name().map(|x| x.to_string_lossy().to_string()),
+          original_file_name: None,
+          source: AssetSource::Buffer(fs::read(file_path)?),
+          file_name: None,
+        },
+        Some(args.id),
+      );
       let url = ctx.get_file_name(&reference_id);
       return Ok(Some(HookLoadOutput {
         code: format!(
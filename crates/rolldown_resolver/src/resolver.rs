@@ -1,8 +1,8 @@
 use arcstr::ArcStr;
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use itertools::Itertools;
 use rolldown_common::{ImportKind, ModuleDefFormat, PackageJson, Platform, ResolveOptions};
-use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use std::{
   path::{Path, PathBuf},
   sync::Arc,
@@ -10,21 +10,29 @@ use std::{
 use sugar_path::SugarPath;
 
 use oxc_resolver::{
-  EnforceExtension, PackageJson as OxcPackageJson, Resolution, ResolveError,
+  EnforceExtension, PackageJson as OxcPackageJson, Resolution, ResolveContext, ResolveError,
   ResolveOptions as OxcResolverOptions, ResolverGeneric, TsconfigOptions,
 };
+use serde_json::Value;
 
 #[derive(Debug)]
 #[allow(dead_code)]
-pub struct Resolver<T: FileSystem + Default = OsFileSystem> {
+pub struct Resolver<T: FileSystem + Default = SharedFileSystem> {
   cwd: PathBuf,
+  fs: T,
   default_resolver: ResolverGeneric<T>,
   import_resolver: ResolverGeneric<T>,
   require_resolver: ResolverGeneric<T>,
   package_json_cache: DashMap<PathBuf, Arc<PackageJson>>,
+  /// `package.json` files read while resolving, which the bundle depends on.
+  package_json_files: DashSet<PathBuf>,
+  /// Paths probed but not found while resolving, paired with the importer. Creating one of them could change what
+  /// the imports of the importer resolve to, e.g. `foo.ts` next to `foo/index.ts`, or a new package in `node_modules`.
+  missing_files: DashSet<(PathBuf, ArcStr)>,
 }
 
-impl<F: FileSystem + Default> Resolver<F> {
+impl<F: FileSystem + Default + Clone> Resolver<F> {
+  #[allow(clippy::too_many_lines)]
   pub fn new(raw_resolve: ResolveOptions, platform: Platform, cwd: PathBuf, fs: F) -> Self {
     let mut default_conditions = vec!["default".to_string()];
     let mut import_conditions = vec!["import".to_string()];
@@ -65,7 +73,7 @@ impl<F: FileSystem + Default> Resolver<F> {
     };
 
     let resolve_options_with_default_conditions = OxcResolverOptions {
-      tsconfig: raw_resolve.tsconfig_filename.map(|p| {
+      tsconfig: raw_resolve.tsconfig_filename.as_ref().map(|p| {
         let path = PathBuf::from(&p);
         TsconfigOptions {
           config_file: if path.is_relative() { cwd.join(path) } else { path },
@@ -117,7 +125,7 @@ impl<F: FileSystem + Default> Resolver<F> {
       ..resolve_options_with_default_conditions.clone()
     };
     let default_resolver =
-      ResolverGeneric::new_with_file_system(fs, resolve_options_with_default_conditions);
+      ResolverGeneric::new_with_file_system(fs.clone(), resolve_options_with_default_conditions);
     let import_resolver =
       default_resolver.clone_with_options(resolve_options_with_import_conditions);
     let require_resolver =
@@ -125,16 +133,129 @@ impl<F: FileSystem + Default> Resolver<F> {
 
     Self {
       cwd,
+      fs,
       default_resolver,
       import_resolver,
       require_resolver,
       package_json_cache: DashMap::default(),
+      package_json_files: DashSet::default(),
+      missing_files: DashSet::default(),
     }
   }
 
   pub fn cwd(&self) -> &PathBuf {
     &self.cwd
   }
+
+  /// `package.json` files read while resolving so far, sorted by path.
+  pub fn package_json_files(&self) -> Vec<PathBuf> {
+    let mut files = self.package_json_files.iter().map(|path| path.clone()).collect::<Vec<_>>();
+    files.sort_unstable();
+    files
+  }
+
+  /// Take the paths probed but not found while resolving since the last call, paired with the importers.
+  pub fn take_missing_files(&self) -> Vec<(PathBuf, ArcStr)> {
+    let files = self.missing_files.iter().map(|entry| entry.key().clone()).collect();
+    self.missing_files.clear();
+    files
+  }
+
+  /// Clear the cached file system states, so changes of files are visible to the following resolutions.
+  pub fn clear_cache(&self) {
+    self.default_resolver.clear_cache();
+    self.import_resolver.clear_cache();
+    self.require_resolver.clear_cache();
+    self.package_json_cache.clear();
+  }
+
+  /// The tsconfig file used to resolve `paths`, followed by the files it extends. Files that can't be read are
+  /// skipped.
+  pub fn tsconfig_files(&self) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = vec![];
+    let mut pending = self
+      .default_resolver
+      .options()
+      .tsconfig
+      .as_ref()
+      .map(|tsconfig| tsconfig.config_file.clone())
+      .into_iter()
+      .collect::<Vec<_>>();
+    while let Some(path) = pending.pop() {
+      if files.contains(&path) {
+        continue;
+      }
+      let Ok(mut json) = self.fs.read_to_string(&path) else {
+        continue;
+      };
+      let dir = path.parent().unwrap_or(&self.cwd).to_path_buf();
+      files.push(path);
+      let Ok(tsconfig) =
+        json_strip_comments::strip(&mut json).map(|()| serde_json::from_str::<Value>(&json))
+      else {
+        continue;
+      };
+      let extends = match tsconfig.as_ref().ok().and_then(|tsconfig| tsconfig.get("extends")) {
+        Some(Value::String(specifier)) => vec![specifier.as_str()],
+        Some(Value::Array(specifiers)) => specifiers.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+      };
+      // Visit the extended files in order, since `pending` is a stack.
+      pending.extend(
+        extends
+          .into_iter()
+          .rev()
+          .filter_map(|specifier| self.resolve_tsconfig_extends(&dir, specifier)),
+      );
+    }
+    files
+  }
+
+  /// Resolve `extends` of a tsconfig file in the way of TypeScript, which appends `.json` to relative paths and
+  /// looks for `tsconfig.json` in packages if needed.
+  fn resolve_tsconfig_extends(&self, dir: &Path, specifier: &str) -> Option<PathBuf> {
+    let has_json_extension =
+      Path::new(specifier).extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+      let mut path = dir.join(specifier).normalize().into_os_string();
+      if !has_json_extension && self.fs.metadata(Path::new(&path)).is_err() {
+        path.push(".json");
+      }
+      return Some(path.into());
+    }
+    if !has_json_extension {
+      if let Ok(resolution) =
+        self.default_resolver.resolve(dir, &format!("{specifier}/tsconfig.json"))
+      {
+        return Some(resolution.into_path_buf());
+      }
+    }
+    self.default_resolver.resolve(dir, specifier).ok().map(Resolution::into_path_buf)
+  }
+
+  /// Resolve with `resolver`, and record the `package.json` files it reads and the paths it misses for `importer`.
+  fn resolve_with_tracking(
+    &self,
+    resolver: &ResolverGeneric<F>,
+    importer: Option<&Path>,
+    dir: &Path,
+    specifier: &str,
+  ) -> Result<Resolution, ResolveError> {
+    let mut ctx = ResolveContext::default();
+    let resolution = resolver.resolve_with_context(dir, specifier, &mut ctx);
+    for path in ctx.file_dependencies {
+      if path.ends_with("package.json") {
+        self.package_json_files.insert(path);
+      }
+    }
+    if let Some(importer) = importer {
+      let importer: ArcStr = importer.to_string_lossy().as_ref().into();
+      for path in ctx.missing_dependencies {
+        self.missing_files.insert((path, importer.clone()));
+      }
+    }
+    resolution
+  }
 }
 
 #[derive(Debug)]
@@ -144,7 +265,7 @@ pub struct ResolveReturn {
   pub package_json: Option<Arc<PackageJson>>,
 }
 
-impl<F: FileSystem + Default> Resolver<F> {
+impl<F: FileSystem + Default + Clone> Resolver<F> {
   pub fn resolve(
     &self,
     importer: Option<&Path>,
@@ -168,7 +289,8 @@ impl<F: FileSystem + Default> Resolver<F> {
 
     let context_dir = importer_dir.unwrap_or(self.cwd.as_path());
 
-    let mut resolution = selected_resolver.resolve(context_dir, specifier);
+    let mut resolution =
+      self.resolve_with_tracking(selected_resolver, importer, context_dir, specifier);
 
     if resolution.is_err() && is_user_defined_entry {
       let is_specifier_path_like = specifier.starts_with('.') || specifier.starts_with('/');
@@ -181,8 +303,12 @@ impl<F: FileSystem + Default> Resolver<F> {
 
         // So in this branch, to improve rollup-compatibility, we try to simulate the Rollup's resolve behavior in this case.
         // // Related rollup code: https://github.com/rollup/rollup/blob/680912e2ceb42c8d5e571e01c6ece0e4889aecbb/src/utils/resolveId.ts#L56.
-        let fallback = selected_resolver
-          .resolve(context_dir, &self.cwd.join(specifier).normalize().to_string_lossy());
+        let fallback = self.resolve_with_tracking(
+          selected_resolver,
+          importer,
+          context_dir,
+          &self.cwd.join(specifier).normalize().to_string_lossy(),
+        );
         if fallback.is_ok() {
           resolution = fallback;
         }
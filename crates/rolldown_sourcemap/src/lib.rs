@@ -71,6 +71,33 @@ pub fn collapse_sourcemaps(mut sourcemap_chain: Vec<&SourceMap>) -> SourceMap {
   )
 }
 
+/// Creates a sourcemap that maps `source` to itself, with a token at each word boundary like `hires: 'boundary'` of
+/// magic-string.
+#[allow(clippy::cast_possible_truncation)]
+pub fn create_identity_sourcemap(id: &str, source: &str) -> SourceMap {
+  let mut tokens = vec![];
+  for (line_index, line) in source.split('\n').enumerate() {
+    let line_index = line_index as u32;
+    let mut column = 0;
+    let mut prev_char: Option<char> = None;
+    for ch in line.chars() {
+      let is_boundary = !ch.is_whitespace()
+        && prev_char
+          .map_or(true, |prev| prev.is_whitespace() || !is_word_char(prev) || !is_word_char(ch));
+      if is_boundary {
+        tokens.push(Token::new(line_index, column, line_index, column, Some(0), None));
+      }
+      column += ch.len_utf16() as u32;
+      prev_char = Some(ch);
+    }
+  }
+  SourceMap::new(None, vec![], None, vec![id.into()], Some(vec![source.into()]), tokens, None)
+}
+
+fn is_word_char(ch: char) -> bool {
+  ch.is_alphanumeric() || ch == '_' || ch == '$'
+}
+
 #[test]
 fn test_collapse_sourcemaps() {
   use crate::{collapse_sourcemaps, ConcatSource, SourceMapSource};
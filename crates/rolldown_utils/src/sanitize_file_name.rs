@@ -15,6 +15,32 @@ pub fn sanitize_file_name(str: Cow<str>) -> String {
   sanitized
 }
 
+/// Replaces the characters that are invalid in file names on common file systems, unlike [sanitize_file_name] which
+/// only keeps alphanumeric characters.
+///
+/// ref: https://github.com/rollup/rollup/blob/a8647dac0fe46c86183be8596ef7de25bc5b4e4b/src/utils/sanitizeFileName.ts
+pub fn sanitize_invalid_file_name_chars(str: &str) -> String {
+  str
+    .chars()
+    .map(|char| {
+      if char.is_ascii_control() || "\"#$%&*+,:;<=>?[]^`{|}".contains(char) {
+        '_'
+      } else {
+        char
+      }
+    })
+    .collect()
+}
+
+#[test]
+fn test_sanitize_invalid_file_name_chars() {
+  assert_eq!(
+    sanitize_invalid_file_name_chars("\0virtual:foo.config?raw"),
+    "_virtual_foo.config_raw"
+  );
+  assert_eq!(sanitize_invalid_file_name_chars("a-b_c.d e@1"), "a-b_c.d e@1");
+}
+
 #[test]
 fn test_sanitize_file_name() {
   assert_eq!(sanitize_file_name("\0+a=Z_0-".into()), "__a_Z_0-");
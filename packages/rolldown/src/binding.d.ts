@@ -13,7 +13,15 @@ export declare class BindingModuleInfo {
   importedIds: Array<string>
   dynamicallyImportedIds: Array<string>
   isEntry: boolean
+  isExternal: boolean
+  isIncluded?: boolean
+  exports: Array<string>
+  hasDefaultExport: boolean
+  moduleType?: string
   get code(): string | null
+  get moduleSideEffects(): BindingHookSideEffects
+  /** JSON string of the `meta` object. */
+  get meta(): string
 }
 
 export declare class BindingOutputAsset {
@@ -39,6 +47,8 @@ export declare class BindingOutputChunk {
   set code(code: string)
   get map(): string | null
   set map(map: string)
+  get originalLength(): number
+  get renderedLength(): number
   get sourcemapFileName(): string | null
   get preliminaryFileName(): string
   get name(): string
@@ -53,13 +63,25 @@ export declare class BindingOutputs {
 
 export declare class BindingPluginContext {
   resolve(specifier: string, importer?: string | undefined | null, extraOptions?: BindingPluginContextResolveOptions | undefined | null): Promise<BindingPluginContextResolvedId | null>
+  load(id: string, sideEffects?: BindingHookSideEffects | undefined | null, meta?: string | undefined | null): Promise<BindingModuleInfo>
   emitFile(file: BindingEmittedAsset): string
+  emitChunk(chunk: BindingEmittedChunk): string
   getFileName(referenceId: string): string
   getModuleInfo(moduleId: string): BindingModuleInfo | null
   getModuleIds(): Array<string> | null
+  addWatchFile(file: string): void
+  getWatchFiles(): Array<string>
 }
 
 export declare class BindingTransformPluginContext {
+  /** Returns the combined sourcemap as a JSON string. */
+  getCombinedSourcemap(): string
+  /** Adds a file that only the module being transformed depends on. */
+  addWatchFile(file: string): void
+  /** Emits an asset, which is emitted again if the module being transformed is reused from the cache in watch mode. */
+  emitFile(file: BindingEmittedAsset): string
+  /** Marks that the hook produces outputs besides the transformed result, so the result isn't persistently cached. */
+  markSideOutput(): void
   inner(): BindingPluginContext
 }
 
@@ -105,6 +127,7 @@ export interface ArrowFunctionsBindingOptions {
 
 export interface BindingAdvancedChunksOptions {
   minSize?: number
+  maxSize?: number
   minShareCount?: number
   groups?: Array<BindingMatchGroup>
 }
@@ -157,6 +180,13 @@ export interface BindingEmittedAsset {
   source: BindingAssetSource
 }
 
+export interface BindingEmittedChunk {
+  name?: string
+  fileName?: string
+  id: string
+  importer?: string
+}
+
 export interface BindingExperimentalOptions {
   strictExecutionOrder?: boolean
   disableLiveBindings?: boolean
@@ -175,8 +205,12 @@ export interface BindingGlobImportPluginConfig {
 export interface BindingHookLoadOutput {
   code: string
   sideEffects?: BindingHookSideEffects
+  /** JSON string of the `meta` object. */
+  meta?: string
   map?: BindingSourcemap
   moduleType?: string
+  /** JSON string of `syntheticNamedExports`, which is either a boolean or a string. */
+  syntheticNamedExports?: string
 }
 
 export interface BindingHookRenderChunkOutput {
@@ -194,6 +228,14 @@ export interface BindingHookResolveIdOutput {
   id: string
   external?: boolean
   sideEffects?: BindingHookSideEffects
+  /** JSON string of the `meta` object. */
+  meta?: string
+}
+
+export interface BindingHookResolveImportMetaExtraArgs {
+  chunkId: string
+  moduleId: string
+  format: 'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'app'
 }
 
 export declare enum BindingHookSideEffects {
@@ -205,8 +247,12 @@ export declare enum BindingHookSideEffects {
 export interface BindingHookTransformOutput {
   code?: string
   sideEffects?: BindingHookSideEffects
+  /** JSON string of the `meta` object. */
+  meta?: string
   map?: BindingSourcemap
   moduleType?: string
+  /** JSON string of `syntheticNamedExports`, which is either a boolean or a string. */
+  syntheticNamedExports?: string
 }
 
 export interface BindingInjectImportNamed {
@@ -272,11 +318,13 @@ export interface BindingManifestPluginConfig {
 }
 
 export interface BindingMatchGroup {
-  name: string
-  test?: string
+  name: string | ((id: string, moduleInfo: BindingModuleInfo) => MaybePromise<VoidNullable<string>>)
+  test?: string | ((id: string, moduleInfo: BindingModuleInfo) => MaybePromise<boolean>)
   priority?: number
   minSize?: number
+  maxSize?: number
   minShareCount?: number
+  minModuleSize?: number
 }
 
 export interface BindingModulePreloadPolyfillPluginConfig {
@@ -295,12 +343,15 @@ export interface BindingOutputOptions {
   extend?: boolean
   externalLiveBindings?: boolean
   footer?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
-  format?: 'es' | 'cjs' | 'iife'
+  format?: 'es' | 'cjs' | 'iife' | 'umd' | 'amd'
   globals?: Record<string, string>
   inlineDynamicImports?: boolean
+  interop?: 'default' | 'esModule' | 'auto' | 'defaultOnly' | ((id: string) => 'default' | 'esModule' | 'auto' | 'defaultOnly' | undefined | null)
   intro?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
   outro?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
   plugins: (BindingBuiltinPlugin | BindingPluginOptions | undefined)[]
+  preserveModules?: boolean
+  preserveModulesRoot?: string
   sourcemap?: 'file' | 'inline' | 'hidden'
   sourcemapIgnoreList?: (source: string, sourcemapPath: string) => boolean
   sourcemapPathTransform?: (source: string, sourcemapPath: string) => string
@@ -325,6 +376,8 @@ export interface BindingPluginHookMeta {
 
 export interface BindingPluginOptions {
   name: string
+  version?: string
+  cacheKey?: string
   buildStart?: (ctx: BindingPluginContext) => MaybePromise<VoidNullable>
   buildStartMeta?: BindingPluginHookMeta
   resolveId?: (ctx: BindingPluginContext, specifier: string, importer: Nullable<string>, options: BindingHookResolveIdExtraArgs) => MaybePromise<VoidNullable<BindingHookResolveIdOutput>>
@@ -348,6 +401,8 @@ export interface BindingPluginOptions {
   augmentChunkHashMeta?: BindingPluginHookMeta
   renderStart?: (ctx: BindingPluginContext) => void
   renderStartMeta?: BindingPluginHookMeta
+  resolveImportMeta?: (ctx: BindingPluginContext, property: Nullable<string>, options: BindingHookResolveImportMetaExtraArgs) => MaybePromise<VoidNullable<string>>
+  resolveImportMetaMeta?: BindingPluginHookMeta
   renderError?: (ctx: BindingPluginContext, error: string) => void
   renderErrorMeta?: BindingPluginHookMeta
   generateBundle?: (ctx: BindingPluginContext, bundle: BindingOutputs, isWrite: boolean) => MaybePromise<VoidNullable>
@@ -378,6 +433,10 @@ export interface BindingPluginWithIndex {
 
 export interface BindingRenderedModule {
   code?: string
+  originalLength: number
+  renderedLength: number
+  renderedExports: Array<string>
+  removedExports: Array<string>
 }
 
 export interface BindingReplacePluginConfig {
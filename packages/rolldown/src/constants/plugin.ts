@@ -18,6 +18,7 @@ export const ENUMERATED_PLUGIN_HOOK_NAMES = [
   'renderChunk',
   'renderStart',
   'renderError',
+  'resolveImportMeta',
   'writeBundle',
   'footer',
   'banner',
@@ -83,4 +84,5 @@ export const DEFINED_HOOK_NAMES: DefinedHookNames = {
   [ENUMERATED_PLUGIN_HOOK_NAMES[18]]: ENUMERATED_PLUGIN_HOOK_NAMES[18],
   [ENUMERATED_PLUGIN_HOOK_NAMES[19]]: ENUMERATED_PLUGIN_HOOK_NAMES[19],
   [ENUMERATED_PLUGIN_HOOK_NAMES[20]]: ENUMERATED_PLUGIN_HOOK_NAMES[20],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[21]]: ENUMERATED_PLUGIN_HOOK_NAMES[21],
 } as const
@@ -36,6 +36,7 @@ import { ConfigExport } from './types/config-export'
 import { RolldownBuild } from './rolldown-build'
 import {
   EmittedAsset,
+  EmittedChunk,
   EmittedFile,
   PluginContext,
 } from './plugin/plugin-context'
@@ -94,6 +95,7 @@ export type {
   MinimalPluginContext,
   EmittedFile,
   EmittedAsset,
+  EmittedChunk,
   CustomPluginOptions,
   AsyncPluginHooks,
   ParallelPluginHooks,
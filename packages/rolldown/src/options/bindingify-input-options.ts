@@ -21,7 +21,7 @@ export function bindingifyInputOptions(
   const pluginContextData = new PluginContextData()
   return {
     input: bindingifyInput(options.input),
-    plugins: options.plugins.map((plugin) => {
+    plugins: [...options.plugins, ...outputOptions.plugins].map((plugin) => {
       if ('_parallel' in plugin) {
         return undefined
       }
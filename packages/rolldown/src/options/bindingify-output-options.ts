@@ -1,5 +1,6 @@
-import type { BindingOutputOptions } from '../binding'
+import type { BindingModuleInfo, BindingOutputOptions } from '../binding'
 import type { NormalizedOutputOptions } from './normalized-output-options'
+import { transformModuleInfo } from '../utils/transform-module-info'
 
 export function bindingifyOutputOptions(
   outputOptions: NormalizedOutputOptions,
@@ -32,6 +33,10 @@ export function bindingifyOutputOptions(
           return 'cjs'
         case 'iife':
           return 'iife'
+        case 'umd':
+          return 'umd'
+        case 'amd':
+          return 'amd'
       }
     })(),
     exports,
@@ -45,6 +50,7 @@ export function bindingifyOutputOptions(
     extend: outputOptions.extend,
     globals,
     esModule: bindingifyEsModule(esModule),
+    interop: outputOptions.interop,
     name,
     entryFileNames,
     chunkFileNames,
@@ -54,7 +60,32 @@ export function bindingifyOutputOptions(
     minify: outputOptions.minify,
     externalLiveBindings: outputOptions.externalLiveBindings,
     inlineDynamicImports: outputOptions.inlineDynamicImports,
-    advancedChunks: outputOptions.advancedChunks,
+    preserveModules: outputOptions.preserveModules,
+    preserveModulesRoot: outputOptions.preserveModulesRoot,
+    advancedChunks: bindingifyAdvancedChunks(outputOptions.advancedChunks),
+  }
+}
+
+function bindingifyAdvancedChunks(
+  advancedChunks: NormalizedOutputOptions['advancedChunks'],
+): BindingOutputOptions['advancedChunks'] {
+  if (!advancedChunks) {
+    return undefined
+  }
+  const toModuleInfo = (info: BindingModuleInfo) => transformModuleInfo(info)
+  return {
+    ...advancedChunks,
+    groups: advancedChunks.groups?.map(({ name, test, ...group }) => ({
+      ...group,
+      name:
+        typeof name === 'function'
+          ? (id: string, info: BindingModuleInfo) => name(id, toModuleInfo(info))
+          : name,
+      test:
+        typeof test === 'function'
+          ? (id: string, info: BindingModuleInfo) => test(id, toModuleInfo(info))
+          : test,
+    })),
   }
 }
 
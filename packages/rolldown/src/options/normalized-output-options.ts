@@ -2,11 +2,15 @@ import type {
   SourcemapIgnoreListOption,
   SourcemapPathTransformOption,
 } from '../rollup'
-import type { OutputOptions } from './output-options'
+import type {
+  GetInteropFunction,
+  InteropType,
+  OutputOptions,
+} from './output-options'
 import type { RolldownPlugin } from '../plugin'
 import type { PreRenderedChunk, RenderedChunk } from '../binding'
 
-export type InternalModuleFormat = 'es' | 'cjs' | 'iife'
+export type InternalModuleFormat = 'es' | 'cjs' | 'iife' | 'umd' | 'amd'
 
 type AddonFunction = (chunk: RenderedChunk) => string | Promise<string>
 type ChunkFileNamesOption =
@@ -27,9 +31,12 @@ export interface NormalizedOutputOptions extends OutputOptions {
   intro: AddonFunction
   outro: AddonFunction
   esModule: boolean | 'if-default-prop'
+  interop: InteropType | GetInteropFunction
   entryFileNames: ChunkFileNamesOption
   chunkFileNames: ChunkFileNamesOption
   assetFileNames: string
   name: string | undefined
   inlineDynamicImports: boolean
+  preserveModules: boolean
+  preserveModulesRoot: string | undefined
 }
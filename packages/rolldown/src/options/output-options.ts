@@ -1,4 +1,5 @@
 import type { PreRenderedChunk, RenderedChunk } from '../binding'
+import type { ModuleInfo } from '../types/module-info'
 import { z } from 'zod'
 import * as zodExt from '../utils/zod-ext'
 import { bold, underline } from '../cli/colors'
@@ -10,11 +11,21 @@ const ModuleFormatSchema = z
   .or(z.literal('module'))
   .or(z.literal('commonjs'))
   .or(z.literal('iife'))
+  .or(z.literal('umd'))
+  .or(z.literal('amd'))
   .describe(
-    `output format of the generated bundle (supports ${underline('esm')}, cjs, and iife).`,
+    `output format of the generated bundle (supports ${underline('esm')}, cjs, iife, umd and amd).`,
   )
   .optional()
 
+const InteropTypeSchema = z
+  .literal('default')
+  .or(z.literal('esModule'))
+  .or(z.literal('auto'))
+  .or(z.literal('defaultOnly'))
+
+const interopDescription = `how default imports of CommonJS modules are resolved (default, esModule, ${underline('auto')}, defaultOnly)`
+
 const addonFunctionSchema = z
   .function()
   .args(zodExt.phantom<RenderedChunk>())
@@ -61,6 +72,9 @@ const outputOptionsSchema = z.strictObject({
     .describe('extend global variable defined by name in IIFE / UMD formats')
     .optional(),
   esModule: z.literal('if-default-prop').or(z.boolean()).optional(),
+  interop: InteropTypeSchema.or(zodExt.phantom<GetInteropFunction>())
+    .describe(interopDescription)
+    .optional(),
   entryFileNames: z.string().or(chunkFileNamesFunctionSchema).optional(),
   chunkFileNames: z.string().or(chunkFileNamesFunctionSchema).optional(),
   assetFileNames: z.string().optional(),
@@ -82,23 +96,41 @@ const outputOptionsSchema = z.strictObject({
     .describe('inline dynamic imports')
     .default(false)
     .optional(),
+  preserveModules: z
+    .boolean()
+    .describe('create a separate chunk for every module')
+    .default(false)
+    .optional(),
+  preserveModulesRoot: z
+    .string()
+    .describe(
+      'directory path stripped from module paths in `preserveModules` mode',
+    )
+    .optional(),
   advancedChunks: z
     .strictObject({
       minSize: z.number().optional(),
+      maxSize: z.number().optional(),
       minShareCount: z.number().optional(),
       groups: z
         .array(
           z.strictObject({
-            name: z.string(),
-            test: z.string().optional(),
+            name: z.string().or(zodExt.phantom<AdvancedChunksNameFunction>()),
+            test: z
+              .string()
+              .or(zodExt.phantom<AdvancedChunksTestFunction>())
+              .optional(),
             priority: z.number().optional(),
             minSize: z.number().optional(),
+            maxSize: z.number().optional(),
             minShareCount: z.number().optional(),
+            minModuleSize: z.number().optional(),
           }),
         )
         .optional(),
     })
     .optional(),
+  manualChunks: zodExt.phantom<ManualChunksFunction>().optional(),
 })
 
 const getAddonDescription = (
@@ -131,9 +163,14 @@ export const outputCliOptionsSchema = outputOptionsSchema
         'always generate `__esModule` marks in non-ESM formats, defaults to `if-default-prop` (use `--no-esModule` to always disable).',
       )
       .optional(),
+    interop: InteropTypeSchema.describe(interopDescription).optional(),
     advancedChunks: z
       .strictObject({
         minSize: z.number().describe('minimum size of the chunk').optional(),
+        maxSize: z
+          .number()
+          .describe('split chunks larger than this into smaller ones')
+          .optional(),
         minShareCount: z
           .number()
           .describe('minimum share count of the chunk')
@@ -144,10 +181,30 @@ export const outputCliOptionsSchema = outputOptionsSchema
   .omit({
     sourcemapPathTransform: true,
     sourcemapIgnoreList: true,
+    manualChunks: true,
   })
 
 export type OutputOptions = z.infer<typeof outputOptionsSchema>
 
+export type InteropType = z.infer<typeof InteropTypeSchema>
+
+export type GetInteropFunction = (id: string) => InteropType | null | undefined
+
+export type AdvancedChunksNameFunction = (
+  id: string,
+  moduleInfo: ModuleInfo,
+) => string | null | undefined | void | Promise<string | null | undefined | void>
+
+export type AdvancedChunksTestFunction = (
+  id: string,
+  moduleInfo: ModuleInfo,
+) => boolean | Promise<boolean>
+
+export type ManualChunksFunction = (
+  id: string,
+  meta: { getModuleInfo: (moduleId: string) => ModuleInfo | null },
+) => string | null | undefined | void
+
 export type SourcemapIgnoreListOption = (
   relativeSourcePath: string,
   sourcemapPath: string,
@@ -12,7 +12,10 @@ import type {
 } from './index'
 import { NormalizedInputOptions } from '../options/normalized-input-options'
 import { isEmptySourcemapFiled } from '../utils/transform-sourcemap'
-import { transformModuleInfo } from '../utils/transform-module-info'
+import {
+  bindingifyMeta,
+  transformModuleInfo,
+} from '../utils/transform-module-info'
 import path from 'node:path'
 import { bindingifySourcemap, ExistingRawSourceMap } from '../types/sourcemap'
 import {
@@ -21,6 +24,7 @@ import {
 } from './plugin-context'
 import { TransformPluginContext } from './transform-plugin-context'
 import { bindingifySideEffects } from '../utils/transform-side-effects'
+import { bindingifySyntheticNamedExports } from '../utils/transform-synthetic-named-exports'
 import { PluginContextData } from './plugin-context-data'
 import {
   PluginHookWithBindingExt,
@@ -126,6 +130,7 @@ export function bindingifyResolveId(
       const result: BindingHookResolveIdOutput = {
         id: ret.id,
         external: ret.external,
+        meta: bindingifyMeta(ret.meta),
       }
 
       if (ret.moduleSideEffects !== null) {
@@ -176,6 +181,7 @@ export function bindingifyResolveDynamicImport(
       const result: BindingHookResolveIdOutput = {
         id: ret.id,
         external: ret.external,
+        meta: bindingifyMeta(ret.meta),
       }
 
       if (ret.moduleSideEffects !== null) {
@@ -239,7 +245,11 @@ export function bindingifyTransform(
         code: ret.code,
         map: bindingifySourcemap(ret.map),
         sideEffects: bindingifySideEffects(ret.moduleSideEffects),
+        meta: bindingifyMeta(ret.meta),
         moduleType: ret.moduleType,
+        syntheticNamedExports: bindingifySyntheticNamedExports(
+          ret.syntheticNamedExports,
+        ),
       }
     },
     meta: bindingifyPluginHookMeta(meta),
@@ -275,7 +285,14 @@ export function bindingifyLoad(
       }
 
       if (!ret.map) {
-        return { code: ret.code, moduleType: ret.moduleType }
+        return {
+          code: ret.code,
+          meta: bindingifyMeta(ret.meta),
+          moduleType: ret.moduleType,
+          syntheticNamedExports: bindingifySyntheticNamedExports(
+            ret.syntheticNamedExports,
+          ),
+        }
       }
 
       let map =
@@ -295,7 +312,11 @@ export function bindingifyLoad(
       const result = {
         code: ret.code,
         map: bindingifySourcemap(map),
+        meta: bindingifyMeta(ret.meta),
         moduleType: ret.moduleType,
+        syntheticNamedExports: bindingifySyntheticNamedExports(
+          ret.syntheticNamedExports,
+        ),
       }
 
       if (ret.moduleSideEffects !== null) {
@@ -333,7 +354,7 @@ export function bindingifyModuleParsed(
         new PluginContext(options, ctx, plugin, pluginContextData),
         transformModuleInfo(
           moduleInfo,
-          pluginContextData.moduleOptionMap.get(moduleInfo.id)!,
+          pluginContextData.getModuleMeta(moduleInfo),
         ),
       )
     },
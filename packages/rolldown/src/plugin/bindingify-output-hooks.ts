@@ -36,6 +36,30 @@ export function bindingifyRenderStart(
   }
 }
 
+export function bindingifyResolveImportMeta(
+  plugin: Plugin,
+  options: NormalizedInputOptions,
+  pluginContextData: PluginContextData,
+): PluginHookWithBindingExt<BindingPluginOptions['resolveImportMeta']> {
+  const hook = plugin.resolveImportMeta
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: async (ctx, property, extraArgs) => {
+      return handler.call(
+        new PluginContext(options, ctx, plugin, pluginContextData),
+        property,
+        // `app` is an internal format, and isn't passed to plugins by rollup.
+        extraArgs as Parameters<typeof handler>[1],
+      )
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
 export function bindingifyRenderChunk(
   plugin: Plugin,
   options: NormalizedInputOptions,
@@ -12,6 +12,7 @@ import {
 import {
   bindingifyRenderStart,
   bindingifyRenderChunk,
+  bindingifyResolveImportMeta,
   bindingifyGenerateBundle,
   bindingifyWriteBundle,
   bindingifyRenderError,
@@ -88,6 +89,9 @@ export function bindingifyPlugin(
     pluginContextData,
   )
 
+  const { plugin: resolveImportMeta, meta: resolveImportMetaMeta } =
+    bindingifyResolveImportMeta(plugin, options, pluginContextData)
+
   const { plugin: renderError, meta: renderErrorMeta } = bindingifyRenderError(
     plugin,
     options,
@@ -133,6 +137,8 @@ export function bindingifyPlugin(
 
   return {
     name: plugin.name ?? 'unknown',
+    version: plugin.version,
+    cacheKey: plugin.cacheKey,
     buildStart,
     buildStartMeta,
     resolveId,
@@ -158,6 +164,8 @@ export function bindingifyPlugin(
     augmentChunkHashMeta,
     renderStart,
     renderStartMeta,
+    resolveImportMeta,
+    resolveImportMetaMeta,
     renderError,
     renderErrorMeta,
     generateBundle,
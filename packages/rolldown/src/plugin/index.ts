@@ -15,7 +15,10 @@ import type { ModuleInfo } from '../types/module-info'
 import type { OutputBundle } from '../types/output-bundle'
 import { PluginContext } from './plugin-context'
 import type { TransformPluginContext } from './transform-plugin-context'
-import type { NormalizedOutputOptions } from '../options/normalized-output-options'
+import type {
+  InternalModuleFormat,
+  NormalizedOutputOptions,
+} from '../options/normalized-output-options'
 import type { LogLevel } from '../log/logging'
 import type { RollupLog } from '../rollup'
 import type { MinimalPluginContext } from '../log/logger'
@@ -67,6 +70,11 @@ export interface SourceDescription extends Partial<PartialNull<ModuleOptions>> {
   code: string
   map?: SourceMapInput
   moduleType?: ModuleType
+  /**
+   * Resolve missing named imports of the module from the properties of the `default` export, or the export with the
+   * given name.
+   */
+  syntheticNamedExports?: boolean | string | null
 }
 
 interface ResolveIdExtraOptions {
@@ -154,6 +162,12 @@ export interface FunctionPluginHooks {
     inputOptions: NormalizedInputOptions,
   ) => void
 
+  [DEFINED_HOOK_NAMES.resolveImportMeta]: (
+    this: PluginContext,
+    property: string | null,
+    options: { chunkId: string; format: InternalModuleFormat; moduleId: string },
+  ) => string | NullValue
+
   [DEFINED_HOOK_NAMES.renderChunk]: (
     this: PluginContext,
     code: string,
@@ -198,10 +212,10 @@ export type ObjectHook<T, O = {}> = T | ({ handler: T } & ObjectHookMeta<O>)
 export type SyncPluginHooks = DefinedHookNames[
   | 'augmentChunkHash'
   | 'onLog'
-  | 'outputOptions']
+  | 'outputOptions'
+  | 'resolveImportMeta']
 // | 'renderDynamicImport'
 // | 'resolveFileUrl'
-// | 'resolveImportMeta'
 
 export type AsyncPluginHooks = Exclude<
   keyof FunctionPluginHooks,
@@ -213,8 +227,8 @@ export type FirstPluginHooks = DefinedHookNames[
   // | 'renderDynamicImport'
   | 'resolveDynamicImport'
   // | 'resolveFileUrl'
-  | 'resolveId']
-// | 'resolveImportMeta'
+  | 'resolveId'
+  | 'resolveImportMeta']
 // | 'shouldTransformCachedModule'
 
 export type SequentialPluginHooks = DefinedHookNames[
@@ -241,7 +255,7 @@ export type OutputPluginHooks = DefinedHookNames[
   | 'renderError'
   | 'renderStart'
   // | 'resolveFileUrl'
-  // | 'resolveImportMeta'
+  | 'resolveImportMeta'
   | 'writeBundle']
 
 export type ParallelPluginHooks = Exclude<
@@ -291,9 +305,13 @@ export type AddonHook = string | AddonHookFunction
 export interface OutputPlugin
   extends Partial<{ [K in OutputPluginHooks]: PluginHooks[K] }>,
     Partial<{ [K in AddonHooks]: ObjectHook<AddonHook> }> {
-  // cacheKey?: string
+  /**
+   * Identifies the options that change the results of the hooks. Persistent cache entries are only reused while it
+   * and `version` are unchanged. The persistent cache is disabled while a plugin without it is used.
+   */
+  cacheKey?: string
   name?: string
-  // version?: string
+  version?: string
 }
 
 export interface Plugin<A = any> extends OutputPlugin, Partial<PluginHooks> {
@@ -1,5 +1,5 @@
-import { BindingPluginContext } from '../binding'
-import { ModuleInfo, ModuleOptions } from '..'
+import { BindingModuleInfo, BindingPluginContext } from '../binding'
+import { CustomPluginOptions, ModuleInfo, ModuleOptions } from '..'
 import { transformModuleInfo } from '../utils/transform-module-info'
 import { PluginContextResolveOptions } from './plugin-context'
 
@@ -25,6 +25,28 @@ export class PluginContextData {
     return this.moduleOptionMap.get(id)
   }
 
+  /**
+   * Returns the `meta` kept on the JS side, which plugins might have mutated in place. Keys set by native plugins are
+   * merged into it.
+   */
+  getModuleMeta(info: BindingModuleInfo): CustomPluginOptions {
+    const nativeMeta: CustomPluginOptions = JSON.parse(info.meta)
+    const option = this.moduleOptionMap.get(info.id)
+    if (!option) {
+      this.moduleOptionMap.set(info.id, {
+        meta: nativeMeta,
+        moduleSideEffects: null,
+      })
+      return nativeMeta
+    }
+    for (const key in nativeMeta) {
+      if (!(key in option.meta)) {
+        option.meta[key] = nativeMeta[key]
+      }
+    }
+    return option.meta
+  }
+
   getModuleInfo(id: string, context: BindingPluginContext) {
     if (this.modules.has(id)) {
       return this.modules.get(id) ?? null
@@ -33,7 +55,7 @@ export class PluginContextData {
     if (bindingInfo) {
       const info = transformModuleInfo(
         bindingInfo,
-        this.moduleOptionMap.get(id)!,
+        this.getModuleMeta(bindingInfo),
       )
       this.modules.set(id, info)
       return info
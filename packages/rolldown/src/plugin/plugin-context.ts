@@ -3,6 +3,7 @@ import type { NormalizedInputOptions } from '../options/normalized-input-options
 import type {
   CustomPluginOptions,
   ModuleOptions,
+  ModuleSideEffects,
   Plugin,
   ResolvedId,
 } from './index'
@@ -12,6 +13,11 @@ import { unimplemented, unsupported } from '../utils/misc'
 import { ModuleInfo } from '../types/module-info'
 import { PluginContextData } from './plugin-context-data'
 import { SYMBOL_FOR_RESOLVE_CALLER_THAT_SKIP_SELF } from '../constants/plugin-context'
+import { bindingifySideEffects } from '../utils/transform-side-effects'
+import {
+  bindingifyMeta,
+  transformModuleInfo,
+} from '../utils/transform-module-info'
 
 export interface EmittedAsset {
   type: 'asset'
@@ -21,7 +27,21 @@ export interface EmittedAsset {
   source: AssetSource
 }
 
-export type EmittedFile = EmittedAsset
+export interface EmittedChunk {
+  type: 'chunk'
+  name?: string
+  fileName?: string
+  id: string
+  importer?: string
+}
+
+export type EmittedFile = EmittedAsset | EmittedChunk
+
+export interface PluginContextLoadOptions {
+  id: string
+  moduleSideEffects?: ModuleSideEffects
+  meta?: CustomPluginOptions
+}
 
 export interface PluginContextResolveOptions {
   skipSelf?: boolean
@@ -39,11 +59,18 @@ export class PluginContext extends MinimalPluginContext {
     importer?: string,
     options?: PluginContextResolveOptions,
   ) => Promise<ResolvedId | null>
-  readonly emitFile: (file: EmittedAsset) => string
+  /**
+   * Loads, transforms and parses the module, and resolves once it is parsed. Awaiting it inside `load` or
+   * `transform` hooks is fine, unless the loaded module awaits the current module in turn. Loading the current
+   * module itself rejects.
+   */
+  readonly load: (options: PluginContextLoadOptions) => Promise<ModuleInfo>
+  readonly emitFile: (file: EmittedFile) => string
   readonly getFileName: (referenceId: string) => string
   readonly getModuleInfo: (id: string) => ModuleInfo | null
   readonly getModuleIds: () => IterableIterator<string>
   readonly addWatchFile: (id: string) => void
+  readonly getWatchFiles: () => string[]
   /**
    * @deprecated This rollup API won't be supported by rolldown. Using this API will cause runtime error.
    */
@@ -73,10 +100,26 @@ export class PluginContext extends MinimalPluginContext {
       const info = data.getModuleOption(res.id) || ({} as ModuleOptions)
       return { ...res, ...info }
     }
-    this.emitFile = (file: EmittedAsset): string => {
+    this.load = async ({ id, moduleSideEffects, meta }) => {
+      const info = await context.load(
+        id,
+        bindingifySideEffects(moduleSideEffects),
+        bindingifyMeta(meta),
+      )
+      return transformModuleInfo(info, data.getModuleMeta(info))
+    }
+    this.emitFile = (file: EmittedFile): string => {
+      if (file.type === 'chunk') {
+        return context.emitChunk({
+          name: file.name,
+          fileName: file.fileName,
+          id: file.id,
+          importer: file.importer,
+        })
+      }
       if (file.type !== 'asset') {
         return unimplemented(
-          'PluginContext.emitFile: only asset type is supported',
+          'PluginContext.emitFile: only asset and chunk types are supported',
         )
       }
       return context.emitFile({
@@ -91,6 +134,7 @@ export class PluginContext extends MinimalPluginContext {
     this.parse = unsupported(
       '`PluginContext#parse` is not supported by rolldown.',
     )
-    this.addWatchFile = () => {}
+    this.addWatchFile = (id: string) => context.addWatchFile(id)
+    this.getWatchFiles = () => context.getWatchFiles()
   }
 }
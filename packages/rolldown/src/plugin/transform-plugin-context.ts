@@ -4,18 +4,22 @@ import type {
 } from '../binding'
 import type { LoggingFunctionWithPosition, RollupError } from '../rollup'
 import { normalizeLog } from '../log/logHandler'
-import { PluginContext } from './plugin-context'
+import { EmittedFile, PluginContext } from './plugin-context'
 import { augmentCodeLocation, error, logPluginError } from '../log/logs'
 import { PluginContextData } from './plugin-context-data'
 import { NormalizedInputOptions } from '..'
 import type { Plugin } from './index'
+import type { SourceMap } from '../types/rolldown-output'
+import { bindingAssetSource } from '../utils/asset-source'
 
 export class TransformPluginContext extends PluginContext {
   error: (
     error: RollupError | string,
     pos?: number | { column: number; line: number },
   ) => never
-  // getCombinedSourcemap: () => SourceMap
+  getCombinedSourcemap: () => SourceMap
+  addWatchFile: (id: string) => void
+  emitFile: (file: EmittedFile) => string
 
   constructor(
     options: NormalizedInputOptions,
@@ -30,6 +34,8 @@ export class TransformPluginContext extends PluginContext {
     const getLogHandler =
       (handler: LoggingFunctionWithPosition): LoggingFunctionWithPosition =>
       (log, pos) => {
+        // Logs are handled on the JS side, so the result of the hook can't be replayed from the persistent cache.
+        inner.markSideOutput()
         log = normalizeLog(log)
         if (pos) augmentCodeLocation(log, pos, moduleSource, moduleId)
         log.id = moduleId
@@ -50,6 +56,19 @@ export class TransformPluginContext extends PluginContext {
       e.hook = 'transform'
       return error(logPluginError(normalizeLog(e), plugin.name || 'unknown'))
     }
-    // this.getCombinedSourcemap = () => JSON.parse(inner.getCombinedSourcemap())
+    this.getCombinedSourcemap = () => JSON.parse(inner.getCombinedSourcemap())
+    this.addWatchFile = (id: string) => inner.addWatchFile(id)
+    const emitFile = this.emitFile
+    this.emitFile = (file: EmittedFile): string => {
+      if (file.type !== 'asset') {
+        inner.markSideOutput()
+        return emitFile(file)
+      }
+      return inner.emitFile({
+        ...file,
+        originalFileName: file.originalFileName || undefined,
+        source: bindingAssetSource(file.source),
+      })
+    }
   }
 }
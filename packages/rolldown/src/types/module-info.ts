@@ -1,4 +1,4 @@
-import { ModuleOptions } from '..'
+import { ModuleOptions, ModuleType } from '..'
 
 export interface ModuleInfo extends ModuleOptions {
   /**
@@ -12,4 +12,18 @@ export interface ModuleInfo extends ModuleOptions {
   importedIds: string[]
   dynamicallyImportedIds: string[]
   isEntry: boolean
+  isExternal: boolean
+  /**
+   * `null` until tree shaking is done, and always `null` for external modules.
+   */
+  isIncluded: boolean | null
+  /**
+   * Names exported by the module itself. Names re-exported by `export * from` are not included.
+   */
+  exports: string[]
+  hasDefaultExport: boolean
+  /**
+   * `null` for external modules.
+   */
+  moduleType: ModuleType | null
 }
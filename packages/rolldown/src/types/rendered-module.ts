@@ -1 +1,6 @@
-export interface RenderedModule {}
+export interface RenderedModule {
+  originalLength: number
+  renderedLength: number
+  renderedExports: string[]
+  removedExports: string[]
+}
@@ -28,6 +28,7 @@ const unsupportedHookName = [
   'renderError',
   'renderStart',
   'resolveDynamicImport',
+  'resolveImportMeta',
   'writeBundle',
 ] as const
 const unsupportedHooks: Set<string> = new Set(unsupportedHookName)
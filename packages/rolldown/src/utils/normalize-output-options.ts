@@ -1,6 +1,8 @@
 import type { OutputOptions } from '../options/output-options'
 import { unimplemented } from './misc'
 import type { NormalizedOutputOptions } from '../options/normalized-output-options'
+import type { Plugin } from '../plugin'
+import type { PluginContext } from '../plugin/plugin-context'
 
 export function normalizeOutputOptions(
   opts: OutputOptions,
@@ -19,6 +21,7 @@ export function normalizeOutputOptions(
     name,
     esModule,
   } = opts
+  const manualChunks = getManualChunks(opts)
   return {
     dir: dir,
     format: getFormat(format),
@@ -37,18 +40,66 @@ export function normalizeOutputOptions(
     intro: getAddon(opts, 'intro'),
     outro: getAddon(opts, 'outro'),
     esModule: esModule ?? 'if-default-prop',
+    interop: opts.interop ?? 'auto',
     // TODO support functions
     globals: globals ?? {},
     entryFileNames: entryFileNames ?? '[name].js',
     chunkFileNames: chunkFileNames ?? '[name]-[hash].js',
     assetFileNames: assetFileNames ?? 'assets/[name]-[hash][extname]',
-    plugins: [],
+    plugins: manualChunks ? [manualChunks.plugin] : [],
     minify: opts.minify,
     extend: opts.extend,
     name,
     externalLiveBindings: opts.externalLiveBindings ?? true,
     inlineDynamicImports: opts.inlineDynamicImports ?? false,
-    advancedChunks: opts.advancedChunks,
+    preserveModules: opts.preserveModules ?? false,
+    preserveModulesRoot: opts.preserveModulesRoot,
+    advancedChunks: manualChunks
+      ? {
+          ...opts.advancedChunks,
+          groups: [
+            manualChunks.group,
+            ...(opts.advancedChunks?.groups ?? []),
+          ],
+        }
+      : opts.advancedChunks,
+  }
+}
+
+type AdvancedChunksGroup = NonNullable<
+  NonNullable<OutputOptions['advancedChunks']>['groups']
+>[number]
+
+// `manualChunks` is implemented as a group of `advancedChunks` that goes before the others. Its
+// `getModuleInfo` uses the context of a plugin, which is captured in `renderStart`, right before
+// the chunks are generated.
+function getManualChunks(opts: OutputOptions):
+  | {
+      group: AdvancedChunksGroup
+      plugin: Plugin
+    }
+  | undefined {
+  const { manualChunks } = opts
+  if (!manualChunks) {
+    return undefined
+  }
+  let context: PluginContext | undefined
+  return {
+    group: {
+      name: (id, moduleInfo) =>
+        manualChunks(id, {
+          getModuleInfo: (moduleId) =>
+            moduleId === id
+              ? moduleInfo
+              : (context?.getModuleInfo(moduleId) ?? null),
+        }),
+    },
+    plugin: {
+      name: 'rolldown:manual-chunks',
+      renderStart() {
+        context = this
+      },
+    },
   }
 }
 
@@ -72,6 +123,14 @@ function getFormat(
       return 'iife'
     }
 
+    case 'umd': {
+      return 'umd'
+    }
+
+    case 'amd': {
+      return 'amd'
+    }
+
     default:
       unimplemented(`output.format: ${format}`)
   }
@@ -1,11 +1,15 @@
 import type { ModuleInfo } from '../types/module-info'
 import type { BindingModuleInfo } from '../binding'
 import { unsupported } from './misc'
-import { ModuleOptions } from '..'
+import { CustomPluginOptions } from '..'
+import { transformSideEffects } from './transform-side-effects'
 
+/**
+ * @param meta The `meta` kept on the JS side, which is the `meta` returned by the binding if it's not given.
+ */
 export function transformModuleInfo(
   info: BindingModuleInfo,
-  option: ModuleOptions,
+  meta?: CustomPluginOptions,
 ): ModuleInfo {
   return {
     get ast() {
@@ -20,6 +24,18 @@ export function transformModuleInfo(
     importedIds: info.importedIds,
     dynamicallyImportedIds: info.dynamicallyImportedIds,
     isEntry: info.isEntry,
-    ...option,
+    isExternal: info.isExternal,
+    isIncluded: info.isIncluded ?? null,
+    exports: info.exports,
+    hasDefaultExport: info.hasDefaultExport,
+    moduleType: info.moduleType ?? null,
+    moduleSideEffects: transformSideEffects(info.moduleSideEffects),
+    meta: meta ?? JSON.parse(info.meta),
   }
 }
+
+export function bindingifyMeta(
+  meta?: CustomPluginOptions | null,
+): string | undefined {
+  return meta == null ? undefined : JSON.stringify(meta)
+}
@@ -22,3 +22,18 @@ export function bindingifySideEffects(
       throw new Error(`Unexpected side effects: ${sideEffects}`)
   }
 }
+
+export function transformSideEffects(
+  sideEffects: BindingHookSideEffects,
+): ModuleSideEffects {
+  switch (sideEffects) {
+    case BindingHookSideEffects.True:
+      return true
+
+    case BindingHookSideEffects.False:
+      return false
+
+    case BindingHookSideEffects.NoTreeshake:
+      return 'no-treeshake'
+  }
+}
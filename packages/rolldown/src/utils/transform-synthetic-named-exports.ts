@@ -0,0 +1,7 @@
+export function bindingifySyntheticNamedExports(
+  syntheticNamedExports?: boolean | string | null,
+): string | undefined {
+  return syntheticNamedExports == null
+    ? undefined
+    : JSON.stringify(syntheticNamedExports)
+}
@@ -32,7 +32,15 @@ function transformToRollupOutputChunk(
     name: chunk.name,
     get modules() {
       return Object.fromEntries(
-        Object.entries(chunk.modules).map(([key, _]) => [key, {}]),
+        Object.entries(chunk.modules).map(([key, module]) => [
+          key,
+          {
+            originalLength: module.originalLength,
+            renderedLength: module.renderedLength,
+            renderedExports: module.renderedExports,
+            removedExports: module.removedExports,
+          },
+        ]),
       )
     },
     get imports() {
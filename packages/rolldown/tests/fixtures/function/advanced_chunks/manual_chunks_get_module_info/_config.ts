@@ -0,0 +1,34 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import path from 'node:path'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      // Puts the modules imported by `shared.js` into the chunk of `shared.js`.
+      manualChunks(id, { getModuleInfo }) {
+        if (id.endsWith('shared.js')) {
+          return 'shared'
+        }
+        const importers = getModuleInfo(id)?.importers ?? []
+        if (
+          importers.some((importer) =>
+            getModuleInfo(importer)?.id.endsWith('shared.js'),
+          )
+        ) {
+          return 'shared'
+        }
+      },
+    },
+  },
+  afterTest(output) {
+    const shared = getOutputChunk(output).find(
+      (chunk) => chunk.name === 'shared',
+    )
+    expect(shared?.moduleIds.map((id) => path.basename(id))).toStrictEqual([
+      'util.js',
+      'shared.js',
+    ])
+  },
+})